@@ -0,0 +1,186 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+
+use crate::question::{Question, QuestionKind, QuestionOpts};
+
+/// A field of the struct, along with the question that is asked for it.
+struct Field {
+    ident: syn::Ident,
+    question: Question,
+}
+
+impl Field {
+    fn new(field: syn::Field) -> syn::Result<Self> {
+        let ident = field
+            .ident
+            .expect("fields are checked to be named before this is called");
+        let kind = field_kind(&field.ty)?;
+
+        let mut name = None;
+        let mut opts = QuestionOpts::default();
+        let mut attr_found = false;
+
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("prompt"))
+        {
+            if attr_found {
+                return Err(syn::Error::new(
+                    attr.span(),
+                    "duplicate attribute `prompt`, all the options should be in one attribute",
+                ));
+            }
+            attr_found = true;
+
+            let (attr_name, attr_opts) =
+                attr.parse_args_with(|input: syn::parse::ParseStream| {
+                    QuestionOpts::parse_opts(input, kind)
+                })?;
+            name = attr_name;
+            opts = attr_opts;
+        }
+
+        let name = name.unwrap_or_else(|| {
+            let name = syn::LitStr::new(&ident.to_string(), ident.span());
+            syn::parse_quote! { #name }
+        });
+
+        Ok(Self {
+            ident,
+            question: Question { kind, name, opts },
+        })
+    }
+
+    /// The code to set the field from the answer, if it has not already been set.
+    fn fill(&self) -> TokenStream {
+        let ident = &self.ident;
+        let name = &self.question.name;
+
+        let (variant, value) = match self.question.kind {
+            QuestionKind::Input => (quote! { String }, quote! { value.clone() }),
+            QuestionKind::Int => (quote! { Int }, quote! { *value }),
+            QuestionKind::Float => (quote! { Float }, quote! { *value }),
+            QuestionKind::Confirm => (quote! { Bool }, quote! { *value }),
+            _ => unreachable!("`field_kind` only returns the above kinds"),
+        };
+
+        quote_spanned! { ident.span() =>
+            if self.#ident.is_none() {
+                let name = ::std::convert::AsRef::<str>::as_ref(&#name);
+                if let ::std::option::Option::Some(::requestty::Answer::#variant(value)) =
+                    answers.get(name)
+                {
+                    self.#ident = ::std::option::Option::Some(#value);
+                }
+            }
+        }
+    }
+}
+
+/// Gets the kind of question to ask from the type of the field, which must be an `Option` of a
+/// supported type.
+fn field_kind(ty: &syn::Type) -> syn::Result<QuestionKind> {
+    let err = || {
+        syn::Error::new(
+            ty.span(),
+            "unsupported field type, expected `Option<String>`, `Option<i64>`, `Option<f64>` or \
+             `Option<bool>`",
+        )
+    };
+
+    let inner = match last_segment(ty) {
+        Some(segment) if segment.ident == "Option" => match segment.arguments {
+            syn::PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => {
+                match args.args[0] {
+                    syn::GenericArgument::Type(ref inner) => inner,
+                    _ => return Err(err()),
+                }
+            }
+            _ => return Err(err()),
+        },
+        _ => return Err(err()),
+    };
+
+    match last_segment(inner) {
+        Some(segment) if segment.ident == "String" => Ok(QuestionKind::Input),
+        Some(segment) if segment.ident == "i64" => Ok(QuestionKind::Int),
+        Some(segment) if segment.ident == "f64" => Ok(QuestionKind::Float),
+        Some(segment) if segment.ident == "bool" => Ok(QuestionKind::Confirm),
+        _ => Err(err()),
+    }
+}
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+pub(crate) fn derive_prompt_missing(input: syn::DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => fields.named,
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`PromptMissing` can only be derived for structs with named fields",
+            ))
+        }
+    };
+
+    let fields = fields
+        .into_iter()
+        .map(Field::new)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let field_idents = fields.iter().map(|field| &field.ident);
+    let questions = fields.iter().map(|field| &field.question);
+    let fills = fields.iter().map(Field::fill);
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// The questions for the fields which have not been set yet.
+            pub fn missing_questions(&self) -> ::std::vec::Vec<::requestty::Question<'static>> {
+                let mut questions = ::std::vec::Vec::new();
+                #(
+                    if self.#field_idents.is_none() {
+                        questions.push(#questions);
+                    }
+                )*
+                questions
+            }
+
+            /// Sets the fields which have not been set yet from the `answers`.
+            pub fn fill_from_answers(&mut self, answers: &::requestty::Answers) {
+                #(#fills)*
+            }
+
+            /// Asks the questions for the fields which have not been set yet, with the given
+            /// [`Backend`](::requestty::prompt::Backend) and
+            /// [`EventIterator`](::requestty::prompt::EventIterator), and sets them from the
+            /// answers.
+            pub fn prompt_missing_with<B, E>(
+                &mut self,
+                backend: &mut B,
+                events: &mut E,
+            ) -> ::requestty::Result<()>
+            where
+                B: ::requestty::prompt::Backend,
+                E: ::requestty::prompt::EventIterator,
+            {
+                let answers =
+                    ::requestty::prompt_with(self.missing_questions(), backend, events)?;
+                self.fill_from_answers(&answers);
+                ::std::result::Result::Ok(())
+            }
+        }
+    })
+}
@@ -1,5 +1,5 @@
-//! This crate is the declaration of the [`questions!`] macro. It should not be used directly. Use
-//! [`requestty`] instead.
+//! This crate is the declaration of the [`questions!`] macro and the [`PromptMissing`] derive. It
+//! should not be used directly. Use [`requestty`] instead.
 //!
 //! [`requestty`]: https://crates.io/crates/requestty
 
@@ -19,6 +19,7 @@ macro_rules! iter {
     };
 }
 
+mod derive;
 mod helpers;
 mod question;
 
@@ -39,6 +40,15 @@ pub fn questions(item: TokenStream) -> TokenStream {
     .into()
 }
 
+#[proc_macro_derive(PromptMissing, attributes(prompt))]
+pub fn derive_prompt_missing(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as syn::DeriveInput);
+
+    derive::derive_prompt_missing(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 struct Questions {
     inline: bool,
     questions: syn::punctuated::Punctuated<Question, Token![,]>,
@@ -53,9 +63,41 @@ impl Parse for Questions {
             })
             .is_ok();
 
-        Ok(Self {
-            inline,
-            questions: input.parse_terminated(Question::parse)?,
-        })
+        let questions = input.parse_terminated(Question::parse)?;
+        check_duplicate_names(&questions)?;
+
+        Ok(Self { inline, questions })
     }
 }
+
+/// Errors on a question which can never be asked, because an earlier question with the same name
+/// is always answered. Only names which are string literals can be checked, the rest are checked
+/// when the questions are prompted.
+fn check_duplicate_names(
+    questions: &syn::punctuated::Punctuated<Question, Token![,]>,
+) -> syn::Result<()> {
+    let mut answered = Vec::new();
+
+    for question in questions {
+        let name = match question.name {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(ref name),
+                ..
+            }) => name,
+            _ => continue,
+        };
+
+        if question.opts.ask_if_answered.is_none() && answered.contains(&name.value()) {
+            return Err(syn::Error::new(
+                name.span(),
+                format!("duplicate question name `{}`", name.value()),
+            ));
+        }
+
+        if question.opts.when.is_none() && question.opts.optional.is_none() {
+            answered.push(name.value());
+        }
+    }
+
+    Ok(())
+}
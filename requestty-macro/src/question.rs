@@ -168,6 +168,7 @@ pub(crate) struct QuestionOpts {
     pub(crate) when: Option<syn::Expr>,
     pub(crate) ask_if_answered: Option<syn::Expr>,
     pub(crate) on_esc: Option<syn::Expr>,
+    pub(crate) optional: Option<syn::Expr>,
 
     pub(crate) default: Option<syn::Expr>,
 
@@ -175,6 +176,7 @@ pub(crate) struct QuestionOpts {
     pub(crate) validate_on_key: Option<syn::Expr>,
     pub(crate) filter: Option<syn::Expr>,
     pub(crate) transform: Option<syn::Expr>,
+    pub(crate) display_transform: Option<syn::Expr>,
     pub(crate) auto_complete: Option<syn::Expr>,
 
     pub(crate) choices: Option<Choices>,
@@ -196,7 +198,7 @@ fn check_allowed(ident: &syn::Ident, kind: QuestionKind) -> syn::Result<()> {
 
     let builder_method = if ident == "default" {
         BuilderMethods::DEFAULT
-    } else if ident == "transform" {
+    } else if ident == "transform" || ident == "display_transform" {
         BuilderMethods::TRANSFORM
     } else if ident == "validate" || ident == "filter" {
         BuilderMethods::VAL_FIL
@@ -212,7 +214,7 @@ fn check_allowed(ident: &syn::Ident, kind: QuestionKind) -> syn::Result<()> {
         BuilderMethods::MASK
     } else if ident == "editor" || ident == "extension" {
         BuilderMethods::EDITOR
-    } else if ident == "on_esc" {
+    } else if ident == "on_esc" || ident == "optional" {
         BuilderMethods::ON_ESC
     } else if ident == "prompt" {
         BuilderMethods::PROMPT
@@ -239,12 +241,13 @@ pub(crate) struct Question {
     pub(crate) opts: QuestionOpts,
 }
 
-impl Parse for Question {
-    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let kind = QuestionKind::parse(input)?;
-        let content;
-        let brace = syn::braced!(content in input);
-
+impl QuestionOpts {
+    /// Parses the comma separated options of a question of the given `kind`, returning the `name`
+    /// separately if it was given.
+    pub(crate) fn parse_opts(
+        content: syn::parse::ParseStream,
+        kind: QuestionKind,
+    ) -> syn::Result<(Option<syn::Expr>, Self)> {
         let mut opts = QuestionOpts::default();
         let mut name = None;
 
@@ -254,25 +257,27 @@ impl Parse for Question {
             check_allowed(&ident, kind)?;
 
             if ident == "name" {
-                insert_non_dup(ident, &mut name, &content)?;
+                insert_non_dup(ident, &mut name, content)?;
             } else if ident == "message" {
-                insert_non_dup(ident, &mut opts.message, &content)?;
+                insert_non_dup(ident, &mut opts.message, content)?;
             } else if ident == "when" {
-                insert_non_dup(ident, &mut opts.when, &content)?;
+                insert_non_dup(ident, &mut opts.when, content)?;
             } else if ident == "ask_if_answered" {
-                insert_non_dup(ident, &mut opts.ask_if_answered, &content)?;
+                insert_non_dup(ident, &mut opts.ask_if_answered, content)?;
             } else if ident == "default" {
-                insert_non_dup(ident, &mut opts.default, &content)?;
+                insert_non_dup(ident, &mut opts.default, content)?;
             } else if ident == "validate" {
-                insert_non_dup(ident, &mut opts.validate, &content)?;
+                insert_non_dup(ident, &mut opts.validate, content)?;
             } else if ident == "validate_on_key" {
-                insert_non_dup(ident, &mut opts.validate_on_key, &content)?;
+                insert_non_dup(ident, &mut opts.validate_on_key, content)?;
             } else if ident == "filter" {
-                insert_non_dup(ident, &mut opts.filter, &content)?;
+                insert_non_dup(ident, &mut opts.filter, content)?;
             } else if ident == "transform" {
-                insert_non_dup(ident, &mut opts.transform, &content)?;
+                insert_non_dup(ident, &mut opts.transform, content)?;
+            } else if ident == "display_transform" {
+                insert_non_dup(ident, &mut opts.display_transform, content)?;
             } else if ident == "auto_complete" {
-                insert_non_dup(ident, &mut opts.auto_complete, &content)?;
+                insert_non_dup(ident, &mut opts.auto_complete, content)?;
             } else if ident == "choices" {
                 let parser = match kind {
                     QuestionKind::MultiSelect => Choices::parse_multi_select_choice,
@@ -280,30 +285,44 @@ impl Parse for Question {
                     _ => Choices::parse_choice,
                 };
 
-                insert_non_dup_parse(ident, &mut opts.choices, &content, parser)?;
+                insert_non_dup_parse(ident, &mut opts.choices, content, parser)?;
             } else if ident == "page_size" {
-                insert_non_dup(ident, &mut opts.page_size, &content)?;
+                insert_non_dup(ident, &mut opts.page_size, content)?;
             } else if ident == "should_loop" {
-                insert_non_dup(ident, &mut opts.should_loop, &content)?;
+                insert_non_dup(ident, &mut opts.should_loop, content)?;
             } else if ident == "mask" {
-                insert_non_dup(ident, &mut opts.mask, &content)?;
+                insert_non_dup(ident, &mut opts.mask, content)?;
             } else if ident == "editor" {
-                insert_non_dup(ident, &mut opts.editor, &content)?;
+                insert_non_dup(ident, &mut opts.editor, content)?;
             } else if ident == "extension" {
-                insert_non_dup(ident, &mut opts.extension, &content)?;
+                insert_non_dup(ident, &mut opts.extension, content)?;
             } else if ident == "on_esc" {
-                insert_non_dup(ident, &mut opts.on_esc, &content)?;
+                insert_non_dup(ident, &mut opts.on_esc, content)?;
+            } else if ident == "optional" {
+                insert_non_dup(ident, &mut opts.optional, content)?;
             } else if ident == "prompt" {
-                insert_non_dup(ident, &mut opts.prompt, &content)?;
+                insert_non_dup(ident, &mut opts.prompt, content)?;
             } else {
                 unreachable!("check_allowed should have taken care of this case.");
             }
 
-            if parse_optional_comma(&content)?.is_none() {
+            if parse_optional_comma(content)?.is_none() {
                 break;
             }
         }
 
+        Ok((name, opts))
+    }
+}
+
+impl Parse for Question {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let kind = QuestionKind::parse(input)?;
+        let content;
+        let brace = syn::braced!(content in input);
+
+        let (name, opts) = QuestionOpts::parse_opts(&content, kind)?;
+
         if let QuestionKind::Custom = kind {
             if opts.prompt.is_none() {
                 return Err(syn::Error::new(
@@ -387,6 +406,11 @@ impl quote::ToTokens for Question {
         if let Some(ref transform) = self.opts.transform {
             tokens.extend(quote_spanned! { transform.span() => .transform(#transform) });
         }
+        if let Some(ref display_transform) = self.opts.display_transform {
+            tokens.extend(
+                quote_spanned! { display_transform.span() => .display_transform(#display_transform) },
+            );
+        }
         if let Some(ref auto_complete) = self.opts.auto_complete {
             tokens
                 .extend(quote_spanned! { auto_complete.span() => .auto_complete(#auto_complete) });
@@ -417,6 +441,9 @@ impl quote::ToTokens for Question {
         if let Some(ref on_esc) = self.opts.on_esc {
             tokens.extend(quote_spanned! { on_esc.span() => .on_esc(#on_esc) });
         }
+        if let Some(ref optional) = self.opts.optional {
+            tokens.extend(quote_spanned! { optional.span() => .optional(#optional) });
+        }
         tokens.extend(quote! { .build() });
     }
 }
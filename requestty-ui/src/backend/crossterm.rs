@@ -1,10 +1,12 @@
 use std::{
     cmp::Ordering,
     io::{self, Write},
+    ops::Range,
+    sync::atomic::AtomicBool,
 };
 
 use crossterm::{
-    cursor, queue,
+    cursor, event, queue,
     style::{
         Attribute as CAttribute, Color as CColor, SetAttribute, SetBackgroundColor,
         SetForegroundColor,
@@ -12,7 +14,9 @@ use crossterm::{
     terminal,
 };
 
-use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use super::{
+    Attributes, Backend, ClearType, Color, MoveDirection, Size, RESTORE_TITLE, SAVE_TITLE,
+};
 
 /// A backend that uses the `crossterm` library.
 #[derive(Debug, Clone)]
@@ -20,6 +24,8 @@ use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
 pub struct CrosstermBackend<W> {
     buffer: W,
     attributes: Attributes,
+    // Whether the terminal supports keyboard enhancement, once it has been asked
+    keyboard_enhancement: Option<bool>,
 }
 
 impl<W> CrosstermBackend<W> {
@@ -28,6 +34,7 @@ impl<W> CrosstermBackend<W> {
         CrosstermBackend {
             buffer,
             attributes: Attributes::empty(),
+            keyboard_enhancement: None,
         }
     }
 }
@@ -43,11 +50,19 @@ impl<W: Write> Write for CrosstermBackend<W> {
 }
 
 impl<W: Write> Backend for CrosstermBackend<W> {
+    /// Bracketed paste is enabled along with raw mode, so that pasted text can be told apart from
+    /// typed keys.
     fn enable_raw_mode(&mut self) -> io::Result<()> {
-        terminal::enable_raw_mode()
+        terminal::enable_raw_mode()?;
+        // Legacy Windows consoles do not support bracketed paste, in which case pastes still
+        // arrive as keys
+        let _ = queue!(self.buffer, event::EnableBracketedPaste);
+        Ok(())
     }
 
     fn disable_raw_mode(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::DisableBracketedPaste)?;
+        self.buffer.flush()?;
         terminal::disable_raw_mode()
     }
 
@@ -59,7 +74,19 @@ impl<W: Write> Backend for CrosstermBackend<W> {
         queue!(self.buffer, cursor::Show)
     }
 
+    /// If stdout is not a terminal, for example when the prompt is drawn on stderr, the terminal is
+    /// asked through `/dev/tty` on unix instead, as `crossterm` asks through stdout.
     fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        #[cfg(unix)]
+        if !crossterm::tty::IsTty::is_tty(&io::stdout()) {
+            return query_tty(b"\x1b[6n", parse_cursor_position)?.ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "the cursor position could not be read within a normal duration",
+                )
+            });
+        }
+
         cursor::position()
     }
 
@@ -116,6 +143,219 @@ impl<W: Write> Backend for CrosstermBackend<W> {
     fn size(&self) -> io::Result<Size> {
         terminal::size().map(Into::into)
     }
+
+    /// The terminal is asked the first time this is called, and the answer is reused after that.
+    /// If the terminal cannot be asked, it is assumed not to support it.
+    fn supports_keyboard_enhancement(&mut self) -> io::Result<bool> {
+        match self.keyboard_enhancement {
+            Some(supported) => Ok(supported),
+            None => {
+                let supported = query_keyboard_enhancement().unwrap_or(false);
+                self.keyboard_enhancement = Some(supported);
+                Ok(supported)
+            }
+        }
+    }
+
+    /// Key releases and repeats are reported, along with modifiers on keys such as `Enter`.
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        queue!(
+            self.buffer,
+            event::PushKeyboardEnhancementFlags(
+                event::KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+                    | event::KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            )
+        )
+    }
+
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        queue!(self.buffer, event::PopKeyboardEnhancementFlags)
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        // Push the current title on to the title stack, so that it can be restored
+        self.buffer.write_all(SAVE_TITLE)?;
+        queue!(self.buffer, terminal::SetTitle(title))
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.buffer.write_all(RESTORE_TITLE)
+    }
+}
+
+/// Asks the terminal whether it supports the kitty keyboard protocol.
+///
+/// The query for the keyboard enhancement flags is followed by a query for the primary device
+/// attributes, which every terminal answers. So if the answer to the second query comes without an
+/// answer to the first, the protocol is not supported, and there is no need to wait for a timeout.
+#[cfg(unix)]
+fn query_keyboard_enhancement() -> io::Result<bool> {
+    // A terminal which does not answer at all does not support the protocol either
+    query_tty(b"\x1b[?u\x1b[c", parse_keyboard_enhancement_response).map(|res| res.unwrap_or(false))
+}
+
+/// Writes `query` to the terminal, and reads its answer until `parse` can make sense of it.
+///
+/// The terminal is opened directly, so this works even if stdin or stdout are redirected. Returns
+/// `None` if the terminal does not answer within 2 seconds.
+///
+/// The answer is read on another thread, so that it can be given up on without polling the
+/// terminal. Anything read which is not a part of the answer is kept for
+/// [`CrosstermEvents`](crate::events::CrosstermEvents), so keys typed in the meantime are not lost.
+#[cfg(unix)]
+fn query_tty<T, F>(query: &[u8], parse: F) -> io::Result<Option<T>>
+where
+    T: Send + 'static,
+    F: FnMut(&[u8]) -> Option<(T, Range<usize>)> + Send + 'static,
+{
+    use std::{
+        fs::OpenOptions,
+        sync::{atomic::Ordering::SeqCst, mpsc, Arc},
+        thread,
+        time::Duration,
+    };
+
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty")?;
+
+    let was_raw = terminal::is_raw_mode_enabled()?;
+    if !was_raw {
+        terminal::enable_raw_mode()?;
+    }
+
+    let res = (|| {
+        tty.write_all(query)?;
+        tty.flush()?;
+
+        let (tx, rx) = mpsc::channel();
+        let timed_out = Arc::new(AtomicBool::new(false));
+        let thread_timed_out = Arc::clone(&timed_out);
+        thread::spawn(move || {
+            let _ = tx.send(read_answer(tty, parse, &thread_timed_out));
+        });
+
+        match rx.recv_timeout(Duration::from_secs(2)) {
+            Ok(answer) => answer,
+            Err(_) => {
+                timed_out.store(true, SeqCst);
+                Ok(None)
+            }
+        }
+    })();
+
+    if !was_raw {
+        terminal::disable_raw_mode()?;
+    }
+
+    res
+}
+
+/// Reads from `reader` until `parse` finds the answer to a query in what has been read, and keeps
+/// the rest for [`CrosstermEvents`](crate::events::CrosstermEvents).
+///
+/// If `timed_out` is set, no one is waiting for the answer anymore, so reading stops after the
+/// current read.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn read_answer<R, T, F>(
+    mut reader: R,
+    mut parse: F,
+    timed_out: &AtomicBool,
+) -> io::Result<Option<T>>
+where
+    R: io::Read,
+    F: FnMut(&[u8]) -> Option<(T, Range<usize>)>,
+{
+    let mut response = Vec::new();
+    let mut buf = [0; 64];
+
+    let answer = loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break Ok(None),
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => break Err(e),
+        };
+        response.extend_from_slice(&buf[..n]);
+
+        if let Some((answer, range)) = parse(&response) {
+            response.drain(range);
+            break Ok(Some(answer));
+        }
+
+        if timed_out.load(std::sync::atomic::Ordering::SeqCst) {
+            break Ok(None);
+        }
+    };
+
+    crate::events::push_typed_ahead(&response);
+    answer
+}
+
+/// The 0-indexed cursor position in the answer to `CSI 6 n`, which is `CSI <row> ; <column> R`,
+/// along with where the answer is in `response`, or `None` if it has not been answered yet.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn parse_cursor_position(response: &[u8]) -> Option<((u16, u16), Range<usize>)> {
+    let mut offset = 0;
+
+    while let Some(start) = response[offset..].windows(2).position(|w| w == b"\x1b[") {
+        let start = offset + start;
+        let rest = &response[start + 2..];
+        let end = rest
+            .iter()
+            .position(|&b| !(b.is_ascii_digit() || b == b';'))?;
+
+        if rest[end] == b'R' {
+            let answer = std::str::from_utf8(&rest[..end]).ok()?;
+            let mut parts = answer.split(';').map(str::parse::<u16>);
+
+            if let (Some(Ok(row)), Some(Ok(column)), None) =
+                (parts.next(), parts.next(), parts.next())
+            {
+                let pos = (column.saturating_sub(1), row.saturating_sub(1));
+                return Some((pos, start..start + 2 + end + 1));
+            }
+        }
+        offset = start + 2 + end;
+    }
+
+    None
+}
+
+/// The legacy Windows console does not support keyboard enhancement.
+#[cfg(not(unix))]
+fn query_keyboard_enhancement() -> io::Result<bool> {
+    Ok(false)
+}
+
+/// Whether the terminal supports keyboard enhancement, given its answers so far to the queries
+/// made by [`query_keyboard_enhancement`], along with where the answers are in `response`. Returns
+/// `None` if the device attributes have not been answered yet.
+#[cfg_attr(not(unix), allow(dead_code))]
+fn parse_keyboard_enhancement_response(response: &[u8]) -> Option<(bool, Range<usize>)> {
+    let mut supported = false;
+    let mut answers_start = None;
+    let mut offset = 0;
+
+    // Both answers are of the form `CSI ? <digits and ;> <final byte>`
+    while let Some(start) = response[offset..].windows(3).position(|w| w == b"\x1b[?") {
+        let start = offset + start;
+        answers_start.get_or_insert(start);
+        let rest = &response[start + 3..];
+        let end = rest
+            .iter()
+            .position(|&b| !(b.is_ascii_digit() || b == b';'))?;
+
+        match rest[end] {
+            b'u' => supported = true,
+            b'c' => {
+                let answers_start = answers_start.expect("set on the first answer");
+                return Some((supported, answers_start..start + 3 + end + 1));
+            }
+            _ => {}
+        }
+        offset = start + 3 + end + 1;
+    }
+
+    None
 }
 
 impl From<Color> for CColor {
@@ -216,3 +456,51 @@ pub(super) fn set_attributes<W: Write>(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{CrosstermEvents, EventIterator, KeyCode};
+
+    #[test]
+    fn test_parse_keyboard_enhancement_response() {
+        let parse = parse_keyboard_enhancement_response;
+
+        assert_eq!(parse(b""), None);
+        assert_eq!(parse(b"\x1b[?0u"), None);
+        assert_eq!(parse(b"\x1b[?0u\x1b[?62;"), None);
+        assert_eq!(parse(b"\x1b[?0u\x1b[?62;22c"), Some((true, 0..14)));
+        assert_eq!(parse(b"\x1b[?62;22c"), Some((false, 0..9)));
+        // Other input before the answers is not a part of them
+        assert_eq!(parse(b"a\x1b[?1u\x1b[?1;2c"), Some((true, 1..13)));
+    }
+
+    #[test]
+    fn test_parse_cursor_position() {
+        assert_eq!(parse_cursor_position(b""), None);
+        assert_eq!(parse_cursor_position(b"\x1b[12;"), None);
+        assert_eq!(parse_cursor_position(b"\x1b[12;5R"), Some(((4, 11), 0..7)));
+        // Keys typed before the answer are not a part of it
+        assert_eq!(
+            parse_cursor_position(b"a\x1b[A\x1b[1;1R"),
+            Some(((0, 0), 4..10))
+        );
+    }
+
+    #[test]
+    fn test_read_answer() {
+        let timed_out = AtomicBool::new(false);
+        let answer =
+            read_answer(&b"a\x1b[A\x1b[3;7Rb"[..], parse_cursor_position, &timed_out).unwrap();
+        assert_eq!(answer, Some((6, 2)));
+
+        // The keys around the answer are given to the events instead of being dropped
+        let mut events = CrosstermEvents::new();
+        assert_eq!(events.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(events.next_event().unwrap(), KeyCode::Up.into());
+        assert_eq!(
+            events.poll_event().unwrap(),
+            Some(KeyCode::Char('b').into())
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use std::{borrow::Cow, fmt, io};
+
+use super::{Backend, ClearType, MoveDirection, Size};
+use crate::style::{Attributes, Color};
+
+/// A [`Backend`] which calls a function with the text written to it, and passes on the text
+/// returned by the function to the inner backend.
+///
+/// The function is only given the text of the output. Cursor movements, colours and other styles
+/// are passed on to the inner backend without calling it. Styled text written with
+/// [`write_styled`] is also given to the function, without the styles.
+///
+/// This can be used to capture, record or rewrite the output of a prompt without implementing a
+/// new [`Backend`]. To pass on the text unchanged, return it borrowed.
+///
+/// [`write_styled`]: Backend::write_styled
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use requestty_ui::backend::{InspectBackend, TestBackend};
+///
+/// let mut output = Vec::new();
+/// let mut backend = InspectBackend::new(TestBackend::new((20, 5).into()), |text: &[u8]| {
+///     output.extend_from_slice(text);
+///     text.to_ascii_uppercase().into()
+/// });
+///
+/// write!(backend, "Hello, {}!", "World").unwrap();
+/// drop(backend);
+///
+/// assert_eq!(output, b"Hello, World!");
+/// ```
+pub struct InspectBackend<B, F> {
+    backend: B,
+    inspect: F,
+}
+
+impl<B: Backend, F: FnMut(&[u8]) -> Cow<'_, [u8]>> InspectBackend<B, F> {
+    /// Creates a new `InspectBackend` which calls `inspect` with the text written to `backend`,
+    /// and writes the text it returns instead.
+    pub fn new(backend: B, inspect: F) -> Self {
+        Self { backend, inspect }
+    }
+
+    /// A reference to the inner backend.
+    pub fn get_ref(&self) -> &B {
+        &self.backend
+    }
+
+    /// Consumes the `InspectBackend` returning the inner backend.
+    pub fn into_inner(self) -> B {
+        self.backend
+    }
+}
+
+impl<B: fmt::Debug, F> fmt::Debug for InspectBackend<B, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectBackend")
+            .field("backend", &self.backend)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: Backend, F: FnMut(&[u8]) -> Cow<'_, [u8]>> io::Write for InspectBackend<B, F> {
+    /// The text returned by the function may have a different length than `buf`, so it is always
+    /// written completely.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        let text = (self.inspect)(buf);
+        self.backend.write_all(&text)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()
+    }
+}
+
+impl<B: Backend, F: FnMut(&[u8]) -> Cow<'_, [u8]>> Backend for InspectBackend<B, F> {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.enable_raw_mode()
+    }
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.backend.disable_raw_mode()
+    }
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        self.backend.get_cursor_pos()
+    }
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.backend.move_cursor_to(x, y)
+    }
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        self.backend.move_cursor(direction)
+    }
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        self.backend.scroll(dist)
+    }
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        self.backend.set_attributes(attributes)
+    }
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_fg(color)
+    }
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        self.backend.set_bg(color)
+    }
+    // `write_styled` is not forwarded, so that the text goes through `write_all` and is inspected
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear(clear_type)
+    }
+    fn size(&self) -> io::Result<Size> {
+        self.backend.size()
+    }
+    fn supports_keyboard_enhancement(&mut self) -> io::Result<bool> {
+        self.backend.supports_keyboard_enhancement()
+    }
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        self.backend.enable_keyboard_enhancement()
+    }
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        self.backend.disable_keyboard_enhancement()
+    }
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.backend.set_title(title)
+    }
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.backend.restore_title()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{backend::TestBackend, layout::Layout, widgets, Widget};
+
+    #[test]
+    fn test_inspect_backend() {
+        let size = (50, 20).into();
+
+        let mut prompt = widgets::Prompt::new("message").with_hint("hint");
+        let mut expected = TestBackend::new(size);
+        prompt
+            .render(&mut Layout::new(0, size), &mut expected)
+            .unwrap();
+
+        let mut written = 0;
+        let mut backend = InspectBackend::new(TestBackend::new(size), |text: &[u8]| {
+            written += text.len();
+            text.into()
+        });
+        prompt
+            .render(&mut Layout::new(0, size), &mut backend)
+            .unwrap();
+        let backend = backend.into_inner();
+
+        // The prompt renders as "? message (hint) "
+        assert_eq!(written, "? message (hint) ".len());
+        backend.assert_eq(&expected);
+    }
+
+    #[test]
+    fn test_rewrite() {
+        let size = (50, 20).into();
+
+        let mut expected = TestBackend::new(size);
+        widgets::Prompt::new("MESSAGE")
+            .with_hint("HINT")
+            .render(&mut Layout::new(0, size), &mut expected)
+            .unwrap();
+
+        let mut backend = InspectBackend::new(TestBackend::new(size), |text: &[u8]| {
+            text.to_ascii_uppercase().into()
+        });
+        widgets::Prompt::new("message")
+            .with_hint("hint")
+            .render(&mut Layout::new(0, size), &mut backend)
+            .unwrap();
+
+        // Only the text is rewritten, the styles are passed on as is
+        backend.into_inner().assert_eq(&expected);
+    }
+}
@@ -17,8 +17,17 @@ pub fn get_backend<W: io::Write>(buf: W) -> impl Backend {
     return TermionBackend::new(buf);
 }
 
+mod inspect;
+pub use inspect::InspectBackend;
+
+mod record;
+pub use record::RecordBackend;
+
+mod remote;
+pub use remote::RemoteBackend;
+
 mod test_backend;
-pub use test_backend::TestBackend;
+pub use test_backend::{TestBackend, TitleOp};
 
 #[cfg(feature = "termion")]
 mod termion;
@@ -133,8 +142,70 @@ pub trait Backend: io::Write {
     fn clear(&mut self, clear_type: ClearType) -> io::Result<()>;
     /// Gets the size of the terminal in rows and columns.
     fn size(&self) -> io::Result<Size>;
+
+    /// Whether the terminal supports keyboard enhancement (the kitty keyboard protocol).
+    ///
+    /// Such terminals can report key releases and repeats through [`KeyEvent::kind`], and more
+    /// modifier combinations such as `Shift+Enter`. Other terminals only report key presses.
+    ///
+    /// [`Input`] turns keyboard enhancement on with [`enable_keyboard_enhancement`] while it runs
+    /// if this returns `true`. By default, this returns `false`.
+    ///
+    /// [`KeyEvent::kind`]: crate::events::KeyEvent::kind
+    /// [`Input`]: crate::Input
+    /// [`enable_keyboard_enhancement`]: Backend::enable_keyboard_enhancement
+    fn supports_keyboard_enhancement(&mut self) -> io::Result<bool> {
+        Ok(false)
+    }
+
+    /// Turns keyboard enhancement on, so that the terminal starts reporting the extra events.
+    ///
+    /// This is only called if [`supports_keyboard_enhancement`] returns `true`, and every call is
+    /// followed by a call to [`disable_keyboard_enhancement`]. By default, this does nothing.
+    ///
+    /// [`supports_keyboard_enhancement`]: Backend::supports_keyboard_enhancement
+    /// [`disable_keyboard_enhancement`]: Backend::disable_keyboard_enhancement
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Turns off the keyboard enhancement turned on by the last [`enable_keyboard_enhancement`].
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`enable_keyboard_enhancement`]: Backend::enable_keyboard_enhancement
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Sets the title of the terminal window.
+    ///
+    /// The previous title is saved, so that it can be brought back with [`restore_title`]. Every
+    /// call to `set_title` should be followed by a call to [`restore_title`].
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`restore_title`]: Backend::restore_title
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        let _ = title;
+        Ok(())
+    }
+
+    /// Restores the title of the terminal window saved by the last [`set_title`].
+    ///
+    /// By default, this does nothing.
+    ///
+    /// [`set_title`]: Backend::set_title
+    fn restore_title(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
+/// Saves the window title on to the title stack of the terminal (XTWINOPS 22)
+const SAVE_TITLE: &[u8] = b"\x1b[22;0t";
+/// Restores the window title from the title stack of the terminal (XTWINOPS 23)
+const RESTORE_TITLE: &[u8] = b"\x1b[23;0t";
+
 fn default_move_cursor<B: Backend + ?Sized>(
     backend: &mut B,
     direction: MoveDirection,
@@ -203,4 +274,19 @@ impl<'a, B: Backend> Backend for &'a mut B {
     fn size(&self) -> io::Result<Size> {
         (**self).size()
     }
+    fn supports_keyboard_enhancement(&mut self) -> io::Result<bool> {
+        (**self).supports_keyboard_enhancement()
+    }
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        (**self).enable_keyboard_enhancement()
+    }
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        (**self).disable_keyboard_enhancement()
+    }
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        (**self).set_title(title)
+    }
+    fn restore_title(&mut self) -> io::Result<()> {
+        (**self).restore_title()
+    }
 }
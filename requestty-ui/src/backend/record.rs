@@ -0,0 +1,219 @@
+use std::io;
+
+use super::{Backend, ClearType, MoveDirection, Size};
+use crate::style::{Attributes, Color};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Op {
+    Write(Vec<u8>),
+    EnableRawMode,
+    DisableRawMode,
+    HideCursor,
+    ShowCursor,
+    MoveCursorTo(u16, u16),
+    MoveCursor(MoveDirection),
+    Scroll(i16),
+    SetAttributes(Attributes),
+    SetFg(Color),
+    SetBg(Color),
+    Clear(ClearType),
+    SetTitle(String),
+    RestoreTitle,
+}
+
+/// A [`Backend`] which records what is written to it, so that it can be written to another
+/// backend later.
+///
+/// This is useful when the output has to be measured before it is written. Since the recorded
+/// output is not shown anywhere, the position of the cursor is not known and
+/// [`get_cursor_pos`](Backend::get_cursor_pos) always fails.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use requestty_ui::{
+///     backend::{Backend, RecordBackend, TestBackend},
+///     style::Stylize,
+/// };
+///
+/// let mut record = RecordBackend::new((20, 5).into());
+/// record.write_styled(&"Hello".cyan()).unwrap();
+/// write!(record, ", World!").unwrap();
+///
+/// assert_eq!(record.width(), Some(13));
+///
+/// let mut backend = TestBackend::new((20, 5).into());
+/// record.replay(&mut backend).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordBackend {
+    size: Size,
+    ops: Vec<Op>,
+}
+
+impl RecordBackend {
+    /// Creates a new `RecordBackend` which reports the given `size` as the size of the terminal.
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            ops: Vec::new(),
+        }
+    }
+
+    /// The number of columns taken by the recorded text.
+    ///
+    /// This is `None` if the width cannot be known from the text alone, that is if the text spans
+    /// multiple lines, or if anything other than text and styles were recorded.
+    pub fn width(&self) -> Option<u16> {
+        let mut width = 0;
+
+        for op in &self.ops {
+            match op {
+                Op::Write(text) => {
+                    let text = std::str::from_utf8(text).ok()?;
+                    if text.contains(&['\n', '\r'][..]) {
+                        return None;
+                    }
+                    width += textwrap::core::display_width(text);
+                }
+                Op::SetAttributes(_) | Op::SetFg(_) | Op::SetBg(_) => {}
+                _ => return None,
+            }
+        }
+
+        std::convert::TryFrom::try_from(width).ok()
+    }
+
+    /// Writes everything that was recorded to the given `backend`.
+    pub fn replay<B: Backend + ?Sized>(&self, backend: &mut B) -> io::Result<()> {
+        self.replay_moved_down(0, backend)
+    }
+
+    /// Writes everything that was recorded to the given `backend`, with the cursor moved
+    /// `offset_y` rows further down than where it was recorded.
+    pub(crate) fn replay_moved_down<B: Backend + ?Sized>(
+        &self,
+        offset_y: u16,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        for op in &self.ops {
+            match *op {
+                Op::Write(ref text) => backend.write_all(text)?,
+                Op::EnableRawMode => backend.enable_raw_mode()?,
+                Op::DisableRawMode => backend.disable_raw_mode()?,
+                Op::HideCursor => backend.hide_cursor()?,
+                Op::ShowCursor => backend.show_cursor()?,
+                Op::MoveCursorTo(x, y) => backend.move_cursor_to(x, y + offset_y)?,
+                Op::MoveCursor(direction) => backend.move_cursor(direction)?,
+                Op::Scroll(dist) => backend.scroll(dist)?,
+                Op::SetAttributes(attributes) => backend.set_attributes(attributes)?,
+                Op::SetFg(color) => backend.set_fg(color)?,
+                Op::SetBg(color) => backend.set_bg(color)?,
+                Op::Clear(clear_type) => backend.clear(clear_type)?,
+                Op::SetTitle(ref title) => backend.set_title(title)?,
+                Op::RestoreTitle => backend.restore_title()?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push(&mut self, op: Op) -> io::Result<()> {
+        self.ops.push(op);
+        Ok(())
+    }
+}
+
+impl io::Write for RecordBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self.ops.last_mut() {
+            Some(Op::Write(text)) => text.extend_from_slice(buf),
+            _ => self.ops.push(Op::Write(buf.to_vec())),
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Backend for RecordBackend {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.push(Op::EnableRawMode)
+    }
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.push(Op::DisableRawMode)
+    }
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.push(Op::HideCursor)
+    }
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.push(Op::ShowCursor)
+    }
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        // The recorded output is not shown anywhere, so there is no cursor
+        Err(io::ErrorKind::Unsupported.into())
+    }
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.push(Op::MoveCursorTo(x, y))
+    }
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        self.push(Op::MoveCursor(direction))
+    }
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        self.push(Op::Scroll(dist))
+    }
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        self.push(Op::SetAttributes(attributes))
+    }
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        self.push(Op::SetFg(color))
+    }
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        self.push(Op::SetBg(color))
+    }
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.push(Op::Clear(clear_type))
+    }
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.push(Op::SetTitle(title.to_owned()))
+    }
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.push(Op::RestoreTitle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{backend::TestBackend, style::Stylize};
+
+    #[test]
+    fn test_record_backend() {
+        let size = (20, 3).into();
+
+        let mut expected = TestBackend::new(size);
+        expected.write_styled(&"cyan".cyan()).unwrap();
+        expected.write_all(b" text").unwrap();
+
+        let mut record = RecordBackend::new(size);
+        record.write_styled(&"cyan".cyan()).unwrap();
+        record.write_all(b" text").unwrap();
+        assert_eq!(record.width(), Some(9));
+
+        let mut backend = TestBackend::new(size);
+        record.replay(&mut backend).unwrap();
+        backend.assert_eq(&expected);
+
+        record.write_all(b"\nmore").unwrap();
+        assert_eq!(record.width(), None);
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    fmt,
+    io::{self, Read, Write},
+    rc::Rc,
+};
+
+use super::{
+    Attributes, Backend, ClearType, Color, MoveDirection, Size, RESTORE_TITLE, SAVE_TITLE,
+};
+use crate::events::{Decoder, RemoteEvents};
+
+/// A backend that writes escape sequences to any [`Write`], and reads what the terminal sends
+/// from any [`Read`].
+///
+/// This can be used to show prompts on a terminal other than the local one, for example over a
+/// network stream or on the other end of a PTY. The keys are read with the [`RemoteEvents`] from
+/// [`events`](RemoteBackend::events).
+///
+/// Since there is no local terminal to query, the size of the terminal is given instead. It should
+/// be updated with [`set_size`](RemoteBackend::set_size) when the remote terminal is resized. The
+/// position of the cursor is requested from the remote terminal, and its reply is read from the
+/// input stream.
+///
+/// Escape sequences may be split between reads of the input stream, so an escape at the end of a
+/// read is only taken to be the escape key once the next read does not continue a sequence.
+///
+/// Raw mode cannot be controlled through the streams, so enabling and disabling it does nothing.
+/// It is up to the remote side to set up its terminal, for example with the modes of the PTY.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{backend::RemoteBackend, events::EventIterator};
+///
+/// // The input of the remote terminal, which would usually be a stream
+/// let input: &[u8] = b"\x1b[A";
+///
+/// let mut backend = RemoteBackend::new(input, Vec::new(), (80, 24).into());
+/// let mut events = backend.events();
+///
+/// assert_eq!(
+///     events.next_event().unwrap(),
+///     requestty_ui::events::KeyCode::Up.into()
+/// );
+/// ```
+pub struct RemoteBackend<R, W> {
+    decoder: Rc<RefCell<Decoder<R>>>,
+    writer: W,
+    size: Size,
+    attributes: Attributes,
+}
+
+impl<R: Read, W: Write> RemoteBackend<R, W> {
+    /// Creates a new `RemoteBackend` which reads from `reader` and writes to `writer`, for a
+    /// terminal of the given `size`.
+    pub fn new(reader: R, writer: W, size: Size) -> Self {
+        Self {
+            decoder: Rc::new(RefCell::new(Decoder::new(reader))),
+            writer,
+            size,
+            attributes: Attributes::empty(),
+        }
+    }
+
+    /// The keys sent by the remote terminal.
+    ///
+    /// The returned [`RemoteEvents`] shares the input stream with the backend.
+    pub fn events(&self) -> RemoteEvents<R> {
+        RemoteEvents::new(Rc::clone(&self.decoder))
+    }
+
+    /// Sets the size of the remote terminal.
+    pub fn set_size(&mut self, size: Size) {
+        self.size = size;
+    }
+
+    /// A reference to the writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `RemoteBackend` returning the writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<R, W: fmt::Debug> fmt::Debug for RemoteBackend<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemoteBackend")
+            .field("writer", &self.writer)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R, W: Write> Write for RemoteBackend<R, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<R: Read, W: Write> Backend for RemoteBackend<R, W> {
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1b[?25l")
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.writer.write_all(b"\x1b[?25h")
+    }
+
+    fn get_cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        // Device status report, to which the terminal replies with the position of the cursor
+        self.writer.write_all(b"\x1b[6n")?;
+        self.writer.flush()?;
+        self.decoder.borrow_mut().cursor_pos()
+    }
+
+    fn move_cursor_to(&mut self, x: u16, y: u16) -> io::Result<()> {
+        write!(self.writer, "\x1b[{};{}H", y + 1, x + 1)
+    }
+
+    fn move_cursor(&mut self, direction: MoveDirection) -> io::Result<()> {
+        match direction {
+            MoveDirection::Up(n) => write!(self.writer, "\x1b[{}A", n),
+            MoveDirection::Down(n) => write!(self.writer, "\x1b[{}B", n),
+            MoveDirection::Right(n) => write!(self.writer, "\x1b[{}C", n),
+            MoveDirection::Left(n) => write!(self.writer, "\x1b[{}D", n),
+            MoveDirection::NextLine(n) => write!(self.writer, "\x1b[{}E", n),
+            MoveDirection::PrevLine(n) => write!(self.writer, "\x1b[{}F", n),
+            MoveDirection::Column(n) => write!(self.writer, "\x1b[{}G", n + 1),
+        }
+    }
+
+    fn scroll(&mut self, dist: i16) -> io::Result<()> {
+        match dist.cmp(&0) {
+            Ordering::Greater => write!(self.writer, "\x1b[{}T", dist),
+            Ordering::Less => write!(self.writer, "\x1b[{}S", -dist),
+            Ordering::Equal => Ok(()),
+        }
+    }
+
+    fn set_attributes(&mut self, attributes: Attributes) -> io::Result<()> {
+        set_attributes(self.attributes, attributes, &mut self.writer)?;
+        self.attributes = attributes;
+        Ok(())
+    }
+
+    fn set_fg(&mut self, color: Color) -> io::Result<()> {
+        write_color(color, false, &mut self.writer)
+    }
+
+    fn set_bg(&mut self, color: Color) -> io::Result<()> {
+        write_color(color, true, &mut self.writer)
+    }
+
+    fn clear(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.writer.write_all(match clear_type {
+            ClearType::All => b"\x1b[2J",
+            ClearType::FromCursorDown => b"\x1b[J",
+            ClearType::FromCursorUp => b"\x1b[1J",
+            ClearType::CurrentLine => b"\x1b[2K",
+            ClearType::UntilNewLine => b"\x1b[K",
+        })
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        // Push the current title on to the title stack, so that it can be restored
+        self.writer.write_all(SAVE_TITLE)?;
+        // Control characters such as `ESC` and `BEL` would end the sequence early
+        let title: String = title.chars().filter(|c| !c.is_control()).collect();
+        write!(self.writer, "\x1b]0;{}\x07", title)
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.writer.write_all(RESTORE_TITLE)
+    }
+}
+
+fn write_color<W: Write>(color: Color, bg: bool, mut w: W) -> io::Result<()> {
+    // The background colours are the foreground ones offset by 10
+    let offset = if bg { 10 } else { 0 };
+
+    let code = match color {
+        Color::Reset => 39,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Grey => 37,
+        Color::DarkGrey => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Ansi(i) => return write!(w, "\x1b[{};5;{}m", 38 + offset, i),
+        Color::Rgb(r, g, b) => return write!(w, "\x1b[{};2;{};{};{}m", 38 + offset, r, g, b),
+    };
+
+    write!(w, "\x1b[{}m", code + offset)
+}
+
+fn set_attributes<W: Write>(from: Attributes, to: Attributes, mut w: W) -> io::Result<()> {
+    let diff = from.diff(to);
+
+    let sgr = |w: &mut W, code: u8| write!(w, "\x1b[{}m", code);
+
+    if diff.to_remove.contains(Attributes::REVERSED) {
+        sgr(&mut w, 27)?;
+    }
+    if diff.to_remove.contains(Attributes::BOLD) || diff.to_remove.contains(Attributes::DIM) {
+        // Bold and dim are both removed by normal intensity
+        sgr(&mut w, 22)?;
+        if to.contains(Attributes::DIM) {
+            sgr(&mut w, 2)?;
+        }
+        if to.contains(Attributes::BOLD) {
+            sgr(&mut w, 1)?;
+        }
+    }
+    if diff.to_remove.contains(Attributes::ITALIC) {
+        sgr(&mut w, 23)?;
+    }
+    if diff.to_remove.contains(Attributes::UNDERLINED) {
+        sgr(&mut w, 24)?;
+    }
+    if diff.to_remove.contains(Attributes::CROSSED_OUT) {
+        sgr(&mut w, 29)?;
+    }
+    if diff.to_remove.contains(Attributes::HIDDEN) {
+        sgr(&mut w, 28)?;
+    }
+    if diff.to_remove.contains(Attributes::SLOW_BLINK)
+        || diff.to_remove.contains(Attributes::RAPID_BLINK)
+    {
+        sgr(&mut w, 25)?;
+    }
+
+    if diff.to_add.contains(Attributes::REVERSED) {
+        sgr(&mut w, 7)?;
+    }
+    if diff.to_add.contains(Attributes::BOLD) {
+        sgr(&mut w, 1)?;
+    }
+    if diff.to_add.contains(Attributes::ITALIC) {
+        sgr(&mut w, 3)?;
+    }
+    if diff.to_add.contains(Attributes::UNDERLINED) {
+        sgr(&mut w, 4)?;
+    }
+    if diff.to_add.contains(Attributes::DIM) {
+        sgr(&mut w, 2)?;
+    }
+    if diff.to_add.contains(Attributes::CROSSED_OUT) {
+        sgr(&mut w, 9)?;
+    }
+    if diff.to_add.contains(Attributes::HIDDEN) {
+        sgr(&mut w, 8)?;
+    }
+    if diff.to_add.contains(Attributes::SLOW_BLINK) {
+        sgr(&mut w, 5)?;
+    }
+    if diff.to_add.contains(Attributes::RAPID_BLINK) {
+        sgr(&mut w, 6)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use crate::{
+        events::EventIterator,
+        widgets::{SimplePrompt, StringInput},
+        Input, Validation,
+    };
+
+    type Pipe = Rc<RefCell<VecDeque<u8>>>;
+
+    /// The end of an in-memory pipe the keys are read from.
+    struct PipeReader(Pipe);
+
+    impl Read for PipeReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.0.borrow_mut().read(buf)
+        }
+    }
+
+    /// The end of an in-memory pipe the output is written to. Like a terminal, it replies to
+    /// requests for the cursor position on the input pipe.
+    struct PipeWriter {
+        output: Vec<u8>,
+        input: Pipe,
+    }
+
+    impl Write for PipeWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if buf.windows(4).any(|w| w == b"\x1b[6n") {
+                self.input.borrow_mut().extend(b"\x1b[3;1R");
+            }
+            self.output.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_remote_backend() {
+        let input = Pipe::default();
+        input.borrow_mut().extend(b"Jo\x1b[D\x7f\r");
+
+        let mut backend = RemoteBackend::new(
+            PipeReader(Rc::clone(&input)),
+            PipeWriter {
+                output: Vec::new(),
+                input,
+            },
+            (40, 10).into(),
+        );
+        let mut events = backend.events();
+
+        let prompt = SimplePrompt::new(
+            "Name?",
+            StringInput::new(),
+            |_: &StringInput| Ok(Validation::Finish),
+            |input: StringInput| input.finish(),
+        );
+
+        let name = Input::new(prompt, &mut backend).run(&mut events).unwrap();
+        // The `J` is deleted since the cursor was moved before it
+        assert_eq!(name.as_deref(), Some("o"));
+
+        let output = String::from_utf8(backend.into_inner().output).unwrap();
+        assert!(output.contains("\x1b[6n"));
+        assert!(output.contains("Name?"));
+        // The prompt is rendered from the row the cursor was reported at
+        assert!(output.contains("\x1b[3;1H"));
+
+        // The input ran out, which is the same as the stream being closed
+        assert_eq!(
+            events.next_event().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn test_set_title() {
+        let mut backend = RemoteBackend::new(io::empty(), Vec::new(), (40, 10).into());
+        backend.set_title("a\x1b]0;b\x07c\u{9c}d").unwrap();
+
+        let mut expected = SAVE_TITLE.to_vec();
+        expected.extend_from_slice(b"\x1b]0;a]0;bcd\x07");
+        assert_eq!(backend.into_inner(), expected);
+    }
+}
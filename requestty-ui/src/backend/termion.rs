@@ -11,7 +11,9 @@ use termion::{
     scroll, style,
 };
 
-use super::{Attributes, Backend, ClearType, Color, MoveDirection, Size};
+use super::{
+    Attributes, Backend, ClearType, Color, MoveDirection, Size, RESTORE_TITLE, SAVE_TITLE,
+};
 
 enum Terminal<W: Write> {
     Raw(RawTerminal<W>),
@@ -173,6 +175,16 @@ impl<W: Write> Backend for TermionBackend<W> {
         }
     }
 
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        // Push the current title on to the title stack, so that it can be restored
+        self.buffer.write_all(SAVE_TITLE)?;
+        write!(self.buffer, "\x1b]0;{}\x07", title)
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.buffer.write_all(RESTORE_TITLE)
+    }
+
     fn size(&self) -> io::Result<Size> {
         termion::terminal_size().map(Into::into)
     }
@@ -46,6 +46,15 @@ impl From<Cursor> for (u16, u16) {
     }
 }
 
+/// A change to the window title recorded by a [`TestBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TitleOp {
+    /// The title was set with [`Backend::set_title`].
+    SetTitle(String),
+    /// The title was restored with [`Backend::restore_title`].
+    RestoreTitle,
+}
+
 /// A backend that can be used for tests.
 ///
 /// When asserting equality, it is recommended to use [`TestBackend::assert_eq`] or
@@ -63,6 +72,9 @@ pub struct TestBackend {
     current_bg: Color,
     current_attributes: Attributes,
     viewport_start: usize,
+    title_ops: Vec<TitleOp>,
+    // `None` if keyboard enhancement is not supported, otherwise whether it is enabled
+    keyboard_enhancement: Option<bool>,
 }
 
 impl PartialEq for TestBackend {
@@ -98,6 +110,8 @@ impl TestBackend {
             current_bg: Color::Reset,
             current_attributes: Attributes::empty(),
             viewport_start: 0,
+            title_ops: Vec::new(),
+            keyboard_enhancement: None,
         };
 
         this.move_x(layout.line_offset + layout.offset_x);
@@ -143,6 +157,29 @@ impl TestBackend {
         self.move_y(layout.offset_y);
     }
 
+    /// The changes made to the window title, in the order they were made.
+    ///
+    /// The title is not drawn in the cells, so it is not considered when comparing backends.
+    pub fn title_ops(&self) -> &[TitleOp] {
+        &self.title_ops
+    }
+
+    /// Makes the backend report that it supports keyboard enhancement.
+    ///
+    /// See [`Backend::supports_keyboard_enhancement`].
+    pub fn with_keyboard_enhancement(mut self) -> Self {
+        self.keyboard_enhancement = Some(false);
+        self
+    }
+
+    /// Whether keyboard enhancement is enabled at the moment.
+    ///
+    /// It can only be enabled if the backend was created
+    /// [`with_keyboard_enhancement`](TestBackend::with_keyboard_enhancement).
+    pub fn is_keyboard_enhanced(&self) -> bool {
+        self.keyboard_enhancement == Some(true)
+    }
+
     fn viewport(&self) -> &[Cell] {
         &self.cells[self.viewport_start..(self.viewport_start + self.size.area() as usize)]
     }
@@ -385,6 +422,34 @@ impl super::Backend for TestBackend {
     fn size(&self) -> io::Result<Size> {
         Ok(self.size)
     }
+
+    fn supports_keyboard_enhancement(&mut self) -> io::Result<bool> {
+        Ok(self.keyboard_enhancement.is_some())
+    }
+
+    fn enable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        if let Some(ref mut enhanced) = self.keyboard_enhancement {
+            *enhanced = true;
+        }
+        Ok(())
+    }
+
+    fn disable_keyboard_enhancement(&mut self) -> io::Result<()> {
+        if let Some(ref mut enhanced) = self.keyboard_enhancement {
+            *enhanced = false;
+        }
+        Ok(())
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.title_ops.push(TitleOp::SetTitle(title.to_owned()));
+        Ok(())
+    }
+
+    fn restore_title(&mut self) -> io::Result<()> {
+        self.title_ops.push(TitleOp::RestoreTitle);
+        Ok(())
+    }
 }
 
 #[cfg(any(feature = "crossterm", feature = "termion"))]
@@ -0,0 +1,268 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent, KeyModifiers},
+    layout::Layout,
+    widgets::{self, StringInput, Widget},
+    Prompt, Validation,
+};
+
+/// A value shown on a single line, which can be edited in place.
+///
+/// The value is first only displayed, and keys other than `e` are not handled. Pressing `e`
+/// switches to editing the value with a [`StringInput`]. While editing, `Enter` accepts the edited
+/// value and `Esc` cancels the edit, going back to the value from before it. Either way, the value
+/// is displayed again afterwards.
+///
+/// When it is used as a [`Prompt`], `Enter` accepts the edit if the value is being edited, and
+/// otherwise submits the value. `Esc` only reaches the widget if [`Input::on_esc`] is left as
+/// [`OnEsc::Ignore`].
+///
+/// [`Input::on_esc`]: crate::Input::on_esc
+/// [`OnEsc::Ignore`]: crate::OnEsc::Ignore
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{events::KeyCode, widgets::EditableValue, Widget};
+///
+/// let mut value = EditableValue::new("localhost".to_owned());
+/// assert!(!value.is_editing());
+///
+/// value.handle_key(KeyCode::Char('e').into());
+/// value.handle_key(KeyCode::Char('!').into());
+/// value.handle_key(KeyCode::Enter.into());
+///
+/// assert_eq!(value.value(), "localhost!");
+/// ```
+#[derive(Debug)]
+pub struct EditableValue {
+    value: String,
+    // The input is only there while the value is being edited
+    input: Option<StringInput>,
+}
+
+impl EditableValue {
+    /// Creates a new `EditableValue` which displays `value`.
+    pub fn new(value: String) -> Self {
+        Self { value, input: None }
+    }
+
+    /// The value as it was last accepted. Changes which are still being edited are not included.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Whether the value is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.input.is_some()
+    }
+
+    /// Consumes the `EditableValue` returning the value as it was last accepted.
+    pub fn finish(self) -> String {
+        self.value
+    }
+
+    /// The number of columns taken by the value while it is displayed.
+    fn displayed_width(&self, layout: &Layout) -> u16 {
+        // The last column is left for the cursor
+        let max_width = layout.line_width().saturating_sub(1) as usize;
+        widgets::truncate_with(&self.value, max_width, |_| Ok(())).unwrap_or_default() as u16
+    }
+}
+
+impl Widget for EditableValue {
+    /// The value is cut short with '...' if it does not fit on the line while it is displayed.
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        match self.input {
+            Some(ref mut input) => input.render(layout, backend),
+            None => {
+                let max_width = layout.line_width().saturating_sub(1) as usize;
+                let written = widgets::write_truncated(&self.value, max_width, backend)?;
+                layout.line_offset += written as u16;
+                Ok(())
+            }
+        }
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let width = self.displayed_width(layout);
+
+        match self.input {
+            Some(ref mut input) => input.height(layout),
+            None => {
+                layout.line_offset += width;
+                1
+            }
+        }
+    }
+
+    /// Returns the position of the cursor in the input while editing, and the position right
+    /// after the value otherwise.
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let width = self.displayed_width(&layout);
+
+        match self.input {
+            Some(ref mut input) => input.cursor_pos(layout),
+            None => layout.offset_cursor((layout.line_offset + width, 0)),
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let input = match self.input {
+            Some(ref mut input) => input,
+            None => {
+                if key.code == KeyCode::Char('e')
+                    && !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                {
+                    self.input = Some(StringInput::new().with_initial_value(self.value.clone()));
+                    return true;
+                }
+
+                return false;
+            }
+        };
+
+        match key.code {
+            KeyCode::Enter => {
+                if let Some(input) = self.input.take() {
+                    self.value = input.finish();
+                }
+                true
+            }
+            KeyCode::Esc => {
+                self.input = None;
+                true
+            }
+            _ => input.handle_key(key),
+        }
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        match self.input {
+            Some(ref mut input) => input.handle_paste(text),
+            None => false,
+        }
+    }
+
+    fn wants_cursor(&self) -> bool {
+        self.is_editing()
+    }
+}
+
+impl Prompt for EditableValue {
+    type ValidateErr = &'static str;
+    type Output = String;
+
+    /// Accepts the edit if the value is being edited, and submits the value otherwise.
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        if self.handle_key(KeyCode::Enter.into()) {
+            Ok(Validation::Continue)
+        } else {
+            Ok(Validation::Finish)
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{backend::TestBackend, events::TestEvents, Input};
+
+    fn typed(value: &mut EditableValue, text: &str) {
+        for c in text.chars() {
+            value.handle_key(KeyCode::Char(c).into());
+        }
+    }
+
+    #[test]
+    fn test_accept_and_cancel() {
+        let mut value = EditableValue::new("abc".to_owned());
+
+        // Nothing is edited until `e` is pressed
+        assert!(!value.handle_key(KeyCode::Left.into()));
+        assert!(!value.handle_key(KeyCode::Char('x').into()));
+        assert!(!value.handle_key(KeyCode::Enter.into()));
+        assert!(!value.is_editing());
+        assert!(!value.wants_cursor());
+
+        assert!(value.handle_key(KeyCode::Char('e').into()));
+        assert!(value.is_editing());
+        assert!(value.wants_cursor());
+        typed(&mut value, "def");
+        assert_eq!(value.value(), "abc");
+
+        assert!(value.handle_key(KeyCode::Esc.into()));
+        assert!(!value.is_editing());
+        assert_eq!(value.value(), "abc");
+
+        // The edit starts from the current value, not the cancelled one
+        assert!(value.handle_key(KeyCode::Char('e').into()));
+        assert!(value.handle_key(KeyCode::Backspace.into()));
+        typed(&mut value, "xy");
+        assert!(value.handle_key(KeyCode::Enter.into()));
+        assert!(!value.is_editing());
+        assert_eq!(value.value(), "abxy");
+
+        // Keys are ignored again once the edit is accepted
+        assert!(!value.handle_key(KeyCode::Char('x').into()));
+        assert_eq!(value.finish(), "abxy");
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (20, 5).into();
+        let layout = Layout::new(5, size);
+
+        let mut value = EditableValue::new("A very long value".to_owned());
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        let mut rendered_layout = layout;
+        value.render(&mut rendered_layout, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new_with_layout(size, layout);
+        expected.write_all(b"A very long...").unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(rendered_layout, layout.with_line_offset(19));
+        let mut l = layout;
+        assert_eq!(value.height(&mut l), 1);
+        assert_eq!(l, layout.with_line_offset(19));
+        assert_eq!(value.cursor_pos(layout), (19, 0));
+
+        value.handle_key(KeyCode::Char('e').into());
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        let mut rendered_layout = layout;
+        value.render(&mut rendered_layout, &mut backend).unwrap();
+        // The whole value is shown while editing, wrapping onto the next line
+        assert!(backend.to_string().contains("A very long val"));
+        let mut l = layout;
+        assert_eq!(value.height(&mut l), 2);
+    }
+
+    #[test]
+    fn test_prompt() {
+        let mut backend = TestBackend::new((50, 10).into());
+        let mut events = TestEvents::new(vec![
+            KeyCode::Char('e').into(),
+            KeyCode::Char('!').into(),
+            // The first enter only accepts the edit
+            KeyCode::Enter.into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let value = Input::new(EditableValue::new("hi".to_owned()), &mut backend)
+            .run(&mut events)
+            .unwrap();
+
+        assert_eq!(value.as_deref(), Some("hi!"));
+    }
+}
@@ -1,31 +1,136 @@
-use std::convert::{TryFrom, TryInto};
+use std::{
+    collections::VecDeque,
+    convert::{TryFrom, TryInto},
+    sync::Mutex,
+    time::Duration,
+};
 
 use crossterm::event;
+use once_cell::sync::Lazy;
 
-use super::EventIterator;
+use super::{Event, EventIterator};
+
+/// Keys typed while the backend was waiting for the terminal to answer a query. They are returned
+/// before anything else is read, so that they are not lost.
+static TYPED_AHEAD: Lazy<Mutex<VecDeque<super::KeyEvent>>> = Lazy::new(Default::default);
+
+/// Keeps the keys in `bytes`, which were read from the terminal along with the answer to a query,
+/// for [`CrosstermEvents`].
+pub(crate) fn push_typed_ahead(bytes: &[u8]) {
+    TYPED_AHEAD
+        .lock()
+        .expect("typed ahead keys poisoned")
+        .extend(super::remote::decode_keys(bytes));
+}
+
+fn pop_typed_ahead() -> Option<super::KeyEvent> {
+    TYPED_AHEAD
+        .lock()
+        .expect("typed ahead keys poisoned")
+        .pop_front()
+}
 
 /// An iterator over the input keys using the `crossterm` crate
+///
+/// Text pasted into a terminal with bracketed paste enabled is returned all at once by
+/// [`next_input`](EventIterator::next_input), and one character at a time by
+/// [`next_event`](EventIterator::next_event).
 #[derive(Debug, Default)]
 #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
-#[non_exhaustive]
-pub struct CrosstermEvents {}
+pub struct CrosstermEvents {
+    /// Pasted text which has not been returned yet
+    paste: VecDeque<char>,
+}
 
 impl CrosstermEvents {
     /// Creates a new `CrosstermEvents`
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Reads events until there is a key or a paste.
+    fn read(&mut self) -> std::io::Result<Event> {
+        if let Some(k) = pop_typed_ahead() {
+            return Ok(Event::Key(k));
+        }
+
+        loop {
+            match event::read()? {
+                event::Event::Key(k) => {
+                    if let Ok(k) = k.try_into() {
+                        return Ok(Event::Key(k));
+                    }
+                }
+                event::Event::Paste(text) => return Ok(Event::Paste(text)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// The key typed for a character of pasted text
+fn paste_key(c: char) -> super::KeyEvent {
+    match c {
+        '\n' | '\r' => super::KeyCode::Enter.into(),
+        c => super::KeyCode::Char(c).into(),
     }
 }
 
 impl EventIterator for CrosstermEvents {
     fn next_event(&mut self) -> std::io::Result<super::KeyEvent> {
-        loop {
-            if let event::Event::Key(k) = event::read()? {
-                if let Ok(k) = k.try_into() {
-                    return Ok(k);
+        if let Some(c) = self.paste.pop_front() {
+            return Ok(paste_key(c));
+        }
+
+        match self.read()? {
+            Event::Key(k) => Ok(k),
+            Event::Paste(text) => {
+                self.paste.extend(text.chars());
+                self.next_event()
+            }
+        }
+    }
+
+    fn next_input(&mut self) -> std::io::Result<Event> {
+        if !self.paste.is_empty() {
+            return Ok(Event::Paste(self.paste.drain(..).collect()));
+        }
+
+        self.read()
+    }
+
+    /// A paste is never returned by this, it is kept for the next call to `next_event` or
+    /// `next_input` instead.
+    fn poll_event(&mut self) -> std::io::Result<Option<super::KeyEvent>> {
+        if let Some(k) = pop_typed_ahead() {
+            return Ok(Some(k));
+        }
+
+        while self.paste.is_empty() && event::poll(Duration::ZERO)? {
+            match event::read()? {
+                event::Event::Key(k) => {
+                    if let Ok(k) = k.try_into() {
+                        return Ok(Some(k));
+                    }
                 }
+                event::Event::Paste(text) => self.paste.extend(text.chars()),
+                _ => {}
             }
         }
+
+        Ok(None)
+    }
+}
+
+impl super::KeyEvent {
+    /// Converts the key to a [`crossterm::event::KeyEvent`](event::KeyEvent).
+    ///
+    /// This is the same as the [`From`] implementation, and is useful when passing keys to code
+    /// which already uses `crossterm`. Keys can be converted back with [`TryFrom`], which fails for
+    /// the keys which are not supported by this crate.
+    #[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+    pub fn as_crossterm(&self) -> event::KeyEvent {
+        (*self).into()
     }
 }
 
@@ -66,7 +171,113 @@ impl TryFrom<event::KeyEvent> for super::KeyEvent {
         if event.modifiers.contains(event::KeyModifiers::ALT) {
             modifiers |= super::KeyModifiers::ALT;
         }
+        if event.modifiers.contains(event::KeyModifiers::SUPER) {
+            modifiers |= super::KeyModifiers::SUPER;
+        }
+        if event.modifiers.contains(event::KeyModifiers::HYPER) {
+            modifiers |= super::KeyModifiers::HYPER;
+        }
+        if event.modifiers.contains(event::KeyModifiers::META) {
+            modifiers |= super::KeyModifiers::META;
+        }
+
+        let kind = match event.kind {
+            event::KeyEventKind::Press => super::KeyEventKind::Press,
+            event::KeyEventKind::Repeat => super::KeyEventKind::Repeat,
+            event::KeyEventKind::Release => super::KeyEventKind::Release,
+        };
+
+        Ok(super::KeyEvent {
+            code,
+            modifiers,
+            kind,
+        })
+    }
+}
+
+impl From<super::KeyEvent> for event::KeyEvent {
+    fn from(event: super::KeyEvent) -> Self {
+        let code = match event.code {
+            super::KeyCode::Backspace => event::KeyCode::Backspace,
+            super::KeyCode::Enter => event::KeyCode::Enter,
+            super::KeyCode::Left => event::KeyCode::Left,
+            super::KeyCode::Right => event::KeyCode::Right,
+            super::KeyCode::Up => event::KeyCode::Up,
+            super::KeyCode::Down => event::KeyCode::Down,
+            super::KeyCode::Home => event::KeyCode::Home,
+            super::KeyCode::End => event::KeyCode::End,
+            super::KeyCode::PageUp => event::KeyCode::PageUp,
+            super::KeyCode::PageDown => event::KeyCode::PageDown,
+            super::KeyCode::Tab => event::KeyCode::Tab,
+            super::KeyCode::BackTab => event::KeyCode::BackTab,
+            super::KeyCode::Delete => event::KeyCode::Delete,
+            super::KeyCode::Insert => event::KeyCode::Insert,
+            super::KeyCode::F(f) => event::KeyCode::F(f),
+            super::KeyCode::Char(c) => event::KeyCode::Char(c),
+            super::KeyCode::Null => event::KeyCode::Null,
+            super::KeyCode::Esc => event::KeyCode::Esc,
+        };
+
+        let mut modifiers = event::KeyModifiers::empty();
+
+        if event.modifiers.contains(super::KeyModifiers::SHIFT) {
+            modifiers |= event::KeyModifiers::SHIFT;
+        }
+        if event.modifiers.contains(super::KeyModifiers::CONTROL) {
+            modifiers |= event::KeyModifiers::CONTROL;
+        }
+        if event.modifiers.contains(super::KeyModifiers::ALT) {
+            modifiers |= event::KeyModifiers::ALT;
+        }
+        if event.modifiers.contains(super::KeyModifiers::SUPER) {
+            modifiers |= event::KeyModifiers::SUPER;
+        }
+        if event.modifiers.contains(super::KeyModifiers::HYPER) {
+            modifiers |= event::KeyModifiers::HYPER;
+        }
+        if event.modifiers.contains(super::KeyModifiers::META) {
+            modifiers |= event::KeyModifiers::META;
+        }
+
+        let kind = match event.kind {
+            super::KeyEventKind::Press => event::KeyEventKind::Press,
+            super::KeyEventKind::Repeat => event::KeyEventKind::Repeat,
+            super::KeyEventKind::Release => event::KeyEventKind::Release,
+        };
+
+        event::KeyEvent::new_with_kind(code, modifiers, kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+    #[test]
+    fn test_round_trip() {
+        let key = KeyEvent::new(
+            KeyCode::Char('x'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .with_kind(KeyEventKind::Repeat);
+
+        let crossterm_key = key.as_crossterm();
+        assert_eq!(crossterm_key.code, event::KeyCode::Char('x'));
+        assert_eq!(
+            crossterm_key.modifiers,
+            event::KeyModifiers::CONTROL | event::KeyModifiers::ALT
+        );
+        assert_eq!(crossterm_key.kind, event::KeyEventKind::Repeat);
+        assert_eq!(KeyEvent::try_from(crossterm_key), Ok(key));
+
+        let crossterm_key = event::KeyEvent::new(event::KeyCode::F(5), event::KeyModifiers::SHIFT);
+        let key = KeyEvent::try_from(crossterm_key).unwrap();
+        assert_eq!(key, KeyEvent::new(KeyCode::F(5), KeyModifiers::SHIFT));
+        assert_eq!(event::KeyEvent::from(key), crossterm_key);
 
-        Ok(super::KeyEvent { code, modifiers })
+        // Keys which are not supported by this crate cannot be converted
+        let caps_lock = event::KeyEvent::new(event::KeyCode::CapsLock, event::KeyModifiers::NONE);
+        assert_eq!(KeyEvent::try_from(caps_lock), Err(()));
     }
 }
@@ -0,0 +1,172 @@
+use super::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers, Movement};
+
+/// The keys which move through a list, and the [`Movement`] each of them makes.
+///
+/// By default, the keys captured by [`Movement::try_from_key`] are used. Keys can be bound to a
+/// different movement, or unbound so that they are not treated as a movement at all. Keys which
+/// are bound or unbound later take precedence over the earlier ones.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::events::{KeyBindings, KeyCode, KeyEvent, KeyModifiers, Movement};
+///
+/// let bindings = KeyBindings::arrows()
+///     .bind(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL), Movement::Down)
+///     .bind(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL), Movement::Up);
+///
+/// assert_eq!(bindings.movement(KeyCode::Down.into()), Some(Movement::Down));
+/// assert_eq!(
+///     bindings.movement(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL)),
+///     Some(Movement::Down)
+/// );
+/// // Only the arrow keys and the other dedicated keys are bound by `arrows`
+/// assert_eq!(bindings.movement(KeyCode::Char('j').into()), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyBindings {
+    // Bound keys with their movement, or `None` if the key was unbound
+    bound: Vec<(KeyCode, KeyModifiers, Option<Movement>)>,
+    defaults: bool,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyBindings {
+    /// Creates the default [`KeyBindings`], which are the keys captured by
+    /// [`Movement::try_from_key`].
+    pub fn new() -> Self {
+        Self {
+            bound: Vec::new(),
+            defaults: true,
+        }
+    }
+
+    /// Creates [`KeyBindings`] without any keys bound.
+    pub fn empty() -> Self {
+        Self {
+            bound: Vec::new(),
+            defaults: false,
+        }
+    }
+
+    /// Creates [`KeyBindings`] with only the arrow keys, `PageUp`, `PageDown`, `Home` and `End`
+    /// bound to their movements. Unlike the defaults, letters such as `j` and `k` are free to be
+    /// used for something else.
+    pub fn arrows() -> Self {
+        Self::empty()
+            .bind(KeyCode::Up.into(), Movement::Up)
+            .bind(KeyCode::Down.into(), Movement::Down)
+            .bind(KeyCode::Left.into(), Movement::Left)
+            .bind(KeyCode::Right.into(), Movement::Right)
+            .bind(KeyCode::PageUp.into(), Movement::PageUp)
+            .bind(KeyCode::PageDown.into(), Movement::PageDown)
+            .bind(KeyCode::Home.into(), Movement::Home)
+            .bind(KeyCode::End.into(), Movement::End)
+    }
+
+    /// Binds `key` to `movement`. Only the code and modifiers of `key` are matched, except that a
+    /// key pressed with `Shift` also matches the binding without it, unless it is bound with `Shift`
+    /// as well.
+    pub fn bind(mut self, key: KeyEvent, movement: Movement) -> Self {
+        self.bound.push((key.code, key.modifiers, Some(movement)));
+        self
+    }
+
+    /// Unbinds `key`, so that it is not a movement even if it is one by default. Only the code and
+    /// modifiers of `key` are matched.
+    pub fn unbind(mut self, key: KeyEvent) -> Self {
+        self.bound.push((key.code, key.modifiers, None));
+        self
+    }
+
+    /// Gets the movement (if any) `key` is bound to.
+    ///
+    /// Key releases are never a movement.
+    pub fn movement(&self, key: KeyEvent) -> Option<Movement> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+
+        let find = |modifiers: KeyModifiers| {
+            self.bound
+                .iter()
+                .rev()
+                .find(|&&(code, bound, _)| code == key.code && bound == modifiers)
+        };
+
+        // Shift is ignored unless the key is bound with it, so that for example `Shift+Down` still
+        // moves down to extend a selection
+        let bound = find(key.modifiers).or_else(|| {
+            if key.modifiers.contains(KeyModifiers::SHIFT) {
+                find(key.modifiers - KeyModifiers::SHIFT)
+            } else {
+                None
+            }
+        });
+
+        match bound {
+            Some(&(_, _, movement)) => movement,
+            None if self.defaults => Movement::try_from_key(key),
+            None => None,
+        }
+    }
+}
+
+#[test]
+fn test_key_bindings() {
+    let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+    let defaults = KeyBindings::new();
+    assert_eq!(
+        defaults.movement(KeyCode::Char('j').into()),
+        Some(Movement::Down)
+    );
+    assert_eq!(defaults.movement(ctrl_n), None);
+
+    let bindings = KeyBindings::new()
+        .bind(ctrl_n, Movement::Down)
+        .unbind(KeyCode::Char('j').into())
+        .bind(KeyCode::Char('k').into(), Movement::End)
+        .bind(KeyCode::Char('k').into(), Movement::Home);
+    assert_eq!(bindings.movement(ctrl_n), Some(Movement::Down));
+    assert_eq!(bindings.movement(KeyCode::Char('j').into()), None);
+    // The later binding wins
+    assert_eq!(
+        bindings.movement(KeyCode::Char('k').into()),
+        Some(Movement::Home)
+    );
+    // The modifiers have to match
+    assert_eq!(bindings.movement(KeyCode::Char('n').into()), None);
+    // Except for shift, unless it is bound explicitly
+    let shift = |code: KeyCode| KeyEvent::new(code, KeyModifiers::SHIFT);
+    assert_eq!(
+        bindings.movement(KeyEvent::new(
+            KeyCode::Char('n'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT
+        )),
+        Some(Movement::Down)
+    );
+    assert_eq!(
+        KeyBindings::arrows().movement(shift(KeyCode::Down)),
+        Some(Movement::Down)
+    );
+    assert_eq!(
+        KeyBindings::arrows()
+            .bind(shift(KeyCode::Down), Movement::End)
+            .movement(shift(KeyCode::Down)),
+        Some(Movement::End)
+    );
+    // Keys which are not bound still use the defaults
+    assert_eq!(bindings.movement(KeyCode::Up.into()), Some(Movement::Up));
+
+    let mut release = ctrl_n;
+    release.kind = KeyEventKind::Release;
+    assert_eq!(bindings.movement(release), None);
+
+    assert_eq!(KeyBindings::empty().movement(KeyCode::Up.into()), None);
+}
@@ -1,5 +1,10 @@
 bitflags::bitflags! {
-    /// Represents key modifiers (shift, control, alt).
+    /// Represents key modifiers (shift, control, alt, etc.).
+    ///
+    /// `SUPER`, `HYPER` and `META` are only reported by terminals which support keyboard
+    /// enhancement. See [`Backend::supports_keyboard_enhancement`].
+    ///
+    /// [`Backend::supports_keyboard_enhancement`]: crate::backend::Backend::supports_keyboard_enhancement
     pub struct KeyModifiers: u8 {
         #[allow(missing_docs)]
         const SHIFT = 0b0000_0001;
@@ -7,31 +12,66 @@ bitflags::bitflags! {
         const CONTROL = 0b0000_0010;
         #[allow(missing_docs)]
         const ALT = 0b0000_0100;
+        #[allow(missing_docs)]
+        const SUPER = 0b0000_1000;
+        #[allow(missing_docs)]
+        const HYPER = 0b0001_0000;
+        #[allow(missing_docs)]
+        const META = 0b0010_0000;
     }
 }
 
+/// Represents the kind of a key event.
+///
+/// Terminals which do not support keyboard enhancement only report [`KeyEventKind::Press`]. See
+/// [`Backend::supports_keyboard_enhancement`].
+///
+/// [`Backend::supports_keyboard_enhancement`]: crate::backend::Backend::supports_keyboard_enhancement
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum KeyEventKind {
+    /// The key was pressed.
+    Press,
+    /// The key is being held down.
+    Repeat,
+    /// The key was released.
+    Release,
+}
+
 /// Represents a key event.
+///
+/// More fields may be added in the future, so it can only be created with [`KeyEvent::new`] or
+/// from a [`KeyCode`] outside this crate.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
 pub struct KeyEvent {
     /// The key itself.
     pub code: KeyCode,
     /// Additional key modifiers.
     pub modifiers: KeyModifiers,
+    /// The kind of the event.
+    pub kind: KeyEventKind,
 }
 
 impl KeyEvent {
-    /// Creates a new `KeyEvent`
-    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
-        KeyEvent { code, modifiers }
+    /// Creates a new `KeyEvent` for a key press
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
+        KeyEvent {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+
+    /// Creates a new `KeyEvent` with the given `kind`
+    pub fn with_kind(mut self, kind: KeyEventKind) -> KeyEvent {
+        self.kind = kind;
+        self
     }
 }
 
 impl From<KeyCode> for KeyEvent {
     fn from(code: KeyCode) -> Self {
-        KeyEvent {
-            code,
-            modifiers: KeyModifiers::empty(),
-        }
+        KeyEvent::new(code, KeyModifiers::empty())
     }
 }
 
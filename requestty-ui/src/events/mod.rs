@@ -13,11 +13,18 @@ pub use self::crossterm::CrosstermEvents;
 #[cfg(feature = "termion")]
 pub use self::termion::TermionEvents;
 
+mod key_bindings;
 mod keys;
 mod movement;
+mod remote;
 
-pub use keys::{KeyCode, KeyEvent, KeyModifiers};
+#[cfg(feature = "crossterm")]
+pub(crate) use self::crossterm::push_typed_ahead;
+pub use key_bindings::KeyBindings;
+pub use keys::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 pub use movement::Movement;
+pub(crate) use remote::Decoder;
+pub use remote::RemoteEvents;
 
 /// Gets the default [`EventIterator`] based on the features enabled.
 #[cfg(any(feature = "crossterm", feature = "termion"))]
@@ -34,10 +41,40 @@ pub fn get_events() -> impl EventIterator {
     return TermionEvents::new();
 }
 
+/// An input from the terminal, which is either a key or text pasted all at once.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// A key was pressed, repeated or released.
+    Key(KeyEvent),
+    /// Text was pasted into a terminal which supports bracketed paste.
+    Paste(String),
+}
+
 /// A trait to represent a source of [`KeyEvent`]s.
 pub trait EventIterator {
     /// Get the next event
     fn next_event(&mut self) -> io::Result<KeyEvent>;
+
+    /// Get the next event, including text which was pasted.
+    ///
+    /// This is used by the [`Input`] runner so that a paste is handled at once with
+    /// [`Widget::handle_paste`], instead of one character at a time. By default, only keys are
+    /// returned, using [`next_event`].
+    ///
+    /// [`Input`]: crate::Input
+    /// [`Widget::handle_paste`]: crate::Widget::handle_paste
+    /// [`next_event`]: EventIterator::next_event
+    fn next_input(&mut self) -> io::Result<Event> {
+        self.next_event().map(Event::Key)
+    }
+
+    /// Get the next event if it is already available, without waiting for one.
+    ///
+    /// This is used to find events which have been buffered, for example when a key is held
+    /// down. By default, no event is ever available.
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        Ok(None)
+    }
 }
 
 /// A simple wrapper around a [`KeyEvent`] iterator that can be used in tests.
@@ -83,6 +120,14 @@ impl<E: Iterator<Item = KeyEvent>> EventIterator for TestEvents<E> {
             .next()
             .expect("Events ran out, but another one was requested"))
     }
+
+    /// All the remaining events are available, so this is the same as [`next_event`] except that
+    /// it returns `None` instead of panicking once the events run out.
+    ///
+    /// [`next_event`]: TestEvents::next_event
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        Ok(self.events.next())
+    }
 }
 
 impl<E: Iterator<Item = KeyEvent>> Drop for TestEvents<E> {
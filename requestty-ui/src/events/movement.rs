@@ -1,4 +1,4 @@
-use super::{KeyCode, KeyEvent, KeyModifiers};
+use super::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 
 /// Movements that can be captured from a [`KeyEvent`]. See the individual variants for
 /// what keys they capture
@@ -31,7 +31,13 @@ impl Movement {
     ///
     /// It also captures 'h', 'j', 'k', 'l', 'g', and 'G'. If these are required
     /// for some input, it must be checked before capturing a movement
+    ///
+    /// Key releases are never a movement.
     pub fn try_from_key(key: KeyEvent) -> Option<Movement> {
+        if key.kind == KeyEventKind::Release {
+            return None;
+        }
+
         let movement = match key.code {
             KeyCode::Left
                 if key
@@ -189,3 +195,17 @@ fn test_movement() {
         Some(Movement::End)
     );
 }
+
+#[test]
+fn test_movement_release() {
+    let key = KeyEvent::new(KeyCode::Up, KeyModifiers::empty());
+
+    assert_eq!(
+        Movement::try_from_key(key.with_kind(KeyEventKind::Repeat)),
+        Some(Movement::Up)
+    );
+    assert_eq!(
+        Movement::try_from_key(key.with_kind(KeyEventKind::Release)),
+        None
+    );
+}
@@ -0,0 +1,438 @@
+use std::{cell::RefCell, collections::VecDeque, io, rc::Rc};
+
+use super::{EventIterator, KeyCode, KeyEvent, KeyModifiers};
+
+/// An iterator over the keys decoded from the input stream of a [`RemoteBackend`].
+///
+/// It is created with [`RemoteBackend::events`], and shares the input stream with the backend.
+///
+/// [`RemoteBackend`]: crate::backend::RemoteBackend
+/// [`RemoteBackend::events`]: crate::backend::RemoteBackend::events
+pub struct RemoteEvents<R> {
+    decoder: Rc<RefCell<Decoder<R>>>,
+}
+
+impl<R> RemoteEvents<R> {
+    pub(crate) fn new(decoder: Rc<RefCell<Decoder<R>>>) -> Self {
+        Self { decoder }
+    }
+}
+
+impl<R> std::fmt::Debug for RemoteEvents<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteEvents").finish_non_exhaustive()
+    }
+}
+
+impl<R: io::Read> EventIterator for RemoteEvents<R> {
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        self.decoder.borrow_mut().next_event()
+    }
+
+    /// Returns the keys which have already been decoded, without reading from the input stream.
+    fn poll_event(&mut self) -> io::Result<Option<KeyEvent>> {
+        Ok(self.decoder.borrow_mut().events.pop_front())
+    }
+}
+
+/// Decodes the bytes sent by a terminal into keys and cursor position reports.
+pub(crate) struct Decoder<R> {
+    reader: R,
+    /// Bytes which have been read, but not decoded yet, as they are an incomplete sequence.
+    pending: Vec<u8>,
+    events: VecDeque<KeyEvent>,
+    cursor_pos: Option<(u16, u16)>,
+}
+
+impl<R: io::Read> Decoder<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending: Vec::new(),
+            events: VecDeque::new(),
+            cursor_pos: None,
+        }
+    }
+
+    fn next_event(&mut self) -> io::Result<KeyEvent> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Ok(event);
+            }
+
+            self.read()?;
+        }
+    }
+
+    /// Reads until the terminal reports the position of the cursor. Keys which are read in the
+    /// meantime are kept for [`next_event`](Decoder::next_event).
+    pub(crate) fn cursor_pos(&mut self) -> io::Result<(u16, u16)> {
+        self.cursor_pos = None;
+
+        loop {
+            if let Some(pos) = self.cursor_pos.take() {
+                return Ok(pos);
+            }
+
+            self.read()?;
+        }
+    }
+
+    /// An escape at the end of a read is kept until the next read, as it may be the start of a
+    /// sequence which was split between the reads. If the next read does not continue a sequence,
+    /// or the stream ends, it is the escape key.
+    fn read(&mut self) -> io::Result<()> {
+        let mut buf = [0; 1024];
+        let n = loop {
+            match self.reader.read(&mut buf) {
+                Ok(0) if self.pending == b"\x1b" => {
+                    self.push_esc();
+                    return Ok(());
+                }
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => break n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        };
+
+        if self.pending == b"\x1b" && buf[0] != b'[' && buf[0] != b'O' {
+            self.push_esc();
+        }
+
+        self.pending.extend_from_slice(&buf[..n]);
+        self.decode();
+        Ok(())
+    }
+
+    fn push_esc(&mut self) {
+        self.pending.clear();
+        self.events
+            .push_back(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+    }
+
+    fn decode(&mut self) {
+        let mut start = 0;
+
+        while start < self.pending.len() {
+            match parse(&self.pending[start..]) {
+                Parsed::Key(key, len) => {
+                    self.events.push_back(key);
+                    start += len;
+                }
+                Parsed::CursorPos(pos, len) => {
+                    self.cursor_pos = Some(pos);
+                    start += len;
+                }
+                Parsed::Skip(len) => start += len,
+                Parsed::Incomplete => break,
+            }
+        }
+
+        self.pending.drain(..start);
+    }
+}
+
+/// Decodes the keys in `bytes`, which were read from the terminal while waiting for something
+/// else. Nothing more is read after them, so a lone escape at the end is the escape key.
+#[cfg_attr(not(feature = "crossterm"), allow(dead_code))]
+pub(crate) fn decode_keys(bytes: &[u8]) -> Vec<KeyEvent> {
+    let mut decoder = Decoder::new(io::empty());
+    decoder.pending.extend_from_slice(bytes);
+    decoder.decode();
+
+    if decoder.pending == b"\x1b" {
+        decoder.push_esc();
+    }
+
+    decoder.events.into()
+}
+
+#[derive(Debug, PartialEq)]
+enum Parsed {
+    Key(KeyEvent, usize),
+    CursorPos((u16, u16), usize),
+    /// Bytes which are not understood, and are skipped.
+    Skip(usize),
+    /// More bytes are needed to know what the sequence is.
+    Incomplete,
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers, len: usize) -> Parsed {
+    Parsed::Key(KeyEvent::new(code, modifiers), len)
+}
+
+fn parse(bytes: &[u8]) -> Parsed {
+    match bytes[0] {
+        b'\x1b' => parse_esc(bytes),
+        // Some terminals send `\r\n` or `\r\0` for enter
+        b'\r' if bytes.get(1) == Some(&b'\n') || bytes.get(1) == Some(&b'\0') => {
+            key(KeyCode::Enter, KeyModifiers::empty(), 2)
+        }
+        b'\r' | b'\n' => key(KeyCode::Enter, KeyModifiers::empty(), 1),
+        b'\t' => key(KeyCode::Tab, KeyModifiers::empty(), 1),
+        b'\x7f' | b'\x08' => key(KeyCode::Backspace, KeyModifiers::empty(), 1),
+        b'\0' => key(KeyCode::Char(' '), KeyModifiers::CONTROL, 1),
+        c @ b'\x01'..=b'\x1a' => key(
+            KeyCode::Char((c - 1 + b'a') as char),
+            KeyModifiers::CONTROL,
+            1,
+        ),
+        c @ b'\x1c'..=b'\x1f' => key(
+            KeyCode::Char((c - 0x1c + b'4') as char),
+            KeyModifiers::CONTROL,
+            1,
+        ),
+        _ => parse_char(bytes),
+    }
+}
+
+fn parse_char(bytes: &[u8]) -> Parsed {
+    let len = match bytes[0] {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return Parsed::Skip(1),
+    };
+
+    if bytes.len() < len {
+        return Parsed::Incomplete;
+    }
+
+    match std::str::from_utf8(&bytes[..len]) {
+        Ok(s) => {
+            let c = s.chars().next().expect("there is at least one byte");
+            let modifiers = if c.is_uppercase() {
+                KeyModifiers::SHIFT
+            } else {
+                KeyModifiers::empty()
+            };
+            key(KeyCode::Char(c), modifiers, len)
+        }
+        Err(_) => Parsed::Skip(1),
+    }
+}
+
+fn parse_esc(bytes: &[u8]) -> Parsed {
+    match bytes.get(1).copied() {
+        // Nothing follows the escape in what has been sent so far, but the rest of a sequence may
+        // still be on its way
+        None => Parsed::Incomplete,
+        Some(b'[') => parse_csi(bytes),
+        Some(b'O') => match bytes.get(2).copied() {
+            None => Parsed::Incomplete,
+            Some(c) => match final_key(c) {
+                Some(code) => key(code, KeyModifiers::empty(), 3),
+                None => Parsed::Skip(3),
+            },
+        },
+        Some(b'\x1b') => key(KeyCode::Esc, KeyModifiers::empty(), 1),
+        // An escape before a key means that alt was held down
+        Some(_) => match parse(&bytes[1..]) {
+            Parsed::Key(key, len) => Parsed::Key(
+                KeyEvent::new(key.code, key.modifiers | KeyModifiers::ALT),
+                len + 1,
+            ),
+            Parsed::Incomplete => Parsed::Incomplete,
+            _ => Parsed::Skip(1),
+        },
+    }
+}
+
+/// The key for the final byte of `ESC [ <final>` and `ESC O <final>` sequences.
+fn final_key(c: u8) -> Option<KeyCode> {
+    let code = match c {
+        b'A' => KeyCode::Up,
+        b'B' => KeyCode::Down,
+        b'C' => KeyCode::Right,
+        b'D' => KeyCode::Left,
+        b'H' => KeyCode::Home,
+        b'F' => KeyCode::End,
+        b'P' => KeyCode::F(1),
+        b'Q' => KeyCode::F(2),
+        b'R' => KeyCode::F(3),
+        b'S' => KeyCode::F(4),
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+fn parse_csi(bytes: &[u8]) -> Parsed {
+    // `ESC [ <parameters> <final>`, where the parameters are in 0x30..=0x3f
+    let end = match bytes[2..].iter().position(|b| !(0x30..=0x3f).contains(b)) {
+        Some(i) => i + 2,
+        None => return Parsed::Incomplete,
+    };
+    let len = end + 1;
+
+    let params = match std::str::from_utf8(&bytes[2..end]) {
+        Ok(params) => params
+            .split(';')
+            .map(|param| param.parse::<u16>().ok())
+            .collect::<Vec<_>>(),
+        Err(_) => return Parsed::Skip(len),
+    };
+    let param = |i: usize| params.get(i).copied().flatten();
+
+    let modifiers = match param(1) {
+        Some(m) if m > 1 => {
+            let m = m - 1;
+            let mut modifiers = KeyModifiers::empty();
+            if m & 1 != 0 {
+                modifiers |= KeyModifiers::SHIFT;
+            }
+            if m & 2 != 0 {
+                modifiers |= KeyModifiers::ALT;
+            }
+            if m & 4 != 0 {
+                modifiers |= KeyModifiers::CONTROL;
+            }
+            if m & 8 != 0 {
+                modifiers |= KeyModifiers::META;
+            }
+            modifiers
+        }
+        _ => KeyModifiers::empty(),
+    };
+
+    let code = match bytes[end] {
+        // The cursor position report is `ESC [ <row> ; <col> R`, which is also how F3 with
+        // modifiers may be sent. As the position is only requested by the backend, it is taken to
+        // be a report whenever it has both parameters.
+        b'R' if params.len() == 2 => {
+            return match (param(0), param(1)) {
+                (Some(row), Some(col)) => {
+                    Parsed::CursorPos((col.saturating_sub(1), row.saturating_sub(1)), len)
+                }
+                _ => Parsed::Skip(len),
+            }
+        }
+        b'Z' => return key(KeyCode::BackTab, KeyModifiers::SHIFT, len),
+        b'~' => match param(0) {
+            Some(1) | Some(7) => KeyCode::Home,
+            Some(2) => KeyCode::Insert,
+            Some(3) => KeyCode::Delete,
+            Some(4) | Some(8) => KeyCode::End,
+            Some(5) => KeyCode::PageUp,
+            Some(6) => KeyCode::PageDown,
+            Some(n @ 11..=15) => KeyCode::F((n - 10) as u8),
+            Some(n @ 17..=21) => KeyCode::F((n - 11) as u8),
+            Some(n @ 23..=24) => KeyCode::F((n - 12) as u8),
+            _ => return Parsed::Skip(len),
+        },
+        c => match final_key(c) {
+            Some(code) => code,
+            None => return Parsed::Skip(len),
+        },
+    };
+
+    key(code, modifiers, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader which gives one chunk at a time, like a stream which receives the bytes in
+    /// separate packets.
+    struct Chunks(VecDeque<&'static [u8]>);
+
+    impl io::Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match self.0.pop_front() {
+                Some(chunk) => {
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    Ok(chunk.len())
+                }
+                None => Ok(0),
+            }
+        }
+    }
+
+    fn decode_all(chunks: &[&'static [u8]]) -> Vec<KeyEvent> {
+        let mut decoder = Decoder::new(Chunks(chunks.iter().copied().collect()));
+        let mut events = Vec::new();
+
+        loop {
+            match decoder.next_event() {
+                Ok(event) => events.push(event),
+                Err(e) => {
+                    assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+                    return events;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode() {
+        let ctrl = KeyModifiers::CONTROL;
+        let none = KeyModifiers::empty();
+
+        assert_eq!(
+            decode_all(&[b"aB\xc3\xa9\r\x7f\t\x03\x1b[A\x1b[1;5C\x1b[3~\x1bOH\x1b[Z\x1bx\x1b"]),
+            vec![
+                KeyEvent::new(KeyCode::Char('a'), none),
+                KeyEvent::new(KeyCode::Char('B'), KeyModifiers::SHIFT),
+                KeyEvent::new(KeyCode::Char('é'), none),
+                KeyEvent::new(KeyCode::Enter, none),
+                KeyEvent::new(KeyCode::Backspace, none),
+                KeyEvent::new(KeyCode::Tab, none),
+                KeyEvent::new(KeyCode::Char('c'), ctrl),
+                KeyEvent::new(KeyCode::Up, none),
+                KeyEvent::new(KeyCode::Right, ctrl),
+                KeyEvent::new(KeyCode::Delete, none),
+                KeyEvent::new(KeyCode::Home, none),
+                KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+                KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT),
+                KeyEvent::new(KeyCode::Esc, none),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_split() {
+        // Sequences split between reads are put back together
+        assert_eq!(
+            decode_all(&[b"\x1b[", b"15", b"~\xe2\x82", b"\xac"]),
+            vec![
+                KeyEvent::from(KeyCode::F(5)),
+                KeyEvent::from(KeyCode::Char('€')),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_split_esc() {
+        let none = KeyModifiers::empty();
+
+        // A sequence split right after the escape is not an escape followed by `[A`
+        assert_eq!(
+            decode_all(&[b"a\x1b", b"[A"]),
+            vec![
+                KeyEvent::new(KeyCode::Char('a'), none),
+                KeyEvent::new(KeyCode::Up, none),
+            ]
+        );
+        // But if the next read does not continue it, it is the escape key, and not alt
+        assert_eq!(
+            decode_all(&[b"\x1b", b"x", b"\x1b"]),
+            vec![
+                KeyEvent::new(KeyCode::Esc, none),
+                KeyEvent::new(KeyCode::Char('x'), none),
+                KeyEvent::new(KeyCode::Esc, none),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cursor_pos() {
+        let mut decoder = Decoder::new(Chunks(vec![&b"a\x1b[3;7Rb"[..]].into()));
+
+        assert_eq!(decoder.cursor_pos().unwrap(), (6, 2));
+        assert_eq!(decoder.next_event().unwrap(), KeyCode::Char('a').into());
+        assert_eq!(decoder.next_event().unwrap(), KeyCode::Char('b').into());
+    }
+}
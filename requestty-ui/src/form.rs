@@ -0,0 +1,387 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    layout::Layout,
+    style::Color,
+    widgets::Widget,
+    Prompt, Validation,
+};
+
+/// A [`Prompt`] made of multiple labelled fields which are filled in on the same screen.
+///
+/// Each field is rendered on its own line after its label, and keeps its own state. Only the
+/// focused field is given keys. `Tab` moves the focus to the next field and `Shift+Tab` to the
+/// previous one, wrapping around at either end.
+///
+/// Pressing `Enter` submits the whole form. Every field is validated, and if any of them fail,
+/// the errors are shown together and the first field which failed is focused. Once they all pass,
+/// the labels and widgets of the fields are returned in the order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use requestty_ui::{
+///     backend::TestBackend,
+///     events::{KeyCode, TestEvents},
+///     widgets::{Form, StringInput},
+///     Input,
+/// };
+///
+/// let form = Form::new()
+///     .with_validated_field("name", StringInput::new(), |input: &StringInput| {
+///         if input.value().is_empty() {
+///             Err("Please enter a name".to_owned())
+///         } else {
+///             Ok(())
+///         }
+///     })
+///     .with_field("city", StringInput::new());
+///
+/// let mut backend = TestBackend::new((50, 10).into());
+/// let mut events = TestEvents::new(vec![
+///     KeyCode::Char('J').into(),
+///     KeyCode::Tab.into(),
+///     KeyCode::Char('P').into(),
+///     KeyCode::Enter.into(),
+/// ]);
+///
+/// let values: HashMap<_, _> = Input::new(form, &mut backend)
+///     .run(&mut events)
+///     .unwrap()
+///     .unwrap()
+///     .into_iter()
+///     .map(|(label, input)| (label, input.finish()))
+///     .collect();
+///
+/// assert_eq!(values["name"], "J");
+/// assert_eq!(values["city"], "P");
+/// ```
+pub struct Form<W> {
+    fields: Vec<Field<W>>,
+    focused: usize,
+}
+
+type ValidateField<W> = Box<dyn FnMut(&W) -> Result<(), String>>;
+
+struct Field<W> {
+    label: String,
+    widget: W,
+    validate: Option<ValidateField<W>>,
+}
+
+impl<W: std::fmt::Debug> std::fmt::Debug for Form<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Form")
+            .field(
+                "fields",
+                &self
+                    .fields
+                    .iter()
+                    .map(|field| (&field.label, &field.widget))
+                    .collect::<Vec<_>>(),
+            )
+            .field("focused", &self.focused)
+            .finish()
+    }
+}
+
+impl<W: Widget> Form<W> {
+    /// Creates a new `Form` without any fields.
+    pub fn new() -> Self {
+        Self {
+            fields: Vec::new(),
+            focused: 0,
+        }
+    }
+
+    /// Adds a field which is always valid after the existing ones.
+    pub fn with_field<S: Into<String>>(mut self, label: S, widget: W) -> Self {
+        self.fields.push(Field {
+            label: label.into(),
+            widget,
+            validate: None,
+        });
+        self
+    }
+
+    /// Adds a field after the existing ones, which is checked with `validate` when the form is
+    /// submitted.
+    pub fn with_validated_field<S, V>(mut self, label: S, widget: W, validate: V) -> Self
+    where
+        S: Into<String>,
+        V: FnMut(&W) -> Result<(), String> + 'static,
+    {
+        self.fields.push(Field {
+            label: label.into(),
+            widget,
+            validate: Some(Box::new(validate)),
+        });
+        self
+    }
+
+    /// The index of the field which is focused.
+    pub fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Focuses the field at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no field at `index`.
+    pub fn set_focused(&mut self, index: usize) {
+        assert!(index < self.fields.len(), "there is no field at {}", index);
+        self.focused = index;
+    }
+
+    /// The widget of the field at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no field at `index`.
+    pub fn field(&self, index: usize) -> &W {
+        &self.fields[index].widget
+    }
+
+    /// A mutable reference to the widget of the field at `index`.
+    ///
+    /// # Panics
+    ///
+    /// If there is no field at `index`.
+    pub fn field_mut(&mut self, index: usize) -> &mut W {
+        &mut self.fields[index].widget
+    }
+
+    /// Validates every field, returning the errors joined together and the index of the first
+    /// field which failed.
+    fn check_fields(&mut self) -> (String, Option<usize>) {
+        let mut errors = Vec::new();
+        let mut first_invalid = None;
+
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            if let Some(ref mut validate) = field.validate {
+                if let Err(e) = validate(&field.widget) {
+                    errors.push(format!("{}: {}", field.label, e));
+                    first_invalid.get_or_insert(i);
+                }
+            }
+        }
+
+        (errors.join("; "), first_invalid)
+    }
+
+    /// Moves `layout` to where the widget of the field at `index` starts, given `layout` is where
+    /// the field starts.
+    fn start_field(&self, index: usize, layout: &mut Layout) {
+        // Each field starts on a new line, except the first which starts wherever the form does
+        if index > 0 {
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+        }
+
+        layout.line_offset += label_width(&self.fields[index].label);
+    }
+}
+
+impl<W: Widget> Default for Form<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The width of the pointer and the label, and the `": "` after it.
+fn label_width(label: &str) -> u16 {
+    (2 + textwrap::core::display_width(label) + 2) as u16
+}
+
+impl<W: Widget> Widget for Form<W> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        let pointer = crate::symbols::current().pointer;
+
+        for i in 0..self.fields.len() {
+            if i > 0 {
+                backend.move_cursor_to(layout.offset_x, layout.offset_y + 1)?;
+            }
+            self.start_field(i, layout);
+
+            let field = &mut self.fields[i];
+            if i == self.focused {
+                backend.set_fg(Color::Cyan)?;
+                write!(backend, "{} {}: ", pointer, field.label)?;
+                backend.set_fg(Color::Reset)?;
+            } else {
+                write!(backend, "  {}: ", field.label)?;
+            }
+
+            field.widget.render(layout, backend)?;
+        }
+
+        Ok(())
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut height = 0;
+
+        for i in 0..self.fields.len() {
+            self.start_field(i, layout);
+            height += self.fields[i].widget.height(layout);
+        }
+
+        height.max(1)
+    }
+
+    fn cursor_pos(&mut self, mut layout: Layout) -> (u16, u16) {
+        for i in 0..self.focused {
+            self.start_field(i, &mut layout);
+            self.fields[i].widget.height(&mut layout);
+        }
+
+        self.start_field(self.focused, &mut layout);
+        self.fields[self.focused].widget.cursor_pos(layout)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.fields.is_empty() {
+            return false;
+        }
+
+        match key.code {
+            KeyCode::Tab => self.focused = (self.focused + 1) % self.fields.len(),
+            KeyCode::BackTab => {
+                self.focused = self.focused.checked_sub(1).unwrap_or(self.fields.len() - 1)
+            }
+            _ => return self.fields[self.focused].widget.handle_key(key),
+        }
+
+        true
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        match self.fields.get_mut(self.focused) {
+            Some(field) => field.widget.handle_paste(text),
+            None => false,
+        }
+    }
+
+    fn wants_cursor(&self) -> bool {
+        matches!(self.fields.get(self.focused), Some(field) if field.widget.wants_cursor())
+    }
+}
+
+impl<W: Widget> Prompt for Form<W> {
+    type ValidateErr = String;
+    type Output = Vec<(String, W)>;
+
+    /// Validates every field, so that all the errors are shown at once.
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        let (errors, first_invalid) = self.check_fields();
+
+        match first_invalid {
+            Some(i) => {
+                self.focused = i;
+                Err(errors)
+            }
+            None => Ok(Validation::Finish),
+        }
+    }
+
+    /// Validates every field like [`validate`](Prompt::validate), without moving the focus.
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
+        match self.check_fields() {
+            (errors, Some(_)) => Err(errors),
+            (_, None) => Ok(()),
+        }
+    }
+
+    fn finish(self) -> Self::Output {
+        self.fields
+            .into_iter()
+            .map(|field| (field.label, field.widget))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{backend::TestBackend, events::TestEvents, widgets::StringInput, Input};
+
+    fn form() -> Form<StringInput> {
+        Form::new()
+            .with_validated_field("name", StringInput::new(), |input: &StringInput| {
+                if input.value().is_empty() {
+                    Err("required".to_owned())
+                } else {
+                    Ok(())
+                }
+            })
+            .with_field("email", StringInput::new())
+    }
+
+    #[test]
+    fn test_tab_and_submit() {
+        let mut backend = TestBackend::new((50, 10).into());
+        let mut events = TestEvents::new(vec![
+            KeyCode::Tab.into(),
+            KeyCode::Char('b').into(),
+            // The name is still empty, so the form is not submitted and the name is focused
+            KeyCode::Enter.into(),
+            KeyCode::Char('a').into(),
+            KeyCode::BackTab.into(),
+            KeyCode::Char('c').into(),
+            KeyCode::Enter.into(),
+        ]);
+
+        let values: Vec<_> = Input::new(form(), &mut backend)
+            .run(&mut events)
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|(label, input)| (label, input.finish()))
+            .collect();
+
+        assert_eq!(
+            values,
+            [
+                ("name".to_owned(), "a".to_owned()),
+                ("email".to_owned(), "bc".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let size = (50, 10).into();
+        let layout = Layout::new(0, size);
+
+        let mut form = form();
+        form.handle_key(KeyCode::Char('a').into());
+        form.handle_key(KeyCode::Tab.into());
+        form.handle_key(KeyCode::Char('b').into());
+
+        let mut backend = TestBackend::new(size);
+        let mut l = layout;
+        form.render(&mut l, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        expected.write_all(b"  name: a").unwrap();
+        expected.move_cursor_to(0, 1).unwrap();
+        expected.set_fg(Color::Cyan).unwrap();
+        write!(expected, "{} email: ", crate::symbols::current().pointer).unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_all(b"b").unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(l, layout.with_offset(0, 1).with_line_offset(10));
+        let mut l = layout;
+        assert_eq!(form.height(&mut l), 2);
+        assert_eq!(l, layout.with_offset(0, 1).with_line_offset(10));
+        assert_eq!(form.cursor_pos(layout), (10, 1));
+    }
+}
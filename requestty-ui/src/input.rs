@@ -5,11 +5,11 @@ use std::{
 
 use super::Widget;
 use crate::{
-    backend::{Backend, ClearType, MoveDirection, Size},
+    backend::{Backend, ClearType, MoveDirection, RecordBackend, Size},
     error,
-    events::{EventIterator, KeyCode, KeyModifiers},
+    events::{Event, EventIterator, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, Movement},
     layout::Layout,
-    style::Stylize,
+    style::{Attributes, Color, Stylize},
 };
 
 /// The state of a prompt on validation.
@@ -60,9 +60,59 @@ pub trait Prompt: Widget {
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
         Ok(Validation::Finish)
     }
+
+    /// Checks whether the prompt state is valid, without changing it. It is called after every key
+    /// while an error is shown with [`Input::persist_errors`], so that the error can be updated as
+    /// the prompt is edited.
+    ///
+    /// Unlike [`validate`](Prompt::validate), this is not a submission, so it should only return
+    /// the error `validate` would, and leave everything else (such as open menus) as it is. By
+    /// default, the prompt is always valid, so the error is cleared on the next key.
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
+        Ok(())
+    }
+
     /// The value to return from [`Input::run`]. This will only be called once validation returns
     /// [`Validation::Finish`]
     fn finish(self) -> Self::Output;
+
+    /// The value to return from [`Input::run_detailed`], along with the text that was entered to
+    /// get it. Like [`finish`](Prompt::finish), this will only be called once validation returns
+    /// [`Validation::Finish`].
+    ///
+    /// The text should be what the user saw before submitting, for example the raw input before
+    /// it is parsed or filtered. By default, it returns the value from [`finish`](Prompt::finish)
+    /// and an empty string, so prompts only need to implement this if they have such text.
+    fn finish_detailed(self) -> (Self::Output, String)
+    where
+        Self: Sized,
+    {
+        (self.finish(), String::new())
+    }
+
+    /// The message of the prompt, used as the window title with [`Input::set_title_to_message`].
+    ///
+    /// By default, the prompt has no message and the title is left unchanged.
+    fn message(&self) -> Option<&str> {
+        None
+    }
+
+    /// Called with the error whenever [`validate`](Prompt::validate) fails, before the prompt is
+    /// rendered again with the error below it.
+    ///
+    /// This can be used to change the prompt after a failed attempt, for example to change the
+    /// message to "Try again". By default, it does nothing.
+    fn on_validate_error(&mut self, _err: &Self::ValidateErr) {}
+
+    /// Does a bit of work which was left for later, for example filtering a long list a few
+    /// elements at a time. It is called repeatedly while no keys are waiting, until it returns
+    /// `false`, and all the remaining work is done before the prompt is submitted.
+    ///
+    /// Returns whether any work was done, in which case the prompt is rendered again. By
+    /// default, there is never any work to do.
+    fn tick(&mut self) -> bool {
+        false
+    }
 }
 
 /// A ui runner which implements the [render cycle].
@@ -76,10 +126,42 @@ pub trait Prompt: Widget {
 pub struct Input<P, B: Backend> {
     prompt: P,
     on_esc: OnEsc,
+    skip_key: Option<KeyEvent>,
+    submit_key: Option<KeyEvent>,
     backend: TerminalState<B>,
     base_row: u16,
+    indent: u16,
     size: Size,
+    refresh_size: bool,
+    persist_errors: bool,
+    showing_error: bool,
+    // The error which is shown, to tell whether a new error is the same one
+    last_error: Option<RecordBackend>,
+    error_prefix: Option<(String, Color)>,
+    preserve_below: u16,
+    // The number of lines drawn by the last render, including the error
+    drawn_height: u16,
+    title_to_message: bool,
+    skip_first_clear: bool,
+    coalesce_movement: bool,
+    suspend_on_submit: bool,
     render_overflow: bool,
+    line_prefix: Option<LinePrefix>,
+}
+
+/// The text written at the start of the first line of the prompt. See [`Input::with_line_prefix`].
+struct LinePrefix {
+    prefix: Box<dyn Fn() -> String>,
+    /// The prefix for the current render
+    text: String,
+}
+
+impl std::fmt::Debug for LinePrefix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinePrefix")
+            .field("text", &self.text)
+            .finish_non_exhaustive()
+    }
 }
 
 impl<P, B: Backend> Input<P, B> {
@@ -92,10 +174,25 @@ impl<P, B: Backend> Input<P, B> {
         Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            skip_key: None,
+            submit_key: None,
             backend: TerminalState::new(backend, false),
             base_row: 0,
+            indent: 0,
             size: Size::default(),
+            refresh_size: true,
+            persist_errors: false,
+            showing_error: false,
+            last_error: None,
+            error_prefix: None,
+            preserve_below: 0,
+            drawn_height: 0,
+            title_to_message: false,
+            skip_first_clear: false,
+            coalesce_movement: false,
+            suspend_on_submit: false,
             render_overflow: false,
+            line_prefix: None,
         }
     }
 
@@ -112,27 +209,213 @@ impl<P, B: Backend> Input<P, B> {
     /// will be returned.
     /// For [`OnEsc::Ignore`] - no special behaviour will be applied to the `Esc` key. Like other
     /// keys, the `Esc` key will be passed to the prompt to handle.
+    ///
+    /// Either way, the `Esc` key is passed to the prompt first, and the prompt is only terminated
+    /// or skipped if the prompt does not [handle](Widget::handle_key) it. This lets a prompt use
+    /// `Esc` to undo something first, like clearing a query.
     pub fn on_esc(mut self, on_esc: OnEsc) -> Self {
         self.on_esc = on_esc;
         self
     }
+
+    /// A key which skips the prompt.
+    ///
+    /// When the given key is pressed, the currently shown prompt will be cleared, and `Ok(None)`
+    /// will be returned. This is the same as the behaviour of `Esc` with [`OnEsc::SkipQuestion`],
+    /// but it can be used with a key other than `Esc`.
+    ///
+    /// If `skip_key` is `None`, which is the default, no key will skip the prompt.
+    pub fn skip_key(mut self, skip_key: Option<KeyEvent>) -> Self {
+        self.skip_key = skip_key;
+        self
+    }
+
+    /// The key which submits the prompt.
+    ///
+    /// When the given key is pressed, [`validate`](Prompt::validate) will be called. `Enter` is
+    /// then passed to the prompt to handle like any other key. By default, `Enter` submits the
+    /// prompt.
+    pub fn submit_key(mut self, submit_key: KeyEvent) -> Self {
+        self.submit_key = Some(submit_key);
+        self
+    }
+
+    /// The number of columns to leave empty to the left of the prompt.
+    ///
+    /// The prompt is rendered with its [`Layout::offset_x`] set to `indent`, so it is shifted to
+    /// the right and has that much less width to render in. Validation errors are indented the
+    /// same way. By default, the prompt is not indented.
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Whether to query the size of the terminal every time the prompt is rendered.
+    ///
+    /// The size is compared to the last known size, and the prompt is re-anchored if the terminal
+    /// has shrunk below it. This is how the prompt adapts to the terminal being resized. If it is
+    /// `false`, the size is only queried once when the `Input` is [run](Input::run), which saves
+    /// a call to [`Backend::size`] per render when the size is known not to change. By default,
+    /// the size is refreshed on every render.
+    pub fn refresh_size(mut self, refresh_size: bool) -> Self {
+        self.refresh_size = refresh_size;
+        self
+    }
+
+    /// Whether to keep showing a validation error while the prompt is being edited.
+    ///
+    /// By default, the error is only shown until the next key is handled by the prompt. If this is
+    /// `true`, [`is_valid`](Prompt::is_valid) is called after every key handled while an error is
+    /// shown, and the error is replaced with the new one until it passes. The prompt is not
+    /// submitted when it passes, and [`on_validate_error`](Prompt::on_validate_error) is only
+    /// called again if the error changes.
+    pub fn persist_errors(mut self, persist_errors: bool) -> Self {
+        self.persist_errors = persist_errors;
+        self
+    }
+
+    /// The symbol shown before a validation error, and its colour.
+    ///
+    /// The error is written after the prefix and a space. By default, the prefix is the
+    /// [`cross`](crate::symbols::SymbolSet::cross) of the current symbol set coloured red.
+    pub fn error_prefix<S: Into<String>>(mut self, prefix: S, color: Color) -> Self {
+        self.error_prefix = Some((prefix.into(), color));
+        self
+    }
+
+    /// The number of lines below the prompt which should not be cleared.
+    ///
+    /// When the prompt is re-rendered, everything from the start of the prompt downwards is
+    /// cleared. If this is set, only the lines of the prompt itself are cleared, and clearing
+    /// everything below starts `preserve_below` lines after the end of the prompt. This protects
+    /// output left right below the prompt, for example by a previous prompt. By default, nothing
+    /// is preserved.
+    pub fn preserve_below(mut self, preserve_below: u16) -> Self {
+        self.preserve_below = preserve_below;
+        self
+    }
+
+    /// Whether to set the window title to the [message](Prompt::message) of the prompt while it is
+    /// running.
+    ///
+    /// The title is set with [`Backend::set_title`] when the `Input` is [run](Input::run), and is
+    /// restored once it finishes, however it finishes. By default, the title is not changed.
+    pub fn set_title_to_message(mut self, title_to_message: bool) -> Self {
+        self.title_to_message = title_to_message;
+        self
+    }
+
+    /// Whether the first render should draw the prompt without clearing the terminal first.
+    ///
+    /// Every render normally clears everything from the start of the prompt downwards. When the
+    /// prompt is embedded after existing output, this can erase part of that output if the cursor
+    /// is not where it is expected to be. If this is set, only the renders after the first one
+    /// clear the terminal. By default, the first render clears as well.
+    pub fn first_render_no_clear(mut self, first_render_no_clear: bool) -> Self {
+        self.skip_first_clear = first_render_no_clear;
+        self
+    }
+
+    /// Whether repeated movement keys which are already available should be handled together
+    /// with a single render.
+    ///
+    /// When a movement key (see [`Movement`]) is held down, the events can arrive faster than the
+    /// prompt is rendered, which makes navigating large lists lag behind. If this is set, after a
+    /// movement key is handled, the following events which are the same key are handled without
+    /// rendering as long as they are immediately available (see [`EventIterator::poll_event`]).
+    /// The prompt is then rendered once. Any other key ends the batch, so it is handled after the
+    /// render as usual. By default, every key is rendered separately.
+    pub fn coalesce_movement(mut self, coalesce_movement: bool) -> Self {
+        self.coalesce_movement = coalesce_movement;
+        self
+    }
+
+    /// Whether to give the terminal back while the prompt is [validated](Prompt::validate) on
+    /// submit.
+    ///
+    /// If this is set, raw mode is disabled and the cursor is shown before `validate` is called,
+    /// and the terminal is set up again once it returns. This is needed when `validate` runs
+    /// another program which uses the terminal, such as a text editor. By default, the terminal
+    /// stays in raw mode.
+    pub fn suspend_on_submit(mut self, suspend_on_submit: bool) -> Self {
+        self.suspend_on_submit = suspend_on_submit;
+        self
+    }
+
+    /// Writes the text returned by `prefix` dimmed at the start of the first line of the prompt,
+    /// for example a timestamp when the prompts are mixed in with logs.
+    ///
+    /// `prefix` is called every time the prompt is rendered, and the prompt starts after it. Once
+    /// the prompt is submitted, the prefix is written once more in place of the prompt, so that
+    /// the finished line written after it has the prefix as well. By default, there is no prefix.
+    pub fn with_line_prefix(mut self, prefix: Box<dyn Fn() -> String>) -> Self {
+        self.line_prefix = Some(LinePrefix {
+            prefix,
+            text: String::new(),
+        });
+        self
+    }
 }
 
 impl<P: Prompt, B: Backend> Input<P, B> {
     fn layout(&self) -> Layout {
-        Layout::new(0, self.size).with_offset(0, self.base_row)
+        let line_offset = match self.line_prefix {
+            Some(ref line_prefix) => {
+                let width = textwrap::core::display_width(&line_prefix.text) as u16;
+                // The prompt has to start on the first line
+                width.min(self.size.width.saturating_sub(self.indent + 1))
+            }
+            None => 0,
+        };
+
+        Layout::new(line_offset, self.size).with_offset(self.indent, self.base_row)
+    }
+
+    /// Gets the prefix for the next render from the function given to
+    /// [`with_line_prefix`](Input::with_line_prefix).
+    fn refresh_line_prefix(&mut self) {
+        if let Some(ref mut line_prefix) = self.line_prefix {
+            line_prefix.text = (line_prefix.prefix)();
+        }
+    }
+
+    /// Writes the line prefix at the start of the prompt, leaving the cursor after it.
+    fn write_line_prefix(&mut self) -> io::Result<()> {
+        let text = match self.line_prefix {
+            Some(ref line_prefix) => &line_prefix.text,
+            None => return Ok(()),
+        };
+
+        self.backend.move_cursor_to(self.indent, self.base_row)?;
+        self.backend.set_attributes(Attributes::DIM)?;
+        self.backend.write_all(text.as_bytes())?;
+        self.backend.set_attributes(Attributes::empty())
     }
 
     fn update_size(&mut self) -> io::Result<()> {
-        self.size = self.backend.size()?;
-        if self.size.area() == 0 {
-            Err(io::Error::new(
+        let size = self.backend.size()?;
+        if size.area() == 0 {
+            return Err(io::Error::new(
                 io::ErrorKind::Other,
                 format!(
                     "Invalid terminal {:?}. Both width and height must be larger than 0",
-                    self.size
+                    size
                 ),
-            ))
+            ));
+        }
+
+        if size != self.size {
+            // The prompt can't start below the terminal, so it is moved up to the last line
+            self.base_row = self.base_row.min(size.height - 1);
+            self.size = size;
+        }
+
+        Ok(())
+    }
+
+    fn sync_size(&mut self) -> io::Result<()> {
+        if self.refresh_size || self.size.area() == 0 {
+            self.update_size()
         } else {
             Ok(())
         }
@@ -140,10 +423,40 @@ impl<P: Prompt, B: Backend> Input<P, B> {
 
     fn init(&mut self) -> io::Result<()> {
         self.backend.init()?;
+        if self.title_to_message {
+            if let Some(message) = self.prompt.message() {
+                self.backend.set_title(message)?;
+            }
+        }
         self.base_row = self.backend.get_cursor_pos()?.1;
         self.render()
     }
 
+    /// Validates the prompt when it is submitted, giving the terminal back while it does if
+    /// [`suspend_on_submit`](Input::suspend_on_submit) is set.
+    fn validate_submit(&mut self) -> io::Result<Result<Validation, P::ValidateErr>> {
+        // The prompt is validated with all of its work done, as if the user had waited for it
+        while self.prompt.tick() {}
+
+        if !self.suspend_on_submit {
+            return Ok(self.prompt.validate());
+        }
+
+        self.backend.reset()?;
+        self.backend.flush()?;
+
+        let res = self.prompt.validate();
+
+        self.backend.init()?;
+        if self.title_to_message {
+            if let Some(message) = self.prompt.message() {
+                self.backend.set_title(message)?;
+            }
+        }
+
+        Ok(res)
+    }
+
     fn adjust_scrollback(&mut self, height: u16) -> io::Result<u16> {
         let th = self.size.height;
 
@@ -160,7 +473,13 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn flush(&mut self) -> io::Result<()> {
-        if !self.backend.hide_cursor {
+        if !self.backend.hide_cursor && !self.prompt.wants_cursor() {
+            // The prompt doesn't need a cursor, so we hide it until it is wanted again.
+            if !self.backend.cursor_hidden {
+                self.backend.cursor_hidden = true;
+                self.backend.hide_cursor()?;
+            }
+        } else if !self.backend.hide_cursor {
             let (x, y) = self.prompt.cursor_pos(self.layout());
 
             if self.render_overflow && y >= self.size.height - 1 {
@@ -194,13 +513,22 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn render(&mut self) -> io::Result<()> {
-        self.update_size()?;
+        self.sync_size()?;
+        self.refresh_line_prefix();
         let height = self.prompt.height(&mut self.layout());
         self.base_row = self.adjust_scrollback(height)?;
-        self.clear()?;
 
+        if self.skip_first_clear {
+            self.skip_first_clear = false;
+            self.backend.move_cursor_to(self.indent, self.base_row)?;
+        } else {
+            self.clear()?;
+        }
+
+        self.write_line_prefix()?;
         self.prompt.render(&mut self.layout(), &mut *self.backend)?;
         self.render_overflow = height > self.size.height;
+        self.drawn_height = height;
 
         if self.render_overflow {
             self.backend.move_cursor_to(0, self.size.height - 1)?;
@@ -211,8 +539,28 @@ impl<P: Prompt, B: Backend> Input<P, B> {
     }
 
     fn clear(&mut self) -> io::Result<()> {
-        self.backend.move_cursor_to(0, self.base_row)?;
-        self.backend.clear(ClearType::FromCursorDown)
+        if self.preserve_below == 0 {
+            self.backend.move_cursor_to(0, self.base_row)?;
+            self.backend.clear(ClearType::FromCursorDown)?;
+        } else {
+            // The prompt may have changed height since it was drawn, so what was actually drawn is
+            // cleared
+            let prompt_end = (self.base_row + self.drawn_height).min(self.size.height);
+
+            for row in self.base_row..prompt_end {
+                self.backend.move_cursor_to(0, row)?;
+                self.backend.clear(ClearType::CurrentLine)?;
+            }
+
+            let clear_from = prompt_end + self.preserve_below;
+            if clear_from < self.size.height {
+                self.backend.move_cursor_to(0, clear_from)?;
+                self.backend.clear(ClearType::FromCursorDown)?;
+            }
+        }
+
+        self.drawn_height = 0;
+        self.backend.move_cursor_to(self.indent, self.base_row)
     }
 
     fn goto_last_line(&mut self, height: u16) -> io::Result<()> {
@@ -220,39 +568,107 @@ impl<P: Prompt, B: Backend> Input<P, B> {
         self.backend.move_cursor_to(0, self.base_row + height)
     }
 
+    /// Checks the prompt again after it changed, if errors are persisted and one is shown.
+    ///
+    /// Returns `false` if the error was shown again, in which case nothing else should be
+    /// rendered. [`on_validate_error`](Prompt::on_validate_error) is only called if the error is
+    /// not the same as the one already shown.
+    fn revalidate(&mut self) -> io::Result<bool> {
+        if self.persist_errors && self.showing_error {
+            match self.prompt.is_valid() {
+                Err(mut e) => {
+                    let error = self.record_error(&mut e)?;
+                    if self.last_error.as_ref() != Some(&error.0) {
+                        self.prompt.on_validate_error(&e);
+                    }
+                    self.show_error(error)?;
+                    return Ok(false);
+                }
+                Ok(()) => {
+                    self.showing_error = false;
+                    self.last_error = None;
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
     fn print_error(&mut self, mut e: P::ValidateErr) -> io::Result<()> {
-        self.update_size()?;
+        let error = self.record_error(&mut e)?;
+        self.prompt.on_validate_error(&e);
+        self.show_error(error)
+    }
+
+    /// The layout of the error, if it were shown on the first row.
+    fn error_layout(&self) -> Layout {
+        let prefix_width = match self.error_prefix {
+            Some((ref prefix, _)) => textwrap::core::display_width(prefix) as u16,
+            None => 1,
+        };
+
+        Layout::new(prefix_width + 1, self.size).with_offset(self.indent, 0)
+    }
+
+    /// Records how the error is rendered as if it were shown on the first row, along with its
+    /// height. The error is only rendered this once, and the recording is moved to where the
+    /// error is actually shown.
+    fn record_error(&mut self, e: &mut P::ValidateErr) -> io::Result<(RecordBackend, u16)> {
+        self.sync_size()?;
+
+        let mut layout = self.error_layout();
+        let err_height = e.height(&mut layout.clone());
+
+        let mut record = RecordBackend::new(self.size);
+        e.render(&mut layout, &mut record)?;
+
+        Ok((record, err_height))
+    }
+
+    /// Renders the prompt with the recorded error below it.
+    fn show_error(&mut self, (error, err_height): (RecordBackend, u16)) -> io::Result<()> {
         let height = self.prompt.height(&mut self.layout());
         self.base_row = self.adjust_scrollback(height + 1)?;
         self.clear()?;
+        self.write_line_prefix()?;
         self.prompt.render(&mut self.layout(), &mut *self.backend)?;
 
         self.goto_last_line(height)?;
-
-        let mut layout = Layout::new(2, self.size).with_offset(0, self.base_row + height);
-        let err_height = e.height(&mut layout.clone());
         self.base_row = self.adjust_scrollback(height + err_height)?;
 
-        if self.render_overflow {
+        let err_row = if self.render_overflow {
             self.backend
                 .move_cursor_to(0, self.size.height - err_height - 1)?;
             self.backend.clear(ClearType::FromCursorDown)?;
             self.render_cutoff_msg()?;
-            self.backend
-                .move_cursor_to(0, self.size.height - err_height)?;
-        }
+            self.size.height - err_height
+        } else {
+            self.base_row + height
+        };
+        self.backend.move_cursor_to(self.indent, err_row)?;
 
-        self.backend
-            .write_styled(&crate::symbols::current().cross.red())?;
+        match self.error_prefix {
+            Some((ref prefix, color)) => {
+                self.backend.set_fg(color)?;
+                self.backend.write_all(prefix.as_bytes())?;
+                self.backend.set_fg(Color::Reset)?;
+            }
+            None => self
+                .backend
+                .write_styled(&crate::symbols::current().cross.red())?,
+        }
         self.backend.write_all(b" ")?;
 
-        e.render(&mut layout, &mut *self.backend)?;
+        error.replay_moved_down(err_row, &mut *self.backend)?;
+        self.drawn_height = err_row + err_height - self.base_row;
+        self.showing_error = true;
+        self.last_error = Some(error);
 
         self.flush()
     }
 
     fn exit(&mut self) -> io::Result<()> {
-        self.update_size()?;
+        self.sync_size()?;
         let height = self.prompt.height(&mut self.layout());
         self.goto_last_line(height)?;
         self.backend.reset()
@@ -260,15 +676,95 @@ impl<P: Prompt, B: Backend> Input<P, B> {
 
     /// Display the prompt and process events until the user presses `Enter`.
     ///
-    /// After the user presses `Enter`, [`validate`](Prompt::validate) will be called.
-    pub fn run<E>(mut self, events: &mut E) -> error::Result<Option<P::Output>>
+    /// After the user presses `Enter` (or the [`submit_key`](Input::submit_key) if set),
+    /// [`validate`](Prompt::validate) will be called.
+    pub fn run<E>(self, events: &mut E) -> error::Result<Option<P::Output>>
+    where
+        E: EventIterator,
+    {
+        self.run_with(events, P::finish)
+    }
+
+    /// Display the prompt and process events until the user presses `Enter`, like
+    /// [`run`](Input::run).
+    ///
+    /// Once the prompt is submitted, it is finished with
+    /// [`finish_detailed`](Prompt::finish_detailed) instead of [`finish`](Prompt::finish), which
+    /// also returns the text that was entered.
+    pub fn run_detailed<E>(self, events: &mut E) -> error::Result<Option<(P::Output, String)>>
     where
         E: EventIterator,
+    {
+        self.run_with(events, P::finish_detailed)
+    }
+
+    /// Display the prompt and process events until the user submits or cancels it.
+    ///
+    /// Unlike [`run`](Input::run), cancelling the prompt is not an error. `Ok(None)` is returned
+    /// if the user presses `Ctrl+C`, `Esc` or the [`skip_key`](Input::skip_key). `Esc` cancels the
+    /// prompt even if [`on_esc`](Input::on_esc) is [`OnEsc::Ignore`], unless the prompt handles it
+    /// first. An [`Err`] is only returned for I/O errors, and if the events run out
+    /// ([`ErrorKind::Eof`](error::ErrorKind::Eof)).
+    pub fn run_optional<E>(mut self, events: &mut E) -> error::Result<Option<P::Output>>
+    where
+        E: EventIterator,
+    {
+        if self.on_esc == OnEsc::Ignore {
+            self.on_esc = OnEsc::SkipQuestion;
+        }
+
+        match self.run(events) {
+            Err(error::ErrorKind::Interrupted) | Err(error::ErrorKind::Aborted) => Ok(None),
+            res => res,
+        }
+    }
+
+    fn run_with<E, T, F>(mut self, events: &mut E, finish: F) -> error::Result<Option<T>>
+    where
+        E: EventIterator,
+        F: FnOnce(P) -> T,
     {
         self.init()?;
 
+        // An event which was polled while coalescing movement keys, but was not part of the batch
+        let mut pending = None;
+        // Whether keys have been handled since the last render
+        let mut coalesced = false;
+
         loop {
-            let e = events.next_event()?;
+            if pending.is_none() {
+                // Work is only done while no keys are waiting, so that typing is not slowed down
+                while self.prompt.tick() {
+                    self.render()?;
+                    pending = events.poll_event()?;
+                    if pending.is_some() {
+                        break;
+                    }
+                }
+            }
+
+            let e = match pending.take() {
+                Some(e) => e,
+                None => match events.next_input()? {
+                    Event::Key(e) => e,
+                    Event::Paste(text) => {
+                        // A paste is handled in one go, so it is only validated and rendered once
+                        if self.prompt.handle_paste(&text) && self.revalidate()? {
+                            self.render()?;
+                        }
+                        continue;
+                    }
+                },
+            };
+            // Prompts only act on key presses and repeats
+            if e.kind == KeyEventKind::Release {
+                continue;
+            }
+
+            let submit = match self.submit_key {
+                Some(submit_key) => e == submit_key,
+                None => e.code == KeyCode::Enter,
+            };
 
             let key_handled = match e.code {
                 KeyCode::Char('c') if e.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -279,6 +775,14 @@ impl<P: Prompt, B: Backend> Input<P, B> {
                     self.exit()?;
                     return Err(error::ErrorKind::Eof);
                 }
+                _ if self.skip_key == Some(e) => {
+                    self.clear()?;
+                    self.backend.reset()?;
+
+                    return Ok(None);
+                }
+                // The prompt can use `Esc` itself first, for example to clear a query
+                KeyCode::Esc if self.on_esc != OnEsc::Ignore && self.prompt.handle_key(e) => true,
                 KeyCode::Esc if self.on_esc == OnEsc::Terminate => {
                     self.exit()?;
                     return Err(error::ErrorKind::Aborted);
@@ -289,16 +793,19 @@ impl<P: Prompt, B: Backend> Input<P, B> {
 
                     return Ok(None);
                 }
-                KeyCode::Enter => match self.prompt.validate() {
+                _ if submit => match self.validate_submit()? {
                     Ok(Validation::Finish) => {
                         self.clear()?;
                         self.backend.reset()?;
+                        self.refresh_line_prefix();
+                        self.write_line_prefix()?;
 
-                        return Ok(Some(self.prompt.finish()));
+                        return Ok(Some(finish(self.prompt)));
                     }
                     Ok(Validation::Continue) => true,
                     Err(e) => {
                         self.print_error(e)?;
+                        coalesced = false;
 
                         continue;
                     }
@@ -307,17 +814,41 @@ impl<P: Prompt, B: Backend> Input<P, B> {
             };
 
             if key_handled {
+                if !self.revalidate()? {
+                    coalesced = false;
+                    continue;
+                }
+
+                if self.coalesce_movement && Movement::try_from_key(e).is_some() {
+                    pending = events.poll_event()?;
+
+                    if matches!(pending, Some(next) if is_repeat_of(next, e)) {
+                        coalesced = true;
+                        continue;
+                    }
+                }
+            }
+
+            if key_handled || coalesced {
+                coalesced = false;
                 self.render()?;
             }
         }
     }
 }
 
+/// Whether `next` is the same key as `key`, which is the case when a key is held down.
+fn is_repeat_of(next: KeyEvent, key: KeyEvent) -> bool {
+    next.kind != KeyEventKind::Release && next.code == key.code && next.modifiers == key.modifiers
+}
+
 #[derive(Debug)]
 struct TerminalState<B: Backend> {
     backend: B,
     hide_cursor: bool,
     cursor_hidden: bool,
+    title_set: bool,
+    keyboard_enhanced: bool,
     enabled: bool,
 }
 
@@ -328,6 +859,8 @@ impl<B: Backend> TerminalState<B> {
             enabled: false,
             hide_cursor,
             cursor_hidden: false,
+            title_set: false,
+            keyboard_enhanced: false,
         }
     }
 
@@ -337,7 +870,20 @@ impl<B: Backend> TerminalState<B> {
             self.backend.hide_cursor()?;
             self.cursor_hidden = true;
         }
-        self.backend.enable_raw_mode()
+        self.backend.enable_raw_mode()?;
+
+        if !self.keyboard_enhanced && self.backend.supports_keyboard_enhancement()? {
+            self.backend.enable_keyboard_enhancement()?;
+            self.keyboard_enhanced = true;
+        }
+
+        Ok(())
+    }
+
+    fn set_title(&mut self, title: &str) -> io::Result<()> {
+        self.backend.set_title(title)?;
+        self.title_set = true;
+        Ok(())
     }
 
     fn reset(&mut self) -> io::Result<()> {
@@ -346,6 +892,14 @@ impl<B: Backend> TerminalState<B> {
             self.backend.show_cursor()?;
             self.cursor_hidden = false;
         }
+        if self.title_set {
+            self.backend.restore_title()?;
+            self.title_set = false;
+        }
+        if self.keyboard_enhanced {
+            self.backend.disable_keyboard_enhancement()?;
+            self.keyboard_enhanced = false;
+        }
         self.backend.disable_raw_mode()
     }
 }
@@ -375,7 +929,10 @@ impl<B: Backend> DerefMut for TerminalState<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{backend::TestBackend, events::TestEvents};
+    use crate::{
+        backend::{TestBackend, TitleOp},
+        events::TestEvents,
+    };
 
     #[derive(Debug, Default, Clone, Copy)]
     struct TestPrompt {
@@ -401,8 +958,8 @@ mod tests {
             layout.offset_cursor((0, self.height))
         }
 
-        fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
-            todo!("{:?}", key)
+        fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+            false
         }
     }
 
@@ -412,6 +969,10 @@ mod tests {
         type Output = ();
 
         fn finish(self) -> Self::Output {}
+
+        fn message(&self) -> Option<&str> {
+            Some("Test prompt")
+        }
     }
 
     #[test]
@@ -426,6 +987,688 @@ mod tests {
         crate::assert_backend_snapshot!(*backend);
     }
 
+    #[test]
+    fn test_ignore_release() {
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = TestEvents::new(vec![
+            KeyEvent::from(KeyCode::Enter).with_kind(KeyEventKind::Release),
+            KeyEvent::from(KeyCode::Esc),
+        ]);
+
+        // The release of enter should not submit the prompt
+        let res = Input::new(TestPrompt::default(), &mut backend)
+            .on_esc(OnEsc::SkipQuestion)
+            .run(&mut events)
+            .unwrap();
+        assert!(res.is_none());
+    }
+
+    #[test]
+    fn test_run_optional() {
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        for &(on_esc, key) in &[
+            (OnEsc::Ignore, KeyEvent::from(KeyCode::Esc)),
+            (OnEsc::Terminate, KeyEvent::from(KeyCode::Esc)),
+            (OnEsc::Ignore, ctrl_c),
+        ] {
+            let mut backend = TestBackend::new((100, 20).into());
+            let mut events = TestEvents::new(Some(key));
+
+            let res = Input::new(TestPrompt::default(), &mut backend)
+                .on_esc(on_esc)
+                .run_optional(&mut events)
+                .unwrap();
+            assert!(res.is_none());
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+        let res = Input::new(TestPrompt::default(), &mut backend)
+            .run_optional(&mut events)
+            .unwrap();
+        assert_eq!(res, Some(()));
+    }
+
+    #[test]
+    fn test_line_prefix() {
+        use std::{cell::Cell, rc::Rc};
+
+        use crate::widgets::Prompt as PromptWidget;
+
+        let renders = Rc::new(Cell::new(0));
+        let prefix_renders = Rc::clone(&renders);
+
+        let size = (40, 10).into();
+        let mut backend = TestBackend::new(size);
+        let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+        Input::new(TestPrompt { height: 1 }, &mut backend)
+            .with_line_prefix(Box::new(move || {
+                prefix_renders.set(prefix_renders.get() + 1);
+                format!("[{}] ", prefix_renders.get())
+            }))
+            .run(&mut events)
+            .unwrap();
+
+        // Once for the render, and once when finishing
+        assert_eq!(renders.get(), 2);
+
+        PromptWidget::write_finished_message(&"Test prompt", false, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        expected.set_attributes(Attributes::DIM).unwrap();
+        std::io::Write::write_all(&mut expected, b"[2] ").unwrap();
+        expected.set_attributes(Attributes::empty()).unwrap();
+        PromptWidget::write_finished_message(&"Test prompt", false, &mut expected).unwrap();
+
+        // The finished line starts after the prefix
+        backend.assert_eq(&expected);
+    }
+
+    #[test]
+    fn test_layout_line_prefix() {
+        let mut backend = TestBackend::new((40, 10).into());
+        let mut input = Input::new(TestPrompt::default(), &mut backend)
+            .with_line_prefix(Box::new(|| "12:00 ".to_owned()));
+        input.size = (40, 10).into();
+
+        assert_eq!(input.layout(), Layout::new(0, input.size));
+        input.refresh_line_prefix();
+        // The prompt starts after the prefix
+        assert_eq!(input.layout(), Layout::new(6, input.size));
+    }
+
+    #[test]
+    fn test_run_detailed() {
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+        // Prompts which don't implement `finish_detailed` give an empty string
+        let res = Input::new(TestPrompt::default(), &mut backend)
+            .run_detailed(&mut events)
+            .unwrap();
+        assert_eq!(res, Some(((), String::new())));
+    }
+
+    #[test]
+    fn test_wants_cursor() {
+        #[derive(Debug, Default)]
+        struct NoCursorPrompt(TestPrompt);
+
+        impl Widget for NoCursorPrompt {
+            fn render<B: Backend>(
+                &mut self,
+                layout: &mut Layout,
+                backend: &mut B,
+            ) -> io::Result<()> {
+                self.0.render(layout, backend)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                self.0.height(layout)
+            }
+
+            fn cursor_pos(&mut self, _: Layout) -> (u16, u16) {
+                unreachable!("cursor_pos should not be called if the cursor is not wanted")
+            }
+
+            fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
+                self.0.handle_key(key)
+            }
+
+            fn wants_cursor(&self) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for NoCursorPrompt {
+            type ValidateErr = &'static str;
+
+            type Output = ();
+
+            fn finish(self) -> Self::Output {}
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+
+        let mut input = Input::new(NoCursorPrompt::default(), &mut backend);
+        input.init().unwrap();
+        assert!(input.backend.cursor_hidden);
+        input.backend.reset().unwrap();
+        assert!(!input.backend.cursor_hidden);
+        drop(input);
+
+        let mut input = Input::new(TestPrompt::default(), &mut backend);
+        input.init().unwrap();
+        assert!(!input.backend.cursor_hidden);
+    }
+
+    #[test]
+    fn test_paste() {
+        #[derive(Debug, Default)]
+        struct PastePrompt {
+            pasted: Vec<String>,
+        }
+
+        impl Widget for PastePrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
+                unreachable!("pasted text should not be sent as keys, got {:?}", key)
+            }
+
+            fn handle_paste(&mut self, text: &str) -> bool {
+                self.pasted.push(text.to_owned());
+                true
+            }
+        }
+
+        impl Prompt for PastePrompt {
+            type ValidateErr = &'static str;
+
+            type Output = Vec<String>;
+
+            fn finish(self) -> Self::Output {
+                self.pasted
+            }
+        }
+
+        struct PasteEvents(std::vec::IntoIter<Event>);
+
+        impl EventIterator for PasteEvents {
+            fn next_event(&mut self) -> io::Result<KeyEvent> {
+                unreachable!("the runner should only ask for inputs")
+            }
+
+            fn next_input(&mut self) -> io::Result<Event> {
+                Ok(self.0.next().expect("Events ran out"))
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = PasteEvents(
+            vec![
+                Event::Paste("hello\nworld".into()),
+                Event::Paste("!".into()),
+                Event::Key(KeyCode::Enter.into()),
+            ]
+            .into_iter(),
+        );
+
+        let pasted = Input::new(PastePrompt::default(), &mut backend)
+            .run(&mut events)
+            .unwrap();
+
+        assert_eq!(
+            pasted,
+            Some(vec!["hello\nworld".to_owned(), "!".to_owned()])
+        );
+    }
+
+    #[test]
+    fn test_indent() {
+        use std::io::Write;
+
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+
+        let mut input = Input::new(TestPrompt { height: 1 }, &mut backend).indent(4);
+        input.render().unwrap();
+        drop(input);
+
+        let mut expected = TestBackend::new(size);
+        expected.move_cursor_to(4, 0).unwrap();
+        expected.write_all(b"Line 0").unwrap();
+        expected.move_cursor_to(4, 1).unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(backend.get_cursor_pos().unwrap(), (4, 1));
+    }
+
+    #[test]
+    fn test_suspend_on_submit() {
+        for &suspend in &[true, false] {
+            let mut backend = TestBackend::new((100, 20).into());
+            let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+            Input::new(TestPrompt::default(), &mut backend)
+                .set_title_to_message(true)
+                .suspend_on_submit(suspend)
+                .run(&mut events)
+                .unwrap();
+
+            let title = TitleOp::SetTitle("Test prompt".into());
+            if suspend {
+                // The title is restored along with the rest of the terminal while validating
+                assert_eq!(
+                    backend.title_ops(),
+                    &[
+                        title.clone(),
+                        TitleOp::RestoreTitle,
+                        title,
+                        TitleOp::RestoreTitle
+                    ]
+                );
+            } else {
+                assert_eq!(backend.title_ops(), &[title, TitleOp::RestoreTitle]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_title_to_message() {
+        for &set_title in &[true, false] {
+            let mut backend = TestBackend::new((100, 20).into());
+            let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+
+            Input::new(TestPrompt::default(), &mut backend)
+                .set_title_to_message(set_title)
+                .run(&mut events)
+                .unwrap();
+
+            if set_title {
+                // The title is restored once the prompt is submitted
+                assert_eq!(
+                    backend.title_ops(),
+                    &[
+                        TitleOp::SetTitle("Test prompt".into()),
+                        TitleOp::RestoreTitle
+                    ]
+                );
+            } else {
+                assert!(backend.title_ops().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn test_keyboard_enhancement() {
+        let mut backend = TestBackend::new((100, 20).into()).with_keyboard_enhancement();
+
+        let mut input = Input::new(TestPrompt::default(), &mut backend);
+        input.init().unwrap();
+        assert!(input.backend.is_keyboard_enhanced());
+        drop(input);
+
+        // It is turned off again once the prompt is done
+        assert!(!backend.is_keyboard_enhanced());
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut input = Input::new(TestPrompt::default(), &mut backend);
+        input.init().unwrap();
+        assert!(!input.backend.is_keyboard_enhanced());
+    }
+
+    #[test]
+    fn test_first_render_no_clear() {
+        use std::io::Write;
+
+        let size = (20, 5).into();
+        let mut backend = TestBackend::new(size);
+        for row in 0..5 {
+            backend.move_cursor_to(0, row).unwrap();
+            write!(backend, "row {}", row).unwrap();
+        }
+
+        let mut input =
+            Input::new(TestPrompt { height: 1 }, &mut backend).first_render_no_clear(true);
+        input.base_row = 1;
+        input.render().unwrap();
+        let first_render = input.backend.clone();
+
+        // The second render clears as usual
+        input.render().unwrap();
+        drop(input);
+
+        // Only the line of the prompt is drawn over, and nothing is cleared
+        let mut expected = TestBackend::new(size);
+        for row in 0..5 {
+            expected.move_cursor_to(0, row).unwrap();
+            if row == 1 {
+                expected.write_all(b"Line 0").unwrap();
+            } else {
+                write!(expected, "row {}", row).unwrap();
+            }
+        }
+        expected.move_cursor_to(0, 2).unwrap();
+        first_render.assert_eq(&expected);
+
+        let mut expected = TestBackend::new(size);
+        expected.write_all(b"row 0").unwrap();
+        expected.move_cursor_to(0, 1).unwrap();
+        expected.write_all(b"Line 0").unwrap();
+        expected.move_cursor_to(0, 2).unwrap();
+        backend.assert_eq(&expected);
+
+        // The first frame is drawn at the indent as well
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(0, 1).unwrap();
+        backend.write_all(b"row 1").unwrap();
+
+        let mut input = Input::new(TestPrompt { height: 1 }, &mut backend)
+            .indent(4)
+            .first_render_no_clear(true);
+        input.base_row = 1;
+        input.render().unwrap();
+        drop(input);
+
+        let mut expected = TestBackend::new(size);
+        expected.move_cursor_to(0, 1).unwrap();
+        expected.write_all(b"row ").unwrap();
+        expected.write_all(b"Line 0").unwrap();
+        expected.move_cursor_to(4, 2).unwrap();
+        backend.assert_eq(&expected);
+    }
+
+    #[test]
+    fn test_preserve_below() {
+        use std::io::Write;
+
+        let size = (20, 10).into();
+
+        // The prompt is 2 lines tall, but was drawn with a 1 line error below it in the last case
+        for &(preserve_below, drawn_height) in &[(0, 2), (2, 2), (2, 3)] {
+            let mut backend = TestBackend::new(size);
+            for row in 0..10 {
+                backend.move_cursor_to(0, row).unwrap();
+                write!(backend, "row {}", row).unwrap();
+            }
+
+            let mut input =
+                Input::new(TestPrompt { height: 2 }, &mut backend).preserve_below(preserve_below);
+            input.size = size;
+            input.base_row = 1;
+            input.drawn_height = drawn_height;
+            input.clear().unwrap();
+            drop(input);
+
+            // Everything which was drawn is cleared, along with everything after the preserved
+            // lines right below it
+            let prompt_end = 1 + drawn_height;
+            let mut expected = TestBackend::new(size);
+            expected.write_all(b"row 0").unwrap();
+            for row in prompt_end..(prompt_end + preserve_below) {
+                expected.move_cursor_to(0, row).unwrap();
+                write!(expected, "row {}", row).unwrap();
+            }
+            expected.move_cursor_to(0, 1).unwrap();
+
+            backend.assert_eq(&expected);
+        }
+    }
+
+    #[test]
+    fn test_refresh_size() {
+        let size = (100, 20).into();
+        let stale_size = (100, 5).into();
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(0, 15).unwrap();
+
+        // The terminal was resized from `stale_size` to `size`, but nothing told the input
+        let mut input = Input::new(TestPrompt { height: 3 }, &mut backend);
+        input.size = stale_size;
+        input.base_row = 15;
+        input.render().unwrap();
+
+        assert_eq!(input.size, size);
+        // The prompt fits below the base row in the new size, so it is not moved
+        assert_eq!(input.base_row, 15);
+        drop(input);
+
+        let mut input = Input::new(TestPrompt { height: 3 }, &mut backend).refresh_size(false);
+        input.size = stale_size;
+        input.base_row = 4;
+        input.render().unwrap();
+
+        assert_eq!(input.size, stale_size);
+        // The prompt is moved up to fit in the stale size
+        assert_eq!(input.base_row, 2);
+        drop(input);
+
+        // The terminal shrunk from `size`, so the base row is past the end of the terminal
+        let mut backend = TestBackend::new(stale_size);
+        let mut input = Input::new(TestPrompt { height: 3 }, &mut backend);
+        input.size = size;
+        input.base_row = 15;
+        input.render().unwrap();
+
+        assert_eq!(input.size, stale_size);
+        assert_eq!(input.base_row, 2);
+    }
+
+    #[test]
+    fn test_persist_errors() {
+        #[derive(Debug)]
+        struct CharPrompt(char, usize);
+
+        impl Widget for CharPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, backend: &mut B) -> io::Result<()> {
+                write!(backend, "value: {}", self.0)
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((8, 0))
+            }
+
+            fn handle_key(&mut self, key: crate::events::KeyEvent) -> bool {
+                match key.code {
+                    KeyCode::Char(c) => {
+                        self.0 = c;
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+
+        impl Prompt for CharPrompt {
+            type ValidateErr = String;
+            type Output = usize;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                self.is_valid().map(|_| Validation::Finish)
+            }
+
+            fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
+                if self.0 == 'y' {
+                    Ok(())
+                } else {
+                    Err(format!("{} is not y", self.0))
+                }
+            }
+
+            fn finish(self) -> Self::Output {
+                self.1
+            }
+
+            fn on_validate_error(&mut self, _: &Self::ValidateErr) {
+                self.1 += 1;
+            }
+        }
+
+        fn run(persist_errors: bool, keys: &[KeyCode]) -> TestBackend {
+            let mut backend = TestBackend::new((100, 20).into());
+            let mut events = TestEvents::new(
+                keys.iter()
+                    .copied()
+                    .map(KeyEvent::from)
+                    .chain(Some(KeyCode::Null.into())),
+            );
+
+            let res = Input::new(CharPrompt(' ', 0), &mut backend)
+                .persist_errors(persist_errors)
+                .run(&mut events);
+            assert!(matches!(res, Err(error::ErrorKind::Eof)));
+
+            backend
+        }
+
+        let invalid = [KeyCode::Char('n'), KeyCode::Enter, KeyCode::Char('x')];
+        let valid = [KeyCode::Char('n'), KeyCode::Enter, KeyCode::Char('y')];
+
+        // The error is cleared on the next key
+        run(false, &invalid).assert_eq(&run(false, &[KeyCode::Char('x')]));
+        // The error is shown again for the new value
+        run(true, &invalid).assert_eq(&run(false, &[KeyCode::Char('x'), KeyCode::Enter]));
+        // The error is cleared once the value is valid, without submitting the prompt
+        run(true, &valid).assert_eq(&run(false, &[KeyCode::Char('y')]));
+
+        // The error is only reported again when it changes
+        let mut backend = TestBackend::new((100, 20).into());
+        let mut events = TestEvents::new(
+            [
+                KeyCode::Char('n'),
+                KeyCode::Enter,
+                KeyCode::Char('n'),
+                KeyCode::Char('x'),
+                KeyCode::Char('y'),
+                KeyCode::Enter,
+            ]
+            .iter()
+            .copied()
+            .map(KeyEvent::from),
+        );
+        let errors = Input::new(CharPrompt(' ', 0), &mut backend)
+            .persist_errors(true)
+            .run(&mut events)
+            .unwrap();
+        assert_eq!(errors, Some(2));
+    }
+
+    #[test]
+    fn test_error_prefix() {
+        use std::io::Write;
+
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+
+        Input::new(TestPrompt { height: 1 }, &mut backend)
+            .error_prefix(">>", Color::LightRed)
+            .print_error("error text")
+            .unwrap();
+
+        let mut expected = TestBackend::new(size);
+        expected.write_all(b"Line 0").unwrap();
+        expected.move_cursor_to(0, 1).unwrap();
+        expected.set_fg(Color::LightRed).unwrap();
+        expected.write_all(b">>").unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_all(b" error text").unwrap();
+        expected.move_cursor_to(0, 1).unwrap();
+
+        backend.assert_eq(&expected);
+    }
+
+    #[test]
+    fn test_on_validate_error() {
+        use std::io::Write;
+
+        #[derive(Debug)]
+        struct RetryPrompt {
+            message: &'static str,
+            errors: usize,
+        }
+
+        impl Widget for RetryPrompt {
+            fn render<B: Backend>(&mut self, _: &mut Layout, backend: &mut B) -> io::Result<()> {
+                backend.write_all(self.message.as_bytes())
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((self.message.len() as u16, 0))
+            }
+
+            fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+                false
+            }
+        }
+
+        impl Prompt for RetryPrompt {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                if self.errors < 2 {
+                    Err("error text")
+                } else {
+                    Ok(Validation::Finish)
+                }
+            }
+
+            fn finish(self) -> Self::Output {
+                self.errors
+            }
+
+            fn on_validate_error(&mut self, err: &Self::ValidateErr) {
+                assert_eq!(*err, "error text");
+                self.message = "Try again: ";
+                self.errors += 1;
+            }
+        }
+
+        let size = (100, 20).into();
+        let mut backend = TestBackend::new(size);
+
+        let mut input = Input::new(
+            RetryPrompt {
+                message: "Message: ",
+                errors: 0,
+            },
+            &mut backend,
+        );
+        input.print_error("error text").unwrap();
+        drop(input);
+
+        // The prompt is rendered with the changed message
+        let mut expected = TestBackend::new(size);
+        expected.write_all(b"Try again: ").unwrap();
+        expected.move_cursor_to(0, 1).unwrap();
+        expected.set_fg(Color::Red).unwrap();
+        expected
+            .write_all(crate::symbols::current().cross.to_string().as_bytes())
+            .unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_all(b" error text").unwrap();
+        expected.move_cursor_to(11, 0).unwrap();
+
+        backend.assert_eq(&expected);
+
+        let mut backend = TestBackend::new(size);
+        let mut events = TestEvents::new(vec![KeyCode::Enter.into(); 3]);
+        let errors = Input::new(
+            RetryPrompt {
+                message: "Message: ",
+                errors: 0,
+            },
+            &mut backend,
+        )
+        .run(&mut events)
+        .unwrap();
+
+        assert_eq!(errors, Some(2));
+    }
+
     #[test]
     fn test_adjust_scrollback() {
         let prompt = TestPrompt::default();
@@ -438,10 +1681,25 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                skip_key: None,
+                submit_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
+                indent: 0,
                 size,
+                refresh_size: true,
+                persist_errors: false,
+                showing_error: false,
+                last_error: None,
+                error_prefix: None,
+                preserve_below: 0,
+                drawn_height: 0,
+                title_to_message: false,
+                skip_first_clear: false,
+                coalesce_movement: false,
+                suspend_on_submit: false,
                 render_overflow: false,
+                line_prefix: None,
             }
             .adjust_scrollback(3)
             .unwrap(),
@@ -454,10 +1712,25 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                skip_key: None,
+                submit_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
+                indent: 0,
                 size,
+                refresh_size: true,
+                persist_errors: false,
+                showing_error: false,
+                last_error: None,
+                error_prefix: None,
+                preserve_below: 0,
+                drawn_height: 0,
+                title_to_message: false,
+                skip_first_clear: false,
+                coalesce_movement: false,
+                suspend_on_submit: false,
                 render_overflow: false,
+                line_prefix: None,
             }
             .adjust_scrollback(6)
             .unwrap(),
@@ -469,10 +1742,25 @@ mod tests {
             Input {
                 prompt,
                 on_esc: OnEsc::Ignore,
+                skip_key: None,
+                submit_key: None,
                 backend: TerminalState::new(&mut backend, false),
                 base_row: 14,
+                indent: 0,
                 size,
+                refresh_size: true,
+                persist_errors: false,
+                showing_error: false,
+                last_error: None,
+                error_prefix: None,
+                preserve_below: 0,
+                drawn_height: 0,
+                title_to_message: false,
+                skip_first_clear: false,
+                coalesce_movement: false,
+                suspend_on_submit: false,
                 render_overflow: false,
+                line_prefix: None,
             }
             .adjust_scrollback(10)
             .unwrap(),
@@ -491,10 +1779,25 @@ mod tests {
         assert!(Input {
             prompt,
             on_esc: OnEsc::Ignore,
+            skip_key: None,
+            submit_key: None,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 5,
+            indent: 0,
+            refresh_size: true,
+            persist_errors: false,
+            showing_error: false,
+            last_error: None,
+            error_prefix: None,
+            preserve_below: 0,
+            drawn_height: 0,
+            title_to_message: false,
+            skip_first_clear: false,
+            coalesce_movement: false,
+            suspend_on_submit: false,
             render_overflow: false,
+            line_prefix: None,
         }
         .render()
         .is_ok());
@@ -511,10 +1814,25 @@ mod tests {
         let mut input = Input {
             prompt: TestPrompt::default(),
             on_esc: OnEsc::Ignore,
+            skip_key: None,
+            submit_key: None,
             backend: TerminalState::new(&mut backend, false),
             size,
             base_row: 15,
+            indent: 0,
+            refresh_size: true,
+            persist_errors: false,
+            showing_error: false,
+            last_error: None,
+            error_prefix: None,
+            preserve_below: 0,
+            drawn_height: 0,
+            title_to_message: false,
+            skip_first_clear: false,
+            coalesce_movement: false,
+            suspend_on_submit: false,
             render_overflow: false,
+            line_prefix: None,
         };
 
         assert!(input.goto_last_line(9).is_ok());
@@ -533,10 +1851,25 @@ mod tests {
         assert!(Input {
             prompt: TestPrompt { height: 5 },
             on_esc: OnEsc::Ignore,
+            skip_key: None,
+            submit_key: None,
             backend: TerminalState::new(&mut backend, true),
             base_row: 0,
+            indent: 0,
             size,
+            refresh_size: true,
+            persist_errors: false,
+            showing_error: false,
+            last_error: None,
+            error_prefix: None,
+            preserve_below: 0,
+            drawn_height: 0,
+            title_to_message: false,
+            skip_first_clear: false,
+            coalesce_movement: false,
+            suspend_on_submit: false,
             render_overflow: false,
+            line_prefix: None,
         }
         .print_error(error)
         .is_ok());
@@ -544,6 +1877,23 @@ mod tests {
         crate::assert_backend_snapshot!(backend);
     }
 
+    #[test]
+    fn test_skip_key() {
+        let skip_key = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let res = Input::new(TestPrompt::default(), &mut backend)
+            .skip_key(Some(skip_key))
+            .run(&mut TestEvents::new(Some(skip_key)));
+        assert!(matches!(res, Ok(None)));
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let res = Input::new(TestPrompt::default(), &mut backend)
+            .skip_key(Some(skip_key))
+            .run(&mut TestEvents::new(Some(KeyCode::Enter.into())));
+        assert!(matches!(res, Ok(Some(()))));
+    }
+
     #[test]
     fn test_zero_size() {
         let mut backend = TestBackend::new((20, 0).into());
@@ -578,4 +1928,139 @@ mod tests {
             "Invalid terminal Size { width: 0, height: 20 }. Both width and height must be larger than 0"
         );
     }
+
+    #[test]
+    fn test_coalesce_movement() {
+        /// Counts the keys it handles, and the times it is rendered.
+        #[derive(Debug, Default)]
+        struct CountPrompt {
+            keys: usize,
+            renders: usize,
+        }
+
+        impl Widget for CountPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, _: &mut B) -> io::Result<()> {
+                self.renders += 1;
+                layout.offset_y += 1;
+                Ok(())
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+                self.keys += 1;
+                true
+            }
+        }
+
+        impl Prompt for CountPrompt {
+            type ValidateErr = &'static str;
+            type Output = (usize, usize);
+
+            fn finish(self) -> Self::Output {
+                (self.keys, self.renders)
+            }
+        }
+
+        fn run(coalesce_movement: bool, keys: Vec<KeyEvent>) -> (usize, usize) {
+            let mut backend = TestBackend::new((100, 20).into());
+            Input::new(CountPrompt::default(), &mut backend)
+                .coalesce_movement(coalesce_movement)
+                .run(&mut TestEvents::new(keys))
+                .unwrap()
+                .unwrap()
+        }
+
+        let mut keys = vec![KeyEvent::from(KeyCode::Down); 100];
+        keys.push(KeyCode::Enter.into());
+
+        // The first render is before any keys are handled
+        assert_eq!(run(false, keys.clone()), (100, 101));
+        assert_eq!(run(true, keys), (100, 2));
+
+        // Other keys break the batch, and are rendered separately
+        let keys = vec![
+            KeyCode::Down.into(),
+            KeyCode::Down.into(),
+            KeyCode::Char('a').into(),
+            KeyCode::Char('a').into(),
+            KeyCode::Up.into(),
+            KeyCode::Down.into(),
+            KeyCode::Enter.into(),
+        ];
+        assert_eq!(run(true, keys), (6, 6));
+    }
+
+    #[test]
+    fn test_tick() {
+        #[derive(Debug, Default)]
+        struct TickPrompt {
+            work: usize,
+            renders: usize,
+        }
+
+        impl Widget for TickPrompt {
+            fn render<B: Backend>(&mut self, layout: &mut Layout, _: &mut B) -> io::Result<()> {
+                self.renders += 1;
+                layout.offset_y += 1;
+                Ok(())
+            }
+
+            fn height(&mut self, layout: &mut Layout) -> u16 {
+                layout.offset_y += 1;
+                1
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, _: crate::events::KeyEvent) -> bool {
+                self.work += 3;
+                true
+            }
+        }
+
+        impl Prompt for TickPrompt {
+            type ValidateErr = &'static str;
+            type Output = usize;
+
+            fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                assert_eq!(self.work, 0, "the work is finished before validating");
+                Ok(Validation::Finish)
+            }
+
+            fn finish(self) -> Self::Output {
+                self.renders
+            }
+
+            fn tick(&mut self) -> bool {
+                if self.work == 0 {
+                    return false;
+                }
+                self.work -= 1;
+                true
+            }
+        }
+
+        let mut backend = TestBackend::new((100, 20).into());
+        let renders = Input::new(TickPrompt::default(), &mut backend)
+            .run(&mut TestEvents::new(vec![
+                KeyCode::Char('a').into(),
+                KeyCode::Enter.into(),
+            ]))
+            .unwrap()
+            .unwrap();
+
+        // The first render, the render after the key, and one after the only tick before the next
+        // key is available
+        assert_eq!(renders, 3);
+    }
 }
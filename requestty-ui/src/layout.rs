@@ -198,6 +198,51 @@ impl Layout {
             0
         }
     }
+
+    /// Gets the lines of a widget with the given `height` that will be visible, taking into account
+    /// the `max_height` and the `render_region`.
+    ///
+    /// The start of the range is the topmost visible line. It is the line on which things like a
+    /// selection indicator should be rendered if the widget is partially hidden.
+    ///
+    /// If the height of the widget to render is 5 and the max_height is 2, then the lines would be:
+    /// - `RenderRegion::Top`: `0..2`
+    /// - `RenderRegion::Middle`: `1..3`
+    /// - `RenderRegion::Bottom`: `3..5`
+    pub fn visible_lines(&self, height: u16) -> std::ops::Range<u16> {
+        let start = self.get_start(height);
+        start..(start + height.min(self.max_height))
+    }
+
+    /// Splits the layout into two side by side layouts, where the left one is `left_width` columns
+    /// wide, and the right one takes the rest of the columns.
+    ///
+    /// The left layout keeps the `line_offset`, while the right layout starts at the beginning of
+    /// its first line. If `left_width` is more than the [`available_width`], the right layout has no
+    /// columns.
+    ///
+    /// ```text
+    ///  ____________
+    /// |            |
+    /// |  ████|█████|
+    /// |  ████|█████|
+    /// '------------'
+    ///    ^^^^-- left_width
+    /// ```
+    ///
+    /// [`available_width`]: Layout::available_width
+    pub fn split_h(self, left_width: u16) -> (Layout, Layout) {
+        let split_x = self.offset_x + left_width.min(self.available_width());
+
+        let mut left = self;
+        left.width = split_x;
+
+        let mut right = self;
+        right.offset_x = split_x;
+        right.line_offset = 0;
+
+        (left, right)
+    }
 }
 
 #[test]
@@ -220,3 +265,44 @@ fn test_layout() {
         5
     );
 }
+
+#[test]
+fn test_visible_lines() {
+    let layout = Layout::new(0, (100, 5).into()).with_max_height(2);
+    assert_eq!(
+        layout
+            .with_render_region(RenderRegion::Top)
+            .visible_lines(5),
+        0..2
+    );
+    assert_eq!(
+        layout
+            .with_render_region(RenderRegion::Middle)
+            .visible_lines(5),
+        1..3
+    );
+    assert_eq!(
+        layout
+            .with_render_region(RenderRegion::Bottom)
+            .visible_lines(5),
+        3..5
+    );
+    assert_eq!(layout.visible_lines(1), 0..1);
+}
+
+#[test]
+fn test_split_h() {
+    let layout = Layout::new(5, (100, 5).into()).with_offset(10, 2);
+
+    let (left, right) = layout.split_h(30);
+    assert_eq!(left.available_width(), 30);
+    assert_eq!(left.line_width(), 25);
+    assert_eq!(left.offset_cursor((0, 0)), (10, 2));
+    assert_eq!(right.available_width(), 60);
+    assert_eq!(right.line_width(), 60);
+    assert_eq!(right.offset_cursor((0, 0)), (40, 2));
+
+    let (left, right) = layout.split_h(200);
+    assert_eq!(left.available_width(), 90);
+    assert_eq!(right.available_width(), 0);
+}
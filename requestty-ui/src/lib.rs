@@ -31,16 +31,20 @@ pub use widgets::Widget;
 
 pub mod backend;
 mod char_input;
+mod editable_value;
 mod error;
 pub mod events;
+mod form;
 mod input;
 pub mod layout;
 mod prompt;
 mod select;
+mod simple_prompt;
 mod string_input;
 pub mod style;
 pub mod symbols;
 mod text;
+mod toggle;
 pub mod widgets;
 
 #[doc(hidden)]
@@ -1,4 +1,8 @@
-use std::{convert::TryFrom, io};
+use std::{
+    convert::TryFrom,
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 use crate::{
     backend::Backend,
@@ -8,6 +12,34 @@ use crate::{
     Widget,
 };
 
+static BOLD_MESSAGE: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether the message of every [`Prompt`] is rendered in bold.
+///
+/// This applies both while the prompt is shown and to the message written by
+/// [`Prompt::write_finished_message`] once it is answered. The message is bold by default.
+///
+/// Also see [`is_message_bold`].
+pub fn bold_message(bold_message: bool) {
+    BOLD_MESSAGE.store(bold_message, Ordering::Relaxed);
+}
+
+/// Whether the message of every [`Prompt`] is rendered in bold.
+///
+/// Also see [`bold_message`].
+pub fn is_message_bold() -> bool {
+    BOLD_MESSAGE.load(Ordering::Relaxed)
+}
+
+/// Writes the message of a prompt, in bold unless it was disabled with [`bold_message`].
+fn write_message<B: Backend>(message: &str, backend: &mut B) -> io::Result<()> {
+    if is_message_bold() {
+        backend.write_styled(&message.bold())
+    } else {
+        backend.write_all(message.as_bytes())
+    }
+}
+
 /// The different delimiters that can be used with hints in [`Prompt`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Delimiter {
@@ -38,12 +70,54 @@ impl From<Delimiter> for Option<(char, char)> {
     }
 }
 
+/// How the answer is placed on the line printed once a question is answered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnswerAlign {
+    /// The answer directly follows the message.
+    Left,
+    /// The answer is pushed to the right edge of the terminal, with padding between the message
+    /// and the answer. If the answer does not fit on the rest of the line, it falls back to
+    /// [`AnswerAlign::Left`].
+    Right,
+}
+
+impl AnswerAlign {
+    /// Writes the padding needed before an answer taking `answer_width` columns, so that it is
+    /// aligned as per `self`. The padding is written at the current position of the cursor, right
+    /// after [`Prompt::write_finished_message`], which `layout` should be at.
+    ///
+    /// If `answer_width` is `None`, the answer is assumed to not fit, and nothing is written.
+    pub fn write_padding<B: Backend>(
+        self,
+        answer_width: Option<u16>,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let answer_width = match (self, answer_width) {
+            (AnswerAlign::Right, Some(answer_width)) => answer_width,
+            _ => return Ok(()),
+        };
+
+        // The last column is left empty, as some terminals move the cursor to the next line as soon
+        // as it is written to, which would leave an empty line after the answer
+        let available = layout.line_width().saturating_sub(1);
+
+        if answer_width <= available {
+            write!(backend, "{:1$}", "", (available - answer_width) as usize)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A generic prompt that renders a message and an optional hint.
 #[derive(Debug, Clone)]
 pub struct Prompt<M, H = &'static str> {
     message: M,
     hint: Option<H>,
     delim: Delimiter,
+    step: Option<(usize, usize)>,
+    hint_reflow: bool,
     message_len: u16,
     hint_len: u16,
 }
@@ -57,18 +131,36 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
             message,
             hint: None,
             delim: Delimiter::Parentheses,
+            step: None,
+            hint_reflow: false,
             hint_len: 0,
         }
     }
 
     /// Sets the hint
     pub fn with_hint(mut self, hint: H) -> Self {
-        self.hint_len = u16::try_from(textwrap::core::display_width(hint.as_ref()))
-            .expect("hint must fit within a u16");
-        self.hint = Some(hint);
+        self.set_hint(Some(hint));
         self
     }
 
+    /// Replaces the hint of an existing prompt.
+    ///
+    /// This can be used to change the hint between renders, for example to show the number of
+    /// remaining characters as the user types.
+    pub fn set_hint(&mut self, hint: Option<H>) {
+        match hint {
+            Some(hint) => {
+                self.hint_len = u16::try_from(textwrap::core::display_width(hint.as_ref()))
+                    .expect("hint must fit within a u16");
+                self.hint = Some(hint);
+            }
+            None => {
+                self.hint = None;
+                self.hint_len = 0;
+            }
+        }
+    }
+
     /// Sets the hint
     pub fn with_optional_hint(self, hint: Option<H>) -> Self {
         match hint {
@@ -83,6 +175,29 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         self
     }
 
+    /// Sets whether the hint is moved to the start of a new line if the message wraps.
+    ///
+    /// By default, the hint is written right after the message, even if the message takes up
+    /// multiple lines. Note that [`width`](Prompt::width) does not take this into account.
+    pub fn with_hint_reflow(mut self, hint_reflow: bool) -> Self {
+        self.hint_reflow = hint_reflow;
+        self
+    }
+
+    /// Sets the progress of the prompt in a list of prompts
+    ///
+    /// It is rendered dimmed as `[current/total]` between the `?` and the message.
+    pub fn with_step(mut self, current: usize, total: usize) -> Self {
+        self.step = Some((current, total));
+        self
+    }
+
+    /// Sets the step
+    pub fn with_optional_step(mut self, step: Option<(usize, usize)>) -> Self {
+        self.step = step;
+        self
+    }
+
     /// Get the message
     pub fn message(&self) -> &M {
         &self.message
@@ -98,6 +213,16 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         self.delim
     }
 
+    /// Get the step as `(current, total)`
+    pub fn step(&self) -> Option<(usize, usize)> {
+        self.step
+    }
+
+    /// Sets or removes the step
+    pub fn set_step(&mut self, step: Option<(usize, usize)>) {
+        self.step = step;
+    }
+
     /// Consume self returning the owned message
     pub fn into_message(self) -> M {
         self.message
@@ -130,31 +255,81 @@ impl<M: AsRef<str>, H: AsRef<str>> Prompt<M, H> {
         }
     }
 
+    /// The character length of the step. It is 0 if the step is absent
+    pub fn step_len(&self) -> u16 {
+        match self.step {
+            // `[<current>/<total>] `
+            Some((current, total)) => (digits(current) + digits(total) + 4) as u16,
+            None => 0,
+        }
+    }
+
     /// The character length of the fully rendered prompt
     pub fn width(&self) -> u16 {
-        if self.hint.is_some() {
+        let width = if self.hint.is_some() {
             // `? <message> <hint> `
             2 + self.message_len + 1 + self.hint_len() + 1
         } else {
             // `? <message> › `
             2 + self.message_len + 3
+        };
+
+        width + self.step_len()
+    }
+
+    /// The line the hint is moved to, relative to the first line, if it is reflowed.
+    fn hint_row(&self, layout: Layout) -> Option<u16> {
+        if !self.hint_reflow || self.hint.is_none() {
+            return None;
+        }
+
+        // `? <message>`
+        let width = self.step_len() + 2 + self.message_len;
+        if width <= layout.line_width() {
+            return None;
+        }
+
+        match end_pos(width, layout) {
+            // The message ends right at the end of a line
+            (0, row) => Some(row),
+            (_, row) => Some(row + 1),
         }
     }
 
     fn cursor_pos_impl(&self, layout: Layout) -> (u16, u16) {
-        let mut width = self.width();
-        let relative_pos = if width > layout.line_width() {
-            width -= layout.line_width();
-
-            (width % layout.width, 1 + width / layout.width)
-        } else {
-            (layout.line_offset + width, 0)
+        let relative_pos = match self.hint_row(layout) {
+            Some(hint_row) => {
+                // `<hint> `
+                let (x, y) = end_pos(self.hint_len() + 1, layout.with_line_offset(0));
+                (x, hint_row + y)
+            }
+            None => end_pos(self.width(), layout),
         };
 
         layout.offset_cursor(relative_pos)
     }
 }
 
+/// The position after writing `width` characters, relative to the offsets of the layout.
+fn end_pos(mut width: u16, layout: Layout) -> (u16, u16) {
+    if width > layout.line_width() {
+        width -= layout.line_width();
+
+        (width % layout.width, 1 + width / layout.width)
+    } else {
+        (layout.line_offset + width, 0)
+    }
+}
+
+fn digits(mut n: usize) -> usize {
+    let mut digits = 1;
+    while n >= 10 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+}
+
 impl<M: AsRef<str>> Prompt<M, &'static str> {
     /// The end prompt to be printed once the question is answered.
     pub fn write_finished_message<B: Backend>(
@@ -169,18 +344,38 @@ impl<M: AsRef<str>> Prompt<M, &'static str> {
             backend.write_styled(&symbol_set.completed.light_green())?;
         }
         backend.write_all(b" ")?;
-        backend.write_styled(&message.as_ref().bold())?;
+        write_message(message.as_ref(), backend)?;
         backend.write_all(b" ")?;
         backend.write_styled(&symbol_set.middle_dot.dark_grey())?;
         backend.write_all(b" ")
     }
+
+    /// The number of columns taken by the end prompt written by
+    /// [`write_finished_message`](Prompt::write_finished_message) when the question is answered.
+    pub fn finished_message_width(message: &M) -> u16 {
+        let symbol_set = crate::symbols::current();
+        let width = textwrap::core::display_width(symbol_set.completed.encode_utf8(&mut [0; 4]))
+            + textwrap::core::display_width(message.as_ref())
+            + textwrap::core::display_width(symbol_set.middle_dot.encode_utf8(&mut [0; 4]))
+            + 3;
+
+        width.min(u16::MAX as usize) as u16
+    }
 }
 
 impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
     fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
         b.write_styled(&"? ".light_green())?;
-        b.write_styled(&self.message.as_ref().bold())?;
-        b.write_all(b" ")?;
+        if let Some((current, total)) = self.step {
+            b.set_fg(Color::DarkGrey)?;
+            write!(b, "[{}/{}] ", current, total)?;
+            b.set_fg(Color::Reset)?;
+        }
+        write_message(self.message.as_ref(), b)?;
+        match self.hint_row(*layout) {
+            Some(hint_row) => b.move_cursor_to(layout.offset_x, layout.offset_y + hint_row)?,
+            None => b.write_all(b" ")?,
+        }
 
         b.set_fg(Color::DarkGrey)?;
 
@@ -221,6 +416,8 @@ impl<M: AsRef<str>, H: AsRef<str>> Widget for Prompt<M, H> {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+
     use crate::{backend::TestBackend, test_consts::*};
 
     use super::*;
@@ -332,6 +529,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_set_hint() {
+        let size = (100, 20).into();
+        let mut prompt = super::Prompt::<_, String>::new("Hello");
+
+        for (hint, cursor_pos) in [("9 left", (17, 0)), ("10 left", (18, 0))].iter() {
+            prompt.set_hint(Some(hint.to_string()));
+
+            let mut expected = TestBackend::new(size);
+            Prompt::new("Hello")
+                .with_hint(hint)
+                .render(&mut Layout::new(0, size), &mut expected)
+                .unwrap();
+
+            let mut layout = Layout::new(0, size);
+            let mut backend = TestBackend::new(size);
+            prompt.render(&mut layout, &mut backend).unwrap();
+            backend.assert_eq(&expected);
+            assert_eq!(prompt.cursor_pos(Layout::new(0, size)), *cursor_pos);
+        }
+
+        prompt.set_hint(None);
+        assert_eq!(prompt.width(), Prompt::new("Hello").width());
+    }
+
     #[test]
     fn test_cursor_pos() {
         let layout = Layout::new(5, (100, 20).into());
@@ -364,4 +586,104 @@ mod tests {
             (51, 12)
         );
     }
+
+    #[test]
+    fn test_hint_reflow() {
+        let size = (20, 10).into();
+        let layout = Layout::new(0, size).with_offset(0, 1);
+        // `? <message>` takes 27 columns, so it wraps onto the second line
+        let message = "0123456789012345678901234";
+
+        let mut prompt = Prompt::new(message).with_hint("hint");
+        assert_eq!(prompt.cursor_pos_impl(layout), (15, 2));
+        assert_eq!(prompt.height(&mut layout.clone()), 2);
+
+        let mut prompt = prompt.with_hint_reflow(true);
+        assert_eq!(prompt.cursor_pos_impl(layout), (7, 3));
+        assert_eq!(prompt.height(&mut layout.clone()), 3);
+
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        prompt.render(&mut layout.clone(), &mut backend).unwrap();
+
+        let mut expected = TestBackend::new_with_layout(size, layout);
+        expected.write_styled(&"? ".light_green()).unwrap();
+        expected.write_styled(&message.bold()).unwrap();
+        expected.move_cursor_to(0, 3).unwrap();
+        expected.set_fg(Color::DarkGrey).unwrap();
+        expected.write_all(b"(hint)").unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_all(b" ").unwrap();
+
+        backend.assert_eq(&expected);
+
+        // The hint is not moved if the message fits
+        assert_eq!(
+            Prompt::new("message")
+                .with_hint("hint")
+                .with_hint_reflow(true)
+                .cursor_pos_impl(layout),
+            (17, 1)
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        let size = (100, 20).into();
+        let layout = Layout::new(5, size);
+
+        let prompt = Prompt::new("Hello").with_step(2, 5);
+        assert_eq!(prompt.step_len(), 6);
+        // `? [2/5] Hello › `
+        assert_eq!(prompt.cursor_pos_impl(layout), (21, 0));
+        assert_eq!(
+            Prompt::new("Hello")
+                .with_hint("world")
+                .with_step(10, 12)
+                .cursor_pos_impl(layout),
+            (29, 0)
+        );
+
+        let mut prompt = prompt.with_hint("world");
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        let mut expected = TestBackend::new_with_layout(size, layout);
+
+        prompt.render(&mut layout.clone(), &mut backend).unwrap();
+
+        expected.write_styled(&"? ".light_green()).unwrap();
+        expected.set_fg(Color::DarkGrey).unwrap();
+        expected.write_all(b"[2/5] ").unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_styled(&"Hello".bold()).unwrap();
+        expected.write_all(b" ").unwrap();
+        expected.set_fg(Color::DarkGrey).unwrap();
+        expected.write_all(b"(world)").unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.write_all(b" ").unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(backend.get_cursor_pos().unwrap(), (27, 0));
+    }
+
+    #[test]
+    fn test_write_padding() {
+        let size = (20, 5).into();
+        let layout = Layout::new(5, size);
+
+        let padded = |align: AnswerAlign, width: Option<u16>, layout: Layout| {
+            let mut backend = TestBackend::new_with_layout(size, layout);
+            align.write_padding(width, layout, &mut backend).unwrap();
+            backend.get_cursor_pos().unwrap().0
+        };
+
+        // The answer ends one column before the edge
+        assert_eq!(padded(AnswerAlign::Right, Some(4), layout), 15);
+        assert_eq!(padded(AnswerAlign::Right, Some(14), layout), 5);
+        // Answers which do not fit and left aligned answers are not padded
+        assert_eq!(padded(AnswerAlign::Right, Some(15), layout), 5);
+        assert_eq!(padded(AnswerAlign::Right, None, layout), 5);
+        assert_eq!(padded(AnswerAlign::Left, Some(4), layout), 5);
+
+        let layout = Layout::new(2, size).with_offset(3, 0);
+        assert_eq!(padded(AnswerAlign::Right, Some(4), layout), 15);
+    }
 }
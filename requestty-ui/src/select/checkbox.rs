@@ -0,0 +1,316 @@
+use std::io;
+
+use super::{List, ReorderList, Select};
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    layout::Layout,
+    style::Color,
+    Widget,
+};
+
+/// A widget to check any number of items from a list.
+///
+/// The list is navigated in the same way as a [`Select`], and `Space` toggles whether the hovered
+/// item is checked. Each item is rendered after the pointer and marker written by
+/// [`write_check_marker`]. Items which are not [selectable] cannot be hovered, so they can never be
+/// checked.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{
+///     events::KeyCode,
+///     widgets::{Checkbox, SectionedList},
+///     Widget,
+/// };
+///
+/// let list = SectionedList::new(vec![
+///     ("Fruits", vec!["Apple", "Banana"]),
+///     ("Vegetables", vec!["Carrot"]),
+/// ]);
+///
+/// let mut checkbox = Checkbox::new(list).with_checked(&[4]);
+/// assert_eq!(checkbox.get_at(), 1);
+///
+/// checkbox.handle_key(KeyCode::Char(' ').into());
+/// assert_eq!(checkbox.checked_indices(), vec![1, 4]);
+/// ```
+///
+/// [selectable]: List::is_selectable
+pub struct Checkbox<L> {
+    select: Select<CheckList<L>>,
+}
+
+/// Writes the pointer and the check marker which come before an item of a list which can be
+/// checked, and sets the color of the text of the item.
+///
+/// The pointer is shown if the item is `hovered`. `checked` is whether the item is checked, or
+/// `None` if it cannot be checked. The marker is green if the item is checked and grey otherwise,
+/// and the text is cyan if the item is hovered. Items which cannot be checked have no marker, and
+/// grey text. The pointer and the marker take 4 columns.
+///
+/// This is how the items of a [`Checkbox`] are rendered.
+pub fn write_check_marker<B: Backend>(
+    hovered: bool,
+    checked: Option<bool>,
+    b: &mut B,
+) -> io::Result<()> {
+    let symbol_set = crate::symbols::current();
+
+    if hovered {
+        b.set_fg(Color::Cyan)?;
+        write!(b, "{} ", symbol_set.pointer)?;
+    } else {
+        b.write_all(b"  ")?;
+    }
+
+    match checked {
+        Some(checked) => {
+            b.set_fg(if checked {
+                Color::LightGreen
+            } else {
+                Color::DarkGrey
+            })?;
+            write!(b, "{} ", symbol_set.completed)?;
+            b.set_fg(if hovered { Color::Cyan } else { Color::Reset })
+        }
+        None => {
+            b.set_fg(Color::DarkGrey)?;
+            b.write_all(b"  ")
+        }
+    }
+}
+
+/// The list given to the [`Select`] of a [`Checkbox`], which renders the markers before the items.
+struct CheckList<L> {
+    list: L,
+    checked: Vec<bool>,
+}
+
+impl<L: std::fmt::Debug> std::fmt::Debug for Checkbox<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Checkbox")
+            .field("at", &self.select.at)
+            .field("checked", &self.select.list.checked)
+            .field("list", &self.select.list.list)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: List> Checkbox<L> {
+    /// Creates a new [`Checkbox`] with none of the items checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no selectable items, or if `list.page_size()` is less than 5.
+    pub fn new(list: L) -> Self {
+        let checked = vec![false; list.len()];
+
+        Self {
+            select: Select::new(CheckList { list, checked }),
+        }
+    }
+
+    /// Checks the items at the given indices, skipping those which are not selectable.
+    pub fn with_checked(mut self, indices: &[usize]) -> Self {
+        for &index in indices {
+            self.set_checked(index, true);
+        }
+        self
+    }
+
+    /// The index of the element that is currently being hovered.
+    pub fn get_at(&self) -> usize {
+        self.select.get_at()
+    }
+
+    /// Set the index of the element that is currently being hovered.
+    ///
+    /// The caller is responsible for making sure that it is a selectable element.
+    pub fn set_at(&mut self, at: usize) {
+        self.select.set_at(at);
+    }
+
+    /// Whether the item at `index` is checked.
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.select.list.checked[index]
+    }
+
+    /// Checks or unchecks the item at `index`. Items which are not selectable are left unchecked.
+    pub fn set_checked(&mut self, index: usize, checked: bool) {
+        let list = &mut self.select.list;
+        list.checked[index] = checked && list.list.is_selectable(index);
+    }
+
+    /// The indices of the checked items, in order.
+    pub fn checked_indices(&self) -> Vec<usize> {
+        self.select
+            .list
+            .checked
+            .iter()
+            .enumerate()
+            .filter(|(_, &checked)| checked)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The underlying list.
+    pub fn list(&self) -> &L {
+        &self.select.list.list
+    }
+
+    /// A mutable reference to the underlying list.
+    pub fn list_mut(&mut self) -> &mut L {
+        &mut self.select.list.list
+    }
+
+    /// Consumes the [`Checkbox`] returning the original list and the indices of the checked
+    /// items.
+    pub fn into_inner(self) -> (L, Vec<usize>) {
+        let checked = self.checked_indices();
+        (self.select.into_inner().list, checked)
+    }
+}
+
+impl<L: List> Widget for Checkbox<L> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if let KeyCode::Char(' ') = key.code {
+            let at = self.get_at();
+            let checked = !self.is_checked(at);
+            self.set_checked(at, checked);
+            return true;
+        }
+
+        self.select.handle_key(key)
+    }
+
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        self.select.render(layout, b)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        self.select.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        self.select.cursor_pos(layout)
+    }
+
+    fn wants_cursor(&self) -> bool {
+        self.select.wants_cursor()
+    }
+}
+
+impl<L: List> CheckList<L> {
+    /// Writes the pointer and the marker for the item at `index`, returning the layout for the rest
+    /// of the item.
+    fn render_marker<B: Backend>(
+        &self,
+        index: usize,
+        hovered: bool,
+        layout: Layout,
+        b: &mut B,
+    ) -> io::Result<Layout> {
+        let checked = if self.list.is_selectable(index) {
+            Some(self.checked[index])
+        } else {
+            None
+        };
+        write_check_marker(hovered, checked, b)?;
+
+        Ok(without_marker(layout))
+    }
+}
+
+/// The layout for an item, given `layout` is where its pointer starts.
+fn without_marker(mut layout: Layout) -> Layout {
+    layout.offset_x = (layout.offset_x + 4).min(layout.width);
+    layout
+}
+
+impl<L: List> List for CheckList<L> {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let layout = self.render_marker(index, hovered, layout, backend)?;
+        self.list.render_item(index, hovered, layout, backend)?;
+        backend.set_fg(Color::Reset)
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        self.list.is_selectable(index)
+    }
+
+    fn disabled_reason(&self, index: usize) -> Option<&str> {
+        self.list.disabled_reason(index)
+    }
+
+    fn page_size(&self) -> usize {
+        self.list.page_size()
+    }
+
+    fn should_loop(&self) -> bool {
+        self.list.should_loop()
+    }
+
+    fn height_at(&mut self, index: usize, layout: Layout) -> u16 {
+        self.list.height_at(index, without_marker(layout))
+    }
+
+    fn expanded_height_at(&mut self, index: usize, layout: Layout) -> u16 {
+        self.list.expanded_height_at(index, without_marker(layout))
+    }
+
+    fn render_expanded_item<B: Backend>(
+        &mut self,
+        index: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let layout = self.render_marker(index, true, layout, backend)?;
+        self.list.render_expanded_item(index, layout, backend)?;
+        backend.set_fg(Color::Reset)
+    }
+
+    fn render_matched_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let layout = self.render_marker(index, hovered, layout, backend)?;
+        self.list
+            .render_matched_item(index, hovered, matched, layout, backend)?;
+        backend.set_fg(Color::Reset)
+    }
+
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    fn help(&self, index: usize) -> Option<&str> {
+        self.list.help(index)
+    }
+
+    fn on_page_change(&mut self, page_start: usize, page_end: usize) {
+        self.list.on_page_change(page_start, page_end)
+    }
+
+    fn on_highlight_change(&mut self, index: usize) {
+        self.list.on_highlight_change(index)
+    }
+}
+
+impl<L: ReorderList> ReorderList for CheckList<L> {
+    fn swap(&mut self, a: usize, b: usize) {
+        self.list.swap(a, b);
+        self.checked.swap(a, b);
+    }
+}
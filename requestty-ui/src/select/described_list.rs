@@ -0,0 +1,137 @@
+use std::io;
+
+use super::{List, ReorderList};
+use crate::{
+    backend::Backend,
+    layout::Layout,
+    style::{Attributes, Color},
+    widgets::write_truncated,
+};
+
+/// A [`List`] of names with descriptions, rendered as two columns.
+///
+/// Each item takes a single row. The name is left-aligned in a column of `name_width` and the
+/// description fills the rest of the row. Both columns are cut short independently if they do not
+/// fit. The description is dimmed, and the name of the hovered item is coloured cyan.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{DescribedList, Select};
+///
+/// let list = DescribedList::new(
+///     vec![
+///         ("build", "Compile the current package"),
+///         ("test", "Run the tests"),
+///     ],
+///     10,
+/// );
+///
+/// let select = Select::new(list);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DescribedList<T> {
+    items: Vec<(T, T)>,
+    name_width: u16,
+    page_size: usize,
+    should_loop: bool,
+}
+
+impl<T: AsRef<str>> DescribedList<T> {
+    /// Creates a new `DescribedList` from `(name, description)` pairs.
+    ///
+    /// The names are rendered in a column `name_width` wide. By default, the page size is 15, and
+    /// the list loops.
+    pub fn new(items: Vec<(T, T)>, name_width: u16) -> Self {
+        Self {
+            items,
+            name_width,
+            page_size: 15,
+            should_loop: true,
+        }
+    }
+
+    /// Creates a new `DescribedList` with the given `page_size`.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Creates a new `DescribedList` with the given `should_loop`.
+    pub fn with_should_loop(mut self, should_loop: bool) -> Self {
+        self.should_loop = should_loop;
+        self
+    }
+
+    /// The `(name, description)` pairs of the list.
+    pub fn items(&self) -> &[(T, T)] {
+        &self.items
+    }
+
+    /// Consumes the list returning the `(name, description)` pairs.
+    pub fn into_items(self) -> Vec<(T, T)> {
+        self.items
+    }
+}
+
+impl<T: AsRef<str>> List for DescribedList<T> {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let (name, description) = &self.items[index];
+        let line_width = layout.line_width();
+        let name_width = self.name_width.min(line_width);
+
+        if hovered {
+            backend.set_fg(Color::Cyan)?;
+        }
+        let written = write_truncated(name.as_ref(), name_width as usize, backend)?;
+        if hovered {
+            backend.set_fg(Color::Reset)?;
+        }
+
+        // One column is left empty between the name and the description
+        let description_width = line_width.saturating_sub(name_width + 1);
+        if description_width == 0 {
+            return Ok(());
+        }
+
+        for _ in written..=(name_width as usize) {
+            backend.write_all(b" ")?;
+        }
+
+        backend.set_attributes(Attributes::DIM)?;
+        write_truncated(description.as_ref(), description_width as usize, backend)?;
+        backend.set_attributes(Attributes::empty())
+    }
+
+    fn is_selectable(&self, _: usize) -> bool {
+        true
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn should_loop(&self) -> bool {
+        self.should_loop
+    }
+
+    fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+        1
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
+impl<T: AsRef<str>> ReorderList for DescribedList<T> {
+    fn swap(&mut self, a: usize, b: usize) {
+        self.items.swap(a, b)
+    }
+}
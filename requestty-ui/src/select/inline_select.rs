@@ -0,0 +1,144 @@
+use std::{
+    io,
+    ops::{Index, IndexMut},
+};
+
+use super::{List, Select};
+use crate::{
+    backend::{Backend, InspectBackend},
+    events::{KeyCode, KeyEvent},
+    layout::{Layout, RenderRegion},
+};
+
+/// A widget to select a single item from a list, showing only the hovered item on the current
+/// line.
+///
+/// Instead of a vertical list, the hovered item is rendered right after whatever precedes it on
+/// the line, for example `? Color › Red`. `Left` and `Right` move to the previous and next
+/// selectable item, in the same way `Up` and `Down` do for [`Select`]. As a single item is shown,
+/// there is no pagination, and the [`page_size`] of the list is ignored.
+///
+/// The item is rendered with [`List::render_item`] as hovered, and is given a single line.
+///
+/// [`page_size`]: List::page_size
+pub struct InlineSelect<L> {
+    select: Select<L>,
+    width: u16,
+}
+
+impl<L: std::fmt::Debug> std::fmt::Debug for InlineSelect<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InlineSelect")
+            .field("at", &self.select.at)
+            .field("width", &self.width)
+            .field("list", &self.select.list)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: List> InlineSelect<L> {
+    /// Creates a new [`InlineSelect`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no selectable items, or if `list.page_size()` is less than 5.
+    pub fn new(list: L) -> Self {
+        Self {
+            select: Select::new(list),
+            width: 0,
+        }
+    }
+
+    /// The index of the element that is currently being hovered.
+    pub fn get_at(&self) -> usize {
+        self.select.get_at()
+    }
+
+    /// Set the index of the element that is currently being hovered.
+    ///
+    /// The caller is responsible for making sure that it is a selectable element.
+    pub fn set_at(&mut self, at: usize) {
+        self.select.at = at;
+    }
+
+    /// The underlying list.
+    pub fn list(&self) -> &L {
+        &self.select.list
+    }
+
+    /// A mutable reference to the underlying list.
+    pub fn list_mut(&mut self) -> &mut L {
+        &mut self.select.list
+    }
+
+    /// Consumes the [`InlineSelect`] returning the original list.
+    pub fn into_inner(self) -> L {
+        self.select.list
+    }
+}
+
+impl<L: Index<usize>> InlineSelect<L> {
+    /// Returns a reference to the currently hovered item.
+    pub fn selected(&self) -> &L::Output {
+        self.select.selected()
+    }
+}
+
+impl<L: IndexMut<usize>> InlineSelect<L> {
+    /// Returns a mutable reference to the currently hovered item.
+    pub fn selected_mut(&mut self) -> &mut L::Output {
+        self.select.selected_mut()
+    }
+}
+
+impl<L: List> crate::Widget for InlineSelect<L> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let at = match key.code {
+            KeyCode::Left => self.select.prev_selectable(),
+            KeyCode::Right => self.select.next_selectable(),
+            _ => return false,
+        };
+
+        if at == self.select.at {
+            return false;
+        }
+
+        self.select.at = at;
+        true
+    }
+
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        let mut item_layout = *layout;
+        item_layout.max_height = 1;
+        item_layout.render_region = RenderRegion::Top;
+
+        let mut width = 0;
+        self.select.list.render_item(
+            self.select.at,
+            true,
+            item_layout,
+            &mut InspectBackend::new(&mut *b, |text: &[u8]| {
+                width += textwrap::core::display_width(&String::from_utf8_lossy(text));
+                text.into()
+            }),
+        )?;
+
+        self.width = (width as u16).min(layout.line_width());
+        layout.line_offset += self.width;
+
+        let (x, y) = layout.offset_cursor((layout.line_offset, 0));
+        b.move_cursor_to(x, y)
+    }
+
+    /// The width of the item is only known once it is rendered, so the `line_offset` is moved by
+    /// the width of the previously rendered item.
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        layout.line_offset += self.width;
+        1
+    }
+
+    /// Returns the position right after the hovered item.
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        layout.offset_cursor((layout.line_offset + self.width, 0))
+    }
+}
@@ -1,17 +1,31 @@
 use std::{
+    cmp::Ordering,
     io,
     ops::{Index, IndexMut},
+    time::{Duration, Instant},
 };
 
 use crate::{
     backend::Backend,
-    events::{KeyEvent, Movement},
+    events::{KeyBindings, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, Movement},
     layout::{Layout, RenderRegion},
-    style::Stylize,
+    style::{Attributes, Stylize},
+    widgets::{wrap_lines, wrapped_height, WrapMode},
 };
 
+pub use checkbox::{write_check_marker, Checkbox};
+pub use described_list::DescribedList;
+pub use inline_select::InlineSelect;
+pub use sectioned_list::SectionedList;
+pub use with_preview::SelectWithPreview;
+
+mod checkbox;
+mod described_list;
+mod inline_select;
+mod sectioned_list;
 #[cfg(test)]
 mod tests;
+mod with_preview;
 
 /// A trait to represent a renderable list.
 ///
@@ -25,7 +39,9 @@ pub trait List {
     ///
     /// [`layout.max_height`] may be less than the height given by [`height_at`].
     /// [`layout.render_region`] can be used to determine which part of the element you want to
-    /// render.
+    /// render. This happens when the element is at the start or end of a page, and is only
+    /// partially visible. [`Layout::visible_lines`] gives the lines of the element which should be
+    /// rendered. Anything that marks the element as hovered should be on the first of these lines.
     ///
     /// [`height_at`]: List::height_at
     /// [`layout.max_height`]: Layout::max_height
@@ -42,6 +58,17 @@ pub trait List {
     /// skipped during navigation.
     fn is_selectable(&self, index: usize) -> bool;
 
+    /// Why the element at a particular index is disabled, if it is.
+    ///
+    /// Elements which are not [selectable](List::is_selectable) because they are unavailable,
+    /// rather than because they are separators or headings, can use this to explain why. It is
+    /// up to [`render_item`](List::render_item) to show it, for example in dark grey after the
+    /// element. By default, no element has a reason.
+    fn disabled_reason(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
     /// The maximum height that can be taken by the list.
     ///
     /// If the total height exceeds the page size, the list will be scrollable.
@@ -55,6 +82,54 @@ pub trait List {
     /// The height of the element at an index will take to render
     fn height_at(&mut self, index: usize, layout: Layout) -> u16;
 
+    /// The height of the element at an index when it is hovered and expanded.
+    ///
+    /// This is only used for the hovered element when [`Select::with_expand_hovered`] is set. By
+    /// default, it is the same as [`height_at`].
+    ///
+    /// [`height_at`]: List::height_at
+    fn expanded_height_at(&mut self, index: usize, layout: Layout) -> u16 {
+        self.height_at(index, layout)
+    }
+
+    /// Render the expanded form of the hovered element.
+    ///
+    /// This is called instead of [`render_item`] for the hovered element when
+    /// [`Select::with_expand_hovered`] is set, and it can use up to [`expanded_height_at`] lines.
+    /// By default, it renders the element in the same way as [`render_item`] does.
+    ///
+    /// [`render_item`]: List::render_item
+    /// [`expanded_height_at`]: List::expanded_height_at
+    fn render_expanded_item<B: Backend>(
+        &mut self,
+        index: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        self.render_item(index, true, layout, backend)
+    }
+
+    /// Render an element whose text starts with the prefix typed with
+    /// [`Select::with_type_ahead`].
+    ///
+    /// `matched` is the number of characters at the start of the text of the element which match
+    /// the prefix, so that they can be marked, for example by underlining them. This is called
+    /// instead of [`render_item`] for every element which matches while a prefix is being typed.
+    /// By default, it renders the element in the same way as [`render_item`] does.
+    ///
+    /// [`render_item`]: List::render_item
+    fn render_matched_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let _ = matched;
+        self.render_item(index, hovered, layout, backend)
+    }
+
     /// The length of the list
     fn len(&self) -> usize;
 
@@ -62,6 +137,197 @@ pub trait List {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// A line of help for the element at an index, shown dimmed below the list while it is
+    /// hovered.
+    ///
+    /// It is rendered after the pagination message if there is one. The text is wrapped, and the
+    /// lines it takes are counted in the [`height`](Widget::height) of the [`Select`]. By default,
+    /// no element has help.
+    fn help(&self, index: usize) -> Option<&str> {
+        let _ = index;
+        None
+    }
+
+    /// Called by the [`Select`] whenever the visible page changes.
+    ///
+    /// It is given the positions of the first and last visible elements, i.e. `page_start` and
+    /// `page_end` (see [`Select::page_bounds`]). Note that if the list loops, `page_end` can be less
+    /// than `page_start`. It is called once the page is first computed, and then only when the
+    /// bounds actually change, not on every render. By default, it does nothing.
+    fn on_page_change(&mut self, page_start: usize, page_end: usize) {
+        let _ = (page_start, page_end);
+    }
+
+    /// Called by the [`Select`] whenever the hovered element changes.
+    ///
+    /// It is given the index of the newly hovered element, once for every key press or call to
+    /// [`Select::set_at`] which actually moves to a different element. Keys which do not move the
+    /// hovered element, for example `Up` on the first element when the list does not loop, do not
+    /// call it. By default, it does nothing.
+    fn on_highlight_change(&mut self, index: usize) {
+        let _ = index;
+    }
+}
+
+/// A [`List`] whose elements can be reordered in place.
+///
+/// This is needed by [`Select::apply_order`] to move the elements of the list to the order they
+/// are shown in.
+pub trait ReorderList: List {
+    /// Swaps the elements at indices `a` and `b`.
+    fn swap(&mut self, a: usize, b: usize);
+}
+
+/// The indices of the elements of the list which are selectable, in order.
+///
+/// The list is checked lazily as the iterator is advanced, and it can be iterated from either
+/// end.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{selectable_indices, SectionedList};
+///
+/// let list = SectionedList::new(vec![("Header", vec!["a", "b"]), ("Header 2", vec!["c"])]);
+///
+/// assert_eq!(selectable_indices(&list).collect::<Vec<_>>(), vec![1, 2, 4]);
+/// ```
+pub fn selectable_indices<L: List + ?Sized>(
+    list: &L,
+) -> impl DoubleEndedIterator<Item = usize> + '_ {
+    (0..list.len()).filter(move |&i| list.is_selectable(i))
+}
+
+/// The index in the list of the element shown at `pos`, given the order of a [`Select`]. This is
+/// a free function so it can be used while the heights are borrowed.
+fn list_index(order: &Option<Vec<usize>>, pos: usize) -> usize {
+    match order {
+        Some(order) => order.get(pos).copied().unwrap_or(pos),
+        None => pos,
+    }
+}
+
+impl<L: List> List for &mut L {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        (**self).render_item(index, hovered, layout, backend)
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        (**self).is_selectable(index)
+    }
+
+    fn disabled_reason(&self, index: usize) -> Option<&str> {
+        (**self).disabled_reason(index)
+    }
+
+    fn page_size(&self) -> usize {
+        (**self).page_size()
+    }
+
+    fn should_loop(&self) -> bool {
+        (**self).should_loop()
+    }
+
+    fn height_at(&mut self, index: usize, layout: Layout) -> u16 {
+        (**self).height_at(index, layout)
+    }
+
+    fn expanded_height_at(&mut self, index: usize, layout: Layout) -> u16 {
+        (**self).expanded_height_at(index, layout)
+    }
+
+    fn render_expanded_item<B: Backend>(
+        &mut self,
+        index: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        (**self).render_expanded_item(index, layout, backend)
+    }
+
+    fn render_matched_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        (**self).render_matched_item(index, hovered, matched, layout, backend)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn is_empty(&self) -> bool {
+        (**self).is_empty()
+    }
+
+    fn help(&self, index: usize) -> Option<&str> {
+        (**self).help(index)
+    }
+
+    fn on_page_change(&mut self, page_start: usize, page_end: usize) {
+        (**self).on_page_change(page_start, page_end)
+    }
+
+    fn on_highlight_change(&mut self, index: usize) {
+        (**self).on_highlight_change(index)
+    }
+}
+
+impl<L: ReorderList> ReorderList for &mut L {
+    fn swap(&mut self, a: usize, b: usize) {
+        (**self).swap(a, b)
+    }
+}
+
+/// Whether a [`Select`] wraps around when moving past the first or last element.
+///
+/// See [`Select::with_should_loop`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShouldLoop {
+    /// Always wrap around.
+    Always,
+    /// Never wrap around.
+    Never,
+    /// Only wrap around if the list does not fit on a single page.
+    ///
+    /// If every element is visible, moving past either end stops there instead.
+    Auto,
+}
+
+impl From<bool> for ShouldLoop {
+    fn from(should_loop: bool) -> Self {
+        if should_loop {
+            ShouldLoop::Always
+        } else {
+            ShouldLoop::Never
+        }
+    }
+}
+
+/// How a [`Select`] moves past elements which are not selectable.
+///
+/// See [`Select::with_skip_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkipPolicy {
+    /// Skip over elements which are not selectable, so they are never hovered.
+    Skip,
+    /// Stop at every element, including those which are not selectable.
+    ///
+    /// This is useful when hovering something like a header should do something, for example
+    /// expand the section under it. Since a non-selectable element can be hovered, it is up to the
+    /// prompt to reject it when the answer is submitted.
+    Land,
 }
 
 #[derive(Debug, Clone)]
@@ -70,6 +336,93 @@ struct Heights {
     prev_layout: Layout,
 }
 
+/// The state of a filter which is applied a few elements at a time. See [`Select::start_filter`].
+#[derive(Debug, Clone)]
+struct Filter {
+    /// The indices of all the elements in the order they are shown when not filtered
+    base: Vec<usize>,
+    /// The position in `base` of each element of the list
+    base_positions: Vec<usize>,
+    /// Whether each element of the list matches, if it has been checked yet
+    matches: Vec<Option<bool>>,
+    /// The position in `base` of the next element to check
+    next: usize,
+}
+
+impl Filter {
+    fn set_base(&mut self, base: Vec<usize>) {
+        self.base_positions = vec![0; base.len()];
+        for (pos, &index) in base.iter().enumerate() {
+            self.base_positions[index] = pos;
+        }
+        self.base = base;
+    }
+
+    fn is_done(&self) -> bool {
+        self.next >= self.base.len()
+    }
+}
+
+/// A query typed into the [`Select`] itself. See [`Select::with_query_filter`].
+#[derive(Debug, Clone)]
+struct Query {
+    /// The text each element is matched against
+    texts: Vec<String>,
+    matcher: fn(&str, &str) -> bool,
+    query: String,
+}
+
+/// The message shown after the page while paginating, unless it is changed with
+/// [`Select::with_pagination_hint`].
+const PAGINATION_HINT: &str = "(Move up and down to reveal more choices)";
+
+/// How many elements are checked against a filter by each call to [`Select::tick`].
+const FILTER_BUDGET: usize = 1000;
+
+/// How long after the last key the prefix typed with [`Select::with_type_ahead`] is started
+/// again.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// The prefix typed to jump to an element. See [`Select::with_type_ahead`].
+#[derive(Debug, Clone)]
+struct TypeAhead {
+    /// The lowercase text each element is matched against
+    texts: Vec<String>,
+    prefix: String,
+    last_key: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// The number of characters of the element at `index` which match the prefix, if it does.
+    fn matched(&self, index: usize) -> Option<usize> {
+        if !self.prefix.is_empty() && self.texts[index].starts_with(&self.prefix) {
+            Some(self.prefix.chars().count())
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether all the characters of `query` occur in `text` in the same order, ignoring case.
+///
+/// This is the matcher used by [`Select::with_query_filter`] by default.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::fuzzy_matches;
+///
+/// assert!(fuzzy_matches("rdo", "Red Orange"));
+/// assert!(!fuzzy_matches("ord", "Red Orange"));
+/// ```
+pub fn fuzzy_matches(query: &str, text: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| text.any(|c| c == q))
+}
+
 /// A widget to select a single item from a list.
 ///
 /// The list must implement the [`List`] trait.
@@ -84,6 +437,22 @@ pub struct Select<L> {
     page_end_height: u16,
     height: u16,
     heights: Option<Heights>,
+    scrolloff: u16,
+    expand_hovered: bool,
+    max_visible: Option<u16>,
+    item_spacing: u16,
+    scrollbar: bool,
+    pagination_hint: Option<String>,
+    ignored_movements: Vec<Movement>,
+    bindings: KeyBindings,
+    query: Option<Query>,
+    type_ahead: Option<TypeAhead>,
+    // Overrides `List::should_loop` if set
+    should_loop: Option<ShouldLoop>,
+    skip_policy: SkipPolicy,
+    // The index in the list of the element at each position, if the list is sorted or filtered
+    order: Option<Vec<usize>>,
+    filter: Option<Filter>,
     /// The underlying list
     pub list: L,
 }
@@ -95,13 +464,11 @@ impl<L: List> Select<L> {
     ///
     /// Panics if there are no selectable items, or if `list.page_size()` is less than 5.
     pub fn new(list: L) -> Self {
-        let first_selectable = (0..list.len())
-            .position(|i| list.is_selectable(i))
+        let first_selectable = selectable_indices(&list)
+            .next()
             .expect("there must be at least one selectable item");
 
-        let last_selectable = (0..list.len())
-            .rposition(|i| list.is_selectable(i))
-            .unwrap();
+        let last_selectable = selectable_indices(&list).next_back().unwrap();
 
         assert!(list.page_size() >= 5, "page size can be a minimum of 5");
 
@@ -112,6 +479,20 @@ impl<L: List> Select<L> {
             page_start_height: u16::MAX,
             page_end_height: u16::MAX,
             heights: None,
+            scrolloff: 1,
+            expand_hovered: false,
+            max_visible: None,
+            item_spacing: 0,
+            scrollbar: false,
+            pagination_hint: Some(PAGINATION_HINT.to_owned()),
+            ignored_movements: Vec::new(),
+            bindings: KeyBindings::new(),
+            query: None,
+            type_ahead: None,
+            should_loop: None,
+            skip_policy: SkipPolicy::Skip,
+            order: None,
+            filter: None,
             at: first_selectable,
             page_start: 0,
             page_end: usize::MAX,
@@ -119,31 +500,559 @@ impl<L: List> Select<L> {
         }
     }
 
+    /// The number of elements to keep visible between the hovered element and the edges of the
+    /// page when the list is scrolled.
+    ///
+    /// This is similar to the `scrolloff` option in vim. The element at the edge of the page in the
+    /// direction opposite to the movement only has its first (or last) line shown, so the cursor
+    /// does not jump around when elements have different heights. By default, the `scrolloff` is 1.
+    pub fn with_scrolloff(mut self, scrolloff: u16) -> Self {
+        self.scrolloff = scrolloff;
+        self
+    }
+
+    /// Whether the hovered element should be shown in its expanded form.
+    ///
+    /// If set, the hovered element is rendered with [`List::render_expanded_item`] and takes
+    /// [`List::expanded_height_at`] lines, while all other elements are rendered as usual. As the
+    /// height of the elements changes when the hovered element does, the page is recomputed on
+    /// every movement. By default, the hovered element is not expanded.
+    pub fn with_expand_hovered(mut self, expand_hovered: bool) -> Self {
+        self.expand_hovered = expand_hovered;
+        self
+    }
+
+    /// The maximum number of lines the list can take, independent of [`List::page_size`].
+    ///
+    /// The list is paginated using the smallest of the `page_size`, `max_visible` and the height
+    /// available to it in the layout. This can be used to keep the list short even if the
+    /// `page_size` of the list is large. By default, there is no maximum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_visible` is less than 5.
+    pub fn with_max_visible(mut self, max_visible: u16) -> Self {
+        assert!(max_visible >= 5, "max visible can be a minimum of 5");
+        self.max_visible = Some(max_visible);
+        self
+    }
+
+    /// The number of blank lines between consecutive elements.
+    ///
+    /// The blank lines after an element are counted as part of its height when paginating, except
+    /// for the last element which has nothing after it. By default, there is no spacing.
+    pub fn with_item_spacing(mut self, item_spacing: u16) -> Self {
+        self.item_spacing = item_spacing;
+        self
+    }
+
+    /// Whether to show a scrollbar in the last column while paginating.
+    ///
+    /// The thumb of the scrollbar shows which part of the list is visible. When it is enabled, the
+    /// last column is never given to the elements, even if the list fits on a single page. By
+    /// default, there is no scrollbar.
+    pub fn with_scrollbar(mut self, scrollbar: bool) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// The message shown after the page while paginating, or `None` to not show a message.
+    ///
+    /// The message is expected to fit on a single line. When there is no message, the line is
+    /// used for the elements instead. By default, it is `"(Move up and down to reveal more
+    /// choices)"`.
+    pub fn with_pagination_hint(mut self, pagination_hint: Option<String>) -> Self {
+        self.pagination_hint = pagination_hint;
+        self
+    }
+
+    /// Movements which should not be handled by the list.
+    ///
+    /// Keys for these movements are treated as unhandled, and `handle_key` returns `false` for
+    /// them. This lets a parent widget use the keys for something else, for example handling
+    /// `PageUp` and `PageDown` itself. By default, no movements are ignored.
+    pub fn ignore_movements(mut self, movements: &[Movement]) -> Self {
+        self.ignored_movements = movements.to_vec();
+        self
+    }
+
+    /// The keys used to move through the list. By default, these are [`KeyBindings::new`].
+    pub fn with_bindings(mut self, bindings: KeyBindings) -> Self {
+        self.bindings = bindings;
+        self
+    }
+
+    /// The keys used to move through the list.
+    pub fn bindings(&self) -> &KeyBindings {
+        &self.bindings
+    }
+
+    /// Lets the list be filtered by typing.
+    ///
+    /// `texts` are the texts the elements are matched against, one for each element of the list.
+    /// Printable characters are added to a query instead of being used as movement keys, so keys
+    /// like `j` and `k` are typed into it. `Backspace` removes the last character of the query,
+    /// and `Esc` clears it. While the query is not empty, only the elements whose text matches it
+    /// are shown, otherwise every element is shown. By default, elements match if the query is a
+    /// subsequence of their text (see [`fuzzy_matches`]), which can be changed with
+    /// [`with_query_matcher`].
+    ///
+    /// The query is not rendered by the [`Select`], so it should be shown by the widget which
+    /// contains it. It can be got with [`query`].
+    ///
+    /// [`with_query_matcher`]: Select::with_query_matcher
+    /// [`query`]: Select::query
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not a text for each element.
+    pub fn with_query_filter(mut self, texts: Vec<String>) -> Self {
+        assert_eq!(
+            texts.len(),
+            self.list.len(),
+            "there must be a text for each element"
+        );
+
+        self.query = Some(Query {
+            texts,
+            matcher: fuzzy_matches,
+            query: String::new(),
+        });
+        self
+    }
+
+    /// The function used to check whether the text of an element matches the query typed with
+    /// [`with_query_filter`]. It is given the query and the text, in that order.
+    ///
+    /// This has no effect unless [`with_query_filter`] is set first.
+    ///
+    /// [`with_query_filter`]: Select::with_query_filter
+    pub fn with_query_matcher(mut self, matcher: fn(&str, &str) -> bool) -> Self {
+        if let Some(ref mut query) = self.query {
+            query.matcher = matcher;
+        }
+        self
+    }
+
+    /// The query typed to filter the list, or an empty string if it cannot be filtered by typing.
+    /// See [`with_query_filter`].
+    ///
+    /// [`with_query_filter`]: Select::with_query_filter
+    pub fn query(&self) -> &str {
+        match self.query {
+            Some(ref query) => &query.query,
+            None => "",
+        }
+    }
+
+    /// Lets the hovered element be changed by typing the start of its text.
+    ///
+    /// `texts` are the texts the elements are matched against, one for each element of the list.
+    /// Printable characters are added to a prefix, and the hovered element moves to the next
+    /// selectable element whose text starts with it, ignoring case. The prefix is started again
+    /// if no key is typed for a second, so typing the same letter again moves on to the next
+    /// element which starts with it. Unlike [`with_query_filter`], every element is still shown.
+    /// Elements which match are rendered with [`List::render_matched_item`].
+    ///
+    /// As letters are typed into the prefix, they cannot also be movement keys, so this is best
+    /// used with [`KeyBindings::arrows`] (see [`with_bindings`]). If the list can also be filtered
+    /// by typing, the query takes the keys instead.
+    ///
+    /// [`with_query_filter`]: Select::with_query_filter
+    /// [`with_bindings`]: Select::with_bindings
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not a text for each element.
+    pub fn with_type_ahead(mut self, texts: Vec<String>) -> Self {
+        assert_eq!(
+            texts.len(),
+            self.list.len(),
+            "there must be a text for each element"
+        );
+
+        self.type_ahead = Some(TypeAhead {
+            texts: texts.iter().map(|text| text.to_lowercase()).collect(),
+            prefix: String::new(),
+            last_key: None,
+        });
+        self
+    }
+
+    /// Adds the character typed with `key` to the type-ahead prefix and jumps to the next element
+    /// which matches it, returning whether the key was handled.
+    fn handle_type_ahead_key(&mut self, key: KeyEvent) -> bool {
+        let type_ahead = match self.type_ahead {
+            Some(ref mut type_ahead) => type_ahead,
+            None => return false,
+        };
+
+        let c = match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                c
+            }
+            _ => return false,
+        };
+
+        let now = Instant::now();
+        if matches!(type_ahead.last_key, Some(last_key) if now - last_key > TYPE_AHEAD_TIMEOUT) {
+            type_ahead.prefix.clear();
+        }
+        type_ahead.last_key = Some(now);
+        // The current element is kept while it still matches a longer prefix, but a new prefix
+        // moves on to the next match
+        let start = if type_ahead.prefix.is_empty() {
+            self.at + 1
+        } else {
+            self.at
+        };
+        type_ahead.prefix.extend(c.to_lowercase());
+
+        let len = self.len();
+        let found = (0..len)
+            .map(|i| (start + i) % len)
+            .map(|pos| list_index(&self.order, pos))
+            .find(|&index| {
+                self.list.is_selectable(index)
+                    && matches!(self.type_ahead, Some(ref t) if t.matched(index).is_some())
+            });
+
+        match found {
+            Some(index) => {
+                self.set_at(index);
+                true
+            }
+            None => {
+                // The character is dropped so that a typo does not stop the rest of the prefix
+                // from matching
+                if let Some(ref mut type_ahead) = self.type_ahead {
+                    type_ahead.prefix.pop();
+                }
+                false
+            }
+        }
+    }
+
+    /// Edits the query with `key` if the list can be filtered by typing, returning whether the
+    /// key was handled.
+    fn handle_query_key(&mut self, key: KeyEvent) -> bool {
+        let query = match self.query {
+            Some(ref mut query) => query,
+            None => return false,
+        };
+
+        match key.code {
+            KeyCode::Char(c)
+                if !key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                query.query.push(c)
+            }
+            KeyCode::Backspace if !query.query.is_empty() => {
+                query.query.pop();
+            }
+            KeyCode::Esc if !query.query.is_empty() => query.query.clear(),
+            _ => return false,
+        }
+
+        if query.query.is_empty() {
+            self.clear_filter();
+            return true;
+        }
+
+        self.start_filter();
+        // Short lists are filtered at once, and the rest is left to `tick`
+        self.tick();
+
+        true
+    }
+
+    /// Whether to wrap around when moving past the first or last element.
+    ///
+    /// This overrides [`List::should_loop`], and can be used to only loop when the list is
+    /// paginated with [`ShouldLoop::Auto`]. By default, [`List::should_loop`] is used.
+    pub fn with_should_loop(mut self, should_loop: ShouldLoop) -> Self {
+        self.should_loop = Some(should_loop);
+        self
+    }
+
+    /// Whether moving skips over the elements which are not selectable, or stops at them too.
+    ///
+    /// With [`SkipPolicy::Land`], the first and last elements are the bounds of the movement,
+    /// even if they are not selectable. The element hovered at first is still the first
+    /// selectable one. By default, [`SkipPolicy::Skip`] is used.
+    pub fn with_skip_policy(mut self, skip_policy: SkipPolicy) -> Self {
+        self.skip_policy = skip_policy;
+        self.reorder(self.hovered());
+        self
+    }
+
+    /// Sorts the elements in the order they are shown, without changing the underlying list.
+    ///
+    /// `compare` is given the indices of two elements of the list, and the order is computed once
+    /// when this is called. [`get_at`], [`set_at`] and [`selected`] still use the indices of the
+    /// underlying list, while the bounds given to [`on_page_change`] are positions in the sorted
+    /// order.
+    ///
+    /// This can be called again at any point to re-sort the list, for example to reverse the order
+    /// on a key press. The hovered element stays the same, and the page is recomputed so that it
+    /// is visible.
+    ///
+    /// [`get_at`]: Select::get_at
+    /// [`set_at`]: Select::set_at
+    /// [`selected`]: Select::selected
+    /// [`on_page_change`]: Select::on_page_change
+    pub fn sort_by<F: FnMut(usize, usize) -> Ordering>(&mut self, mut compare: F) {
+        let at = self.hovered();
+
+        let mut order: Vec<usize> = (0..self.list.len()).collect();
+        order.sort_by(|&a, &b| compare(a, b));
+
+        match self.filter {
+            Some(ref mut filter) => {
+                // The elements which are already known to match are shown in the new order, and
+                // the rest are checked again in the new order
+                self.order = Some(
+                    order
+                        .iter()
+                        .copied()
+                        .filter(|&index| filter.matches[index] == Some(true))
+                        .collect(),
+                );
+                if !filter.is_done() {
+                    filter.next = 0;
+                }
+                filter.set_base(order);
+            }
+            None => self.order = Some(order),
+        }
+
+        self.reorder(at);
+    }
+
+    /// Starts filtering the list, so that only the elements which match a predicate are shown.
+    ///
+    /// As it may be slow to check every element of a large list, the elements are not checked
+    /// when this is called. Instead they are checked a few at a time with [`filter_step`], which
+    /// is given the predicate and can be called in between handling keys and rendering so that
+    /// the list stays responsive. The elements which have been found to match so far are shown,
+    /// followed by a `Filtering…` message until every element has been checked.
+    ///
+    /// Starting a new filter replaces the previous one, and [`clear_filter`] shows all the
+    /// elements again. The hovered element stays the same if it matches, otherwise the first
+    /// selectable element which matches is hovered. If nothing matches, nothing is shown and
+    /// [`get_at`] panics until the filter is changed.
+    ///
+    /// [`filter_step`]: Select::filter_step
+    /// [`clear_filter`]: Select::clear_filter
+    /// [`get_at`]: Select::get_at
+    pub fn start_filter(&mut self) {
+        let at = self.hovered();
+
+        let base = match self.filter.take() {
+            Some(filter) => filter.base,
+            None => self
+                .order
+                .take()
+                .unwrap_or_else(|| (0..self.list.len()).collect()),
+        };
+
+        let mut filter = Filter {
+            base: Vec::new(),
+            base_positions: Vec::new(),
+            matches: vec![None; self.list.len()],
+            next: 0,
+        };
+        filter.set_base(base);
+
+        self.filter = Some(filter);
+        self.order = Some(Vec::new());
+        self.reorder(at);
+    }
+
+    /// Checks up to `budget` more elements against the filter started with [`start_filter`], and
+    /// shows the ones which match.
+    ///
+    /// `predicate` is given the index of an element of the list, and returns whether it should be
+    /// shown. It should give the same answers until the filter is started again, as each element
+    /// is only checked once. The predicate is not kept by the [`Select`], so that it can borrow
+    /// whatever it needs.
+    ///
+    /// Returns whether there are elements which still have to be checked. If there is no filter,
+    /// nothing is done and `false` is returned.
+    ///
+    /// [`start_filter`]: Select::start_filter
+    pub fn filter_step<F: FnMut(usize) -> bool>(
+        &mut self,
+        budget: usize,
+        mut predicate: F,
+    ) -> bool {
+        let at = self.hovered();
+
+        let filter = match self.filter {
+            Some(ref mut filter) => filter,
+            None => return false,
+        };
+        let order = self.order.get_or_insert_with(Vec::new);
+
+        let end = filter.base.len().min(filter.next.saturating_add(budget));
+        let mut changed = false;
+
+        for pos in filter.next..end {
+            let index = filter.base[pos];
+            if filter.matches[index].is_some() {
+                continue;
+            }
+
+            let matches = predicate(index);
+            filter.matches[index] = Some(matches);
+
+            if matches {
+                let base_positions = &filter.base_positions;
+                let insert_at = order.partition_point(|&i| base_positions[i] < pos);
+                order.insert(insert_at, index);
+                changed = true;
+            }
+        }
+
+        filter.next = end;
+        let done = filter.is_done();
+
+        if changed {
+            self.reorder(at);
+        }
+
+        !done
+    }
+
+    /// Removes the filter started with [`start_filter`], showing all the elements again.
+    ///
+    /// [`start_filter`]: Select::start_filter
+    pub fn clear_filter(&mut self) {
+        let filter = match self.filter.take() {
+            Some(filter) => filter,
+            None => return,
+        };
+
+        let at = self.hovered();
+
+        let is_sorted = filter
+            .base
+            .iter()
+            .enumerate()
+            .any(|(pos, &index)| pos != index);
+        self.order = if is_sorted { Some(filter.base) } else { None };
+        self.reorder(at);
+    }
+
+    /// Checks the next few elements against the query typed with [`with_query_filter`], if they
+    /// have not all been checked yet. A filter started with [`start_filter`] is not checked, as
+    /// its predicate is only given to [`filter_step`].
+    ///
+    /// Returns whether any elements were checked, in which case the [`Select`] should be rendered
+    /// again. This is meant to be called from [`Prompt::tick`], which calls it until it returns
+    /// `false` while no keys are waiting.
+    ///
+    /// [`with_query_filter`]: Select::with_query_filter
+    /// [`start_filter`]: Select::start_filter
+    /// [`filter_step`]: Select::filter_step
+    /// [`Prompt::tick`]: crate::Prompt::tick
+    pub fn tick(&mut self) -> bool {
+        if !self.is_filtering() {
+            return false;
+        }
+
+        let query = match self.query.take() {
+            Some(query) => query,
+            None => return false,
+        };
+        let checked = !query.query.is_empty();
+        if checked {
+            self.filter_step(FILTER_BUDGET, |index| {
+                (query.matcher)(&query.query, &query.texts[index])
+            });
+        }
+        self.query = Some(query);
+
+        checked
+    }
+
+    /// Whether there is a filter which has not been checked against every element yet.
+    pub fn is_filtering(&self) -> bool {
+        matches!(self.filter, Some(ref filter) if !filter.is_done())
+    }
+
+    /// The positions of the first and last visible elements, i.e. `page_start` and `page_end`.
+    ///
+    /// These are positions in the order the elements are shown in, which differ from their indices
+    /// in the list if it is [sorted](Select::sort_by) or filtered. If the list loops, `page_end`
+    /// can be less than `page_start`. The bounds are only known once the list has been rendered,
+    /// and before that `page_end` is `usize::MAX`.
+    pub fn page_bounds(&self) -> (usize, usize) {
+        (self.page_start, self.page_end)
+    }
+
+    /// Whether the list is too tall to be shown at once, so only a page of it is visible.
+    ///
+    /// This is only accurate once [`height`](Widget::height) or [`render`](Widget::render) has
+    /// been called, since the heights of the elements are not known before that.
+    pub fn is_paginating(&self) -> bool {
+        self.height > self.page_size()
+    }
+
+    /// Whether no elements are shown. This can only happen when a filter hides every element.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// The index of the element that is currently being hovered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a filter hides every element, since nothing is hovered then. This can be
+    /// checked with [`is_empty`](Select::is_empty).
     pub fn get_at(&self) -> usize {
-        self.at
+        assert!(
+            self.filter.is_none() || !self.is_empty(),
+            "no element is hovered when the filter hides every element"
+        );
+        self.list_index(self.at)
     }
 
     /// Set the index of the element that is currently being hovered.
     ///
     /// `at` can be any number (even beyond `list.len()`), but the caller is responsible for making
-    /// sure that it is a selectable element.
+    /// sure that it is a selectable element, unless the skip policy is [`SkipPolicy::Land`].
     pub fn set_at(&mut self, at: usize) {
-        let dir = if self.at >= self.list.len() || self.at < at {
+        let at = self.position_of(at);
+        let dir = if self.at >= self.len() || self.at < at {
             Movement::Down
         } else {
             Movement::Up
         };
 
+        let prev_at = self.at;
         self.at = at;
 
+        let page = self.page_bounds();
+
+        self.update_expanded_heights(prev_at);
+
         if self.is_paginating() {
-            if at >= self.list.len() {
+            if at >= self.len() {
                 self.init_page();
             } else if self.heights.is_some() {
                 self.maybe_adjust_page(dir);
             }
         }
+
+        self.report_page_change(page);
+        self.report_highlight_change(prev_at);
     }
 
     /// Consumes the [`Select`] returning the original list.
@@ -151,9 +1060,95 @@ impl<L: List> Select<L> {
         self.list
     }
 
+    fn list_index(&self, pos: usize) -> usize {
+        list_index(&self.order, pos)
+    }
+
+    /// The number of elements which are shown.
+    fn len(&self) -> usize {
+        match self.filter {
+            Some(_) => self.order.as_ref().map_or(0, Vec::len),
+            None => self.list.len(),
+        }
+    }
+
+    /// The index of the hovered element, or `None` if nothing is shown.
+    fn hovered(&self) -> Option<usize> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.get_at())
+        }
+    }
+
+    /// Recomputes everything that depends on the positions of the elements, once the elements
+    /// which are shown or their order has changed. The element at index `hovered` stays hovered
+    /// if it is still shown.
+    fn reorder(&mut self, hovered: Option<usize>) {
+        let len = self.len();
+        let first = (0..len).find(|&i| self.can_hover(i));
+        let last = (0..len).rfind(|&i| self.can_hover(i));
+
+        match first.zip(last) {
+            Some((first, last)) => {
+                self.first_selectable = first;
+                self.last_selectable = last;
+            }
+            None => {
+                // Only a filter can hide every selectable element
+                assert!(
+                    self.filter.is_some(),
+                    "there must be at least one selectable item"
+                );
+                self.first_selectable = 0;
+                self.last_selectable = 0;
+            }
+        }
+
+        self.at = hovered
+            .and_then(|index| (0..len).find(|&i| self.list_index(i) == index))
+            .unwrap_or(self.first_selectable);
+
+        // The heights are stored by position, so they have to be recomputed in the new order
+        if let Some(heights) = self.heights.take() {
+            let page = self.page_bounds();
+
+            self.maybe_update_heights(heights.prev_layout);
+
+            if len == 0 {
+                // The page is computed again once something is shown
+                self.page_start = 0;
+                self.page_end = usize::MAX;
+                return;
+            }
+
+            if self.page_end != usize::MAX {
+                self.init_page();
+                if self.is_paginating() {
+                    self.maybe_adjust_page(Movement::Down);
+                }
+            }
+
+            self.report_page_change(page);
+        }
+    }
+
+    /// The position an element of the list is shown at, the inverse of `list_index`
+    fn position_of(&self, index: usize) -> usize {
+        match self.order {
+            Some(ref order) => order.iter().position(|&i| i == index).unwrap_or(index),
+            None => index,
+        }
+    }
+
+    /// Whether the element at `pos` can be hovered by moving to it, as per the skip policy.
+    fn can_hover(&self, pos: usize) -> bool {
+        self.skip_policy == SkipPolicy::Land || self.list.is_selectable(self.list_index(pos))
+    }
+
     fn next_selectable(&self) -> usize {
         if self.at >= self.last_selectable {
-            return if self.list.should_loop() {
+            return if self.should_loop() {
                 self.first_selectable
             } else {
                 self.last_selectable
@@ -161,10 +1156,10 @@ impl<L: List> Select<L> {
         }
 
         // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
-        let mut at = self.at.min(self.list.len());
+        let mut at = self.at.min(self.len());
         loop {
-            at = (at + 1) % self.list.len();
-            if self.list.is_selectable(at) {
+            at = (at + 1) % self.len();
+            if self.can_hover(at) {
                 break;
             }
         }
@@ -173,7 +1168,7 @@ impl<L: List> Select<L> {
 
     fn prev_selectable(&self) -> usize {
         if self.at <= self.first_selectable {
-            return if self.list.should_loop() {
+            return if self.should_loop() {
                 self.last_selectable
             } else {
                 self.first_selectable
@@ -181,10 +1176,10 @@ impl<L: List> Select<L> {
         }
 
         // at not guaranteed to be in the valid range of 0..list.len(), so the min is required
-        let mut at = self.at.min(self.list.len());
+        let mut at = self.at.min(self.len());
         loop {
-            at = (self.list.len() + at - 1) % self.list.len();
-            if self.list.is_selectable(at) {
+            at = (self.len() + at - 1) % self.len();
+            if self.can_hover(at) {
                 break;
             }
         }
@@ -192,6 +1187,7 @@ impl<L: List> Select<L> {
     }
 
     fn maybe_update_heights(&mut self, mut layout: Layout) {
+        let len = self.len();
         let heights = match self.heights {
             Some(ref mut heights) if heights.prev_layout != layout => {
                 heights.heights.clear();
@@ -200,7 +1196,7 @@ impl<L: List> Select<L> {
             }
             None => {
                 self.heights = Some(Heights {
-                    heights: Vec::with_capacity(self.list.len()),
+                    heights: Vec::with_capacity(len),
                     prev_layout: layout,
                 });
 
@@ -210,36 +1206,199 @@ impl<L: List> Select<L> {
         };
 
         layout.line_offset = 0;
+        let layout = without_scrollbar(self.scrollbar, layout);
 
         self.height = 0;
-        for i in 0..self.list.len() {
-            let height = self.list.height_at(i, layout);
+        for i in 0..len {
+            let index = list_index(&self.order, i);
+            let height = if self.expand_hovered && i == self.at {
+                self.list.expanded_height_at(index, layout)
+            } else {
+                self.list.height_at(index, layout)
+            } + spacing_after(self.item_spacing, i, len);
             self.height += height;
             heights.push(height);
         }
     }
 
+    /// Recomputes the heights of the previously and currently hovered elements if the hovered
+    /// element is expanded, and then refills the page since the heights may have changed.
+    fn update_expanded_heights(&mut self, prev_at: usize) {
+        if !self.expand_hovered || prev_at == self.at {
+            return;
+        }
+
+        let len = self.len();
+
+        let heights = match self.heights {
+            Some(ref mut heights) => heights,
+            None => return,
+        };
+
+        let mut layout = heights.prev_layout;
+        layout.line_offset = 0;
+        let layout = without_scrollbar(self.scrollbar, layout);
+
+        if let Some(prev_height) = heights.heights.get_mut(prev_at) {
+            let height = self
+                .list
+                .height_at(list_index(&self.order, prev_at), layout)
+                + spacing_after(self.item_spacing, prev_at, len);
+            self.height = self.height - *prev_height + height;
+            *prev_height = height;
+        }
+
+        if let Some(prev_height) = heights.heights.get_mut(self.at) {
+            let height = self
+                .list
+                .expanded_height_at(list_index(&self.order, self.at), layout)
+                + spacing_after(self.item_spacing, self.at, len);
+            self.height = self.height - *prev_height + height;
+            *prev_height = height;
+        }
+
+        self.refill_page();
+    }
+
+    /// Recomputes the end of the page keeping its start, for when the heights of the elements
+    /// have changed
+    fn refill_page(&mut self) {
+        // The page has not been computed yet
+        if self.page_end == usize::MAX {
+            return;
+        }
+
+        if !self.is_paginating() {
+            self.init_page();
+            return;
+        }
+
+        let heights = &self
+            .heights
+            .as_ref()
+            .expect("`refill_page` called before `height` or `render`")
+            .heights[..];
+
+        let max_height = self.page_size() - self.pagination_hint_height();
+
+        self.page_start_height = if self.page_start == self.at {
+            heights[self.page_start]
+        } else {
+            self.page_start_height.min(heights[self.page_start])
+        };
+
+        let mut height = self.page_start_height;
+        self.page_end = self.page_start;
+        self.page_end_height = height;
+
+        while height < max_height {
+            let i = (self.page_end + 1) % heights.len();
+            if i == self.page_start || (i == 0 && !self.should_loop()) {
+                break;
+            }
+
+            self.page_end = i;
+            self.page_end_height = (height + heights[i]).min(max_height) - height;
+            height += heights[i];
+        }
+
+        if height < max_height {
+            // The end of the list was reached without filling the page, so it is filled from the
+            // bottom instead
+            self.adjust_page(Movement::Down);
+        }
+    }
+
+    /// Calls [`List::on_page_change`] if the page is different from `prev_page`
+    fn report_page_change(&mut self, prev_page: (usize, usize)) {
+        if self.page_bounds() != prev_page {
+            self.list.on_page_change(self.page_start, self.page_end);
+        }
+    }
+
+    /// Calls [`List::on_highlight_change`] if the hovered element is different from `prev_at`
+    fn report_highlight_change(&mut self, prev_at: usize) {
+        if self.at != prev_at {
+            let index = self.list_index(self.at);
+            self.list.on_highlight_change(index);
+        }
+    }
+
+    /// The help for the hovered element, if any.
+    fn help_text(&self) -> Option<&str> {
+        self.hovered().and_then(|index| self.list.help(index))
+    }
+
+    /// The number of lines taken by the help for the hovered element.
+    fn help_height(&self, layout: Layout) -> u16 {
+        match self.help_text() {
+            Some(help) => wrapped_height(help, layout.available_width(), WrapMode::Word),
+            None => 0,
+        }
+    }
+
+    /// The effective height of a page, considering the `max_visible` and the height available in
+    /// the layout
     fn page_size(&self) -> u16 {
-        self.list.page_size() as u16
+        let mut page_size = self.list.page_size().min(u16::MAX as usize) as u16;
+
+        if let Some(max_visible) = self.max_visible {
+            page_size = page_size.min(max_visible);
+        }
+
+        match self.heights {
+            // The page size is not made smaller than the minimum, even if there isn't enough space
+            Some(ref heights) => page_size.min(heights.prev_layout.max_height.max(5)),
+            None => page_size,
+        }
     }
 
-    fn is_paginating(&self) -> bool {
-        self.height > self.page_size()
+    /// The number of lines taken by the message at the end of the page while paginating
+    fn pagination_hint_height(&self) -> u16 {
+        self.pagination_hint.is_some() as u16
+    }
+
+    fn should_loop(&self) -> bool {
+        match self.should_loop {
+            Some(ShouldLoop::Always) => true,
+            Some(ShouldLoop::Never) => false,
+            Some(ShouldLoop::Auto) => self.is_paginating(),
+            None => self.list.should_loop(),
+        }
     }
 
     /// Checks whether the page bounds need to be adjusted
     ///
-    /// This returns true if at == page_start || at == page_end, and so even though it is visible,
-    /// the page bounds should be adjusted
+    /// This returns true if `at` is within `scrolloff` elements of page_start or page_end, and so
+    /// even though it is visible, the page bounds should be adjusted
     fn at_outside_page(&self) -> bool {
-        if self.page_start < self.page_end {
-            // - a - - S - - - - - - E - a -
-            //   ^------- outside -------^
-            self.at <= self.page_start || self.at >= self.page_end
-        } else {
-            // - - - - E - - - a - - S - - -
-            //       outside --^
-            self.at <= self.page_start && self.at >= self.page_end
+        if self.page_end == usize::MAX {
+            // The page has not been computed yet, it is only adjusted if at is at the start
+            return self.at <= self.page_start;
+        }
+
+        let len = self.len();
+        // The position of an element relative to the page_start, taking looping into account
+        //
+        // - - - - E - - - a - - S - - -
+        //                       ^ 0
+        let offset = |i: usize| (i + len - self.page_start) % len;
+
+        let page_len = offset(self.page_end);
+        let at = offset(self.at);
+        let scrolloff = self.scrolloff as usize;
+
+        if at > page_len || at < scrolloff || page_len - at < scrolloff {
+            return true;
+        }
+
+        // With no scrolloff, `at` can be at the edge of the page, which may not be fully visible
+        match self.heights {
+            Some(ref heights) => {
+                (at == 0 && self.page_start_height < heights.heights[self.page_start])
+                    || (at == page_len && self.page_end_height < heights.heights[self.page_end])
+            }
+            None => false,
         }
     }
 
@@ -249,17 +1408,17 @@ impl<L: List> Select<L> {
         if delta.is_positive() {
             let res = self.at + delta as usize;
 
-            if res < self.list.len() {
+            if res < self.len() {
                 Some(res)
-            } else if self.list.should_loop() {
-                Some(res - self.list.len())
+            } else if self.should_loop() {
+                Some(res - self.len())
             } else {
                 None
             }
         } else {
             let delta = -delta as usize;
-            if self.list.should_loop() {
-                Some((self.at + self.list.len() - delta) % self.list.len())
+            if self.should_loop() {
+                Some((self.at + self.len() - delta) % self.len())
             } else {
                 self.at.checked_sub(delta)
             }
@@ -282,10 +1441,9 @@ impl<L: List> Select<L> {
             .expect("`adjust_page` called before `height` or `render`")
             .heights[..];
 
-        // -1 since the message at the end takes one line
-        let max_height = self.page_size() - 1;
+        let max_height = self.page_size() - self.pagination_hint_height();
 
-        // This first gets an element from the direction we have moved from, then one
+        // This first gets an element from the direction we have moved from, then `scrolloff`
         // from the opposite, and the rest again from the direction we have move from
         //
         // for example,
@@ -300,22 +1458,23 @@ impl<L: List> Select<L> {
         // |  3  | <-- self.at
         // .-----.
         // |  4  | <-- iter[1] | We pick 4 over ones before 2 since it provides a
-        // '-----'               padding of one element at the end
+        // '-----'               padding of one (`scrolloff`) element at the end
         //
         // note: the above example avoids things like looping, which is handled by
         // try_get_index
+        let scrolloff = self.scrolloff as isize;
         let iter = self
             .try_get_index(direction)
-            .map(|i| (i, false))
+            .map(|i| (i, 0))
             .into_iter()
             .chain(
-                self.try_get_index(-direction)
-                    .map(|i| (i, true)) // boolean value to show this is special
-                    .into_iter(),
+                // The distance is kept to show these are special
+                (1..=scrolloff)
+                    .filter_map(|i| self.try_get_index(-direction * i).map(|index| (index, i))),
             )
             .chain(
                 (2..(max_height as isize))
-                    .filter_map(|i| self.try_get_index(direction * i).map(|i| (i, false))),
+                    .filter_map(|i| self.try_get_index(direction * i).map(|i| (i, 0))),
             );
 
         // these variables have opposite meaning based on the direction, but they store
@@ -325,14 +1484,14 @@ impl<L: List> Select<L> {
 
         let mut height = heights[self.at];
 
-        for (height_index, opposite_dir) in iter {
+        for (height_index, opposite_dist) in iter {
             if height >= max_height {
                 // There are no more elements that can be shown
                 break;
             }
 
-            let elem_height = if opposite_dir {
-                // To provide better continuity, the element in the opposite direction
+            let elem_height = if opposite_dist == scrolloff && opposite_dist != 0 {
+                // To provide better continuity, the last element in the opposite direction
                 // will have only one line shown. This prevents the cursor from jumping
                 // about when the element in the opposite direction has different height
                 // from the one rendered previously
@@ -341,12 +1500,12 @@ impl<L: List> Select<L> {
                 (height + heights[height_index]).min(max_height) - height
             };
 
-            // If you see the creation of iter, this special cases the second element in
-            // the iterator as it is the _only_ one in the opposite direction
+            // If you see the creation of iter, this special cases the elements after the first
+            // in the iterator as they are the _only_ ones in the opposite direction
             //
-            // It cannot simply be checked as being the second element, as try_get_index
-            // may return None when looping is disabled
-            if opposite_dir {
+            // They cannot simply be checked by their position, as try_get_index may return
+            // None when looping is disabled
+            if opposite_dist != 0 {
                 bound_b.0 = height_index;
                 bound_b.1 = elem_height;
             } else {
@@ -394,8 +1553,7 @@ impl<L: List> Select<L> {
 
         if self.is_paginating() {
             let mut height = heights[0];
-            // -1 since the message at the end takes one line
-            let max_height = self.page_size() - 1;
+            let max_height = self.page_size() - self.pagination_hint_height();
 
             #[allow(clippy::needless_range_loop)]
             for i in 1..heights.len() {
@@ -408,7 +1566,7 @@ impl<L: List> Select<L> {
                 height += heights[i];
             }
         } else {
-            self.page_end = self.list.len() - 1;
+            self.page_end = self.len() - 1;
             self.page_end_height = heights[self.page_end];
         }
     }
@@ -428,21 +1586,47 @@ impl<L: List> Select<L> {
 
         // Create a new local copy of the layout to operate on to avoid changes in max_height and
         // render_region to be reflected upstream
-        let mut layout = *old_layout;
+        let mut layout = without_scrollbar(self.scrollbar, *old_layout);
 
         for i in iter {
-            if i == self.page_start {
-                layout.max_height = self.page_start_height;
+            // The number of lines given to the element, including the spacing after it
+            let height = if i == self.page_start {
                 layout.render_region = RenderRegion::Bottom;
+                self.page_start_height
             } else if i == self.page_end {
-                layout.max_height = self.page_end_height;
                 layout.render_region = RenderRegion::Top;
+                self.page_end_height
             } else {
-                layout.max_height = heights[i];
-            }
+                layout.render_region = old_layout.render_region;
+                heights[i]
+            };
 
-            self.list.render_item(i, i == self.at, layout, b)?;
-            layout.offset_y += layout.max_height;
+            let spacing = spacing_after(self.item_spacing, i, self.len());
+            layout.max_height = if i == self.page_start {
+                // The bottom of the element is shown, which is where the spacing is
+                height.saturating_sub(spacing)
+            } else {
+                height.min(heights[i] - spacing)
+            };
+
+            // If the element is cut off, only the spacing after it may be visible
+            if layout.max_height > 0 {
+                let index = list_index(&self.order, i);
+                let matched = match self.type_ahead {
+                    Some(ref type_ahead) => type_ahead.matched(index),
+                    None => None,
+                };
+
+                if self.expand_hovered && i == self.at {
+                    self.list.render_expanded_item(index, layout, b)?;
+                } else if let Some(matched) = matched {
+                    self.list
+                        .render_matched_item(index, i == self.at, matched, layout, b)?;
+                } else {
+                    self.list.render_item(index, i == self.at, layout, b)?;
+                }
+            }
+            layout.offset_y += height;
 
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
@@ -452,35 +1636,153 @@ impl<L: List> Select<L> {
 
         Ok(())
     }
+
+    /// The first row and the number of rows of the scrollbar thumb, given the number of rows the
+    /// scrollbar takes.
+    fn scrollbar_thumb(&self, rows: u16) -> (u16, u16) {
+        let len = self.len();
+        let rows = rows as usize;
+
+        let start = rows * self.page_start / len;
+        let end = if self.page_end >= self.page_start {
+            // Rounded up, so that the thumb reaches the bottom when the last element is visible
+            let end = rows * (self.page_end + 1);
+            end / len + (end % len).min(1)
+        } else {
+            // The page loops around, so only its size can be shown
+            let visible = self.page_end + len + 1 - self.page_start;
+            start + rows * visible / len
+        };
+
+        let size = end.saturating_sub(start).max(1).min(rows);
+        (start.min(rows - size) as u16, size as u16)
+    }
+
+    /// Renders the scrollbar in the last column of the `rows` lines starting at `offset_y`.
+    fn render_scrollbar<B: Backend>(
+        &self,
+        offset_y: u16,
+        rows: u16,
+        layout: Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        if rows == 0 || layout.available_width() <= 1 {
+            return Ok(());
+        }
+
+        let (thumb_start, thumb_size) = self.scrollbar_thumb(rows);
+        let track = crate::symbols::current().box_vertical;
+
+        for row in 0..rows {
+            b.move_cursor_to(layout.width - 1, offset_y + row)?;
+
+            if (thumb_start..thumb_start + thumb_size).contains(&row) {
+                b.set_attributes(Attributes::REVERSED)?;
+                b.write_all(b" ")?;
+                b.set_attributes(Attributes::empty())?;
+            } else {
+                b.write_styled(&track.dark_grey())?;
+            }
+        }
+
+        b.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+}
+
+/// The layout given to the elements, which leaves out the last column for the scrollbar if it is
+/// enabled.
+fn without_scrollbar(scrollbar: bool, mut layout: Layout) -> Layout {
+    if scrollbar && layout.available_width() > 1 {
+        layout.width -= 1;
+    }
+    layout
+}
+
+/// The number of blank lines after the element at `pos`, which is none for the last element.
+fn spacing_after(item_spacing: u16, pos: usize, len: usize) -> u16 {
+    if pos + 1 < len {
+        item_spacing
+    } else {
+        0
+    }
+}
+
+impl<L: ReorderList> Select<L> {
+    /// Consumes the [`Select`] returning the list reordered to the order set by [`sort_by`].
+    ///
+    /// The elements are moved with [`ReorderList::swap`], so that the element shown first is at index
+    /// `0` and so on. Elements hidden by a filter are placed where they would be shown without
+    /// it. If the list was never sorted, it is returned unchanged.
+    ///
+    /// [`sort_by`]: Select::sort_by
+    pub fn apply_order(mut self) -> L {
+        let order = match self.filter.take() {
+            Some(filter) => filter.base,
+            None => match self.order.take() {
+                Some(order) => order,
+                None => return self.list,
+            },
+        };
+
+        // `at[pos]` is the original index of the element at `pos`, and `pos_of` is its inverse
+        let mut at: Vec<usize> = (0..order.len()).collect();
+        let mut pos_of = at.clone();
+
+        for (pos, &index) in order.iter().enumerate() {
+            let from = pos_of[index];
+            if from != pos {
+                self.list.swap(pos, from);
+                at.swap(pos, from);
+                pos_of[at[pos]] = pos;
+                pos_of[at[from]] = from;
+            }
+        }
+
+        self.list
+    }
 }
 
 impl<L: Index<usize>> Select<L> {
     /// Returns a reference to the currently hovered item.
     pub fn selected(&self) -> &L::Output {
-        &self.list[self.at]
+        &self.list[list_index(&self.order, self.at)]
     }
 }
 
 impl<L: IndexMut<usize>> Select<L> {
     /// Returns a mutable reference to the currently hovered item.
     pub fn selected_mut(&mut self) -> &mut L::Output {
-        &mut self.list[self.at]
+        &mut self.list[list_index(&self.order, self.at)]
     }
 }
 
 impl<L: List> super::Widget for Select<L> {
     fn handle_key(&mut self, key: KeyEvent) -> bool {
-        let movement = match Movement::try_from_key(key) {
-            Some(movement) => movement,
-            None => return false,
+        if key.kind != KeyEventKind::Release
+            && (self.handle_query_key(key) || self.handle_type_ahead_key(key))
+        {
+            return true;
+        }
+
+        // A filter can hide every selectable element, in which case there is nowhere to move
+        if self.filter.is_some() && (self.is_empty() || !self.can_hover(self.at)) {
+            return false;
+        }
+
+        let movement = match self.bindings.movement(key) {
+            Some(movement) if !self.ignored_movements.contains(&movement) => movement,
+            _ => return false,
         };
 
+        let page = self.page_bounds();
+        let prev_at = self.at;
+
         let moved = match movement {
-            Movement::Up if self.list.should_loop() || self.at > self.first_selectable => {
+            Movement::Up if self.should_loop() || self.at > self.first_selectable => {
                 self.at = self.prev_selectable();
                 Movement::Up
             }
-            Movement::Down if self.list.should_loop() || self.at < self.last_selectable => {
+            Movement::Down if self.should_loop() || self.at < self.last_selectable => {
                 self.at = self.next_selectable();
                 Movement::Down
             }
@@ -488,7 +1790,7 @@ impl<L: List> super::Widget for Select<L> {
             Movement::PageUp
                 if !self.is_paginating() // No pagination, PageUp is same as Home
                     // No looping and first item is shown in this page
-                    || (!self.list.should_loop() && self.page_start == 0) =>
+                    || (!self.should_loop() && self.page_start == 0) =>
             {
                 if self.at <= self.first_selectable {
                     return false;
@@ -506,7 +1808,7 @@ impl<L: List> super::Widget for Select<L> {
                 self.at = self.try_get_index(-1).unwrap_or(self.at);
                 self.adjust_page(Movement::Down);
 
-                if self.page_start == 0 && !self.list.should_loop() {
+                if self.page_start == 0 && !self.should_loop() {
                     // We've reached the end, it is possible that because of the bounds
                     // we gave earlier, self.page_end may not be right so we have to
                     // recompute it
@@ -529,8 +1831,8 @@ impl<L: List> super::Widget for Select<L> {
 
             Movement::PageDown
                 if !self.is_paginating() // No pagination, PageDown same as End
-                    || (!self.list.should_loop() // No looping and last item is shown in this page
-                        && self.page_end + 1 == self.list.len()) =>
+                    || (!self.should_loop() // No looping and last item is shown in this page
+                        && self.page_end + 1 == self.len()) =>
             {
                 if self.at >= self.last_selectable {
                     return false;
@@ -553,7 +1855,7 @@ impl<L: List> super::Widget for Select<L> {
                 // so we overshoot by 1...
                 self.at = self.page_end;
 
-                if self.page_end + 1 == self.list.len() && !self.list.should_loop() {
+                if self.page_end + 1 == self.len() && !self.should_loop() {
                     // ...but since we reached the end and there is no looping, self.page_start may
                     // not be right so we have to recompute it
                     self.adjust_page(Movement::Down);
@@ -581,10 +1883,15 @@ impl<L: List> super::Widget for Select<L> {
             _ => return false,
         };
 
+        self.update_expanded_heights(prev_at);
+
         if self.is_paginating() {
             self.maybe_adjust_page(moved)
         }
 
+        self.report_page_change(page);
+        self.report_highlight_change(prev_at);
+
         true
     }
 
@@ -592,8 +1899,17 @@ impl<L: List> super::Widget for Select<L> {
         self.maybe_update_heights(*layout);
 
         // this is the first render, so we need to set page_end
-        if self.page_end == usize::MAX {
+        if self.page_end == usize::MAX && !self.is_empty() {
+            let page = self.page_bounds();
             self.init_page();
+
+            // `at` may have been set before the heights were known, in which case the page has to
+            // be moved down to it
+            if self.is_paginating() && self.at >= self.page_end {
+                self.adjust_page(Movement::Down);
+            }
+
+            self.report_page_change(page);
         }
 
         if layout.line_offset != 0 {
@@ -602,9 +1918,13 @@ impl<L: List> super::Widget for Select<L> {
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
 
-        if self.page_end < self.page_start {
+        let offset_y = layout.offset_y;
+
+        if self.is_empty() {
+            // Nothing matches the filter
+        } else if self.page_end < self.page_start {
             self.render_in(
-                (self.page_start..self.list.len()).chain(0..=self.page_end),
+                (self.page_start..self.len()).chain(0..=self.page_end),
                 layout,
                 b,
             )?;
@@ -612,14 +1932,39 @@ impl<L: List> super::Widget for Select<L> {
             self.render_in(self.page_start..=self.page_end, layout, b)?;
         }
 
-        if self.is_paginating() {
-            // This is the message at the end that other places refer to
-            b.write_styled(&"(Move up and down to reveal more choices)".dark_grey())?;
+        if self.scrollbar && self.is_paginating() && !self.is_empty() {
+            self.render_scrollbar(offset_y, layout.offset_y - offset_y, *layout, b)?;
+        }
+
+        match self.pagination_hint {
+            Some(ref hint) if self.is_paginating() => {
+                // This is the message at the end that other places refer to
+                b.write_styled(&hint.as_str().dark_grey())?;
+                layout.offset_y += 1;
+
+                b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            }
+            _ => {}
+        }
+
+        if self.is_filtering() {
+            b.write_styled(&"Filtering…".dark_grey())?;
             layout.offset_y += 1;
 
             b.move_cursor_to(layout.offset_x, layout.offset_y)?;
         }
 
+        if let Some(help) = self.help_text() {
+            for line in wrap_lines(help, layout.available_width(), WrapMode::Word) {
+                b.set_attributes(Attributes::DIM)?;
+                b.write_all(line.as_bytes())?;
+                b.set_attributes(Attributes::empty())?;
+                layout.offset_y += 1;
+
+                b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -629,6 +1974,11 @@ impl<L: List> super::Widget for Select<L> {
         layout.offset_cursor((layout.line_offset, 0))
     }
 
+    /// A list has no text to edit, so it does not need a cursor.
+    fn wants_cursor(&self) -> bool {
+        false
+    }
+
     fn height(&mut self, layout: &mut Layout) -> u16 {
         self.maybe_update_heights(*layout);
 
@@ -647,8 +1997,11 @@ impl<L: List> super::Widget for Select<L> {
                     .get(self.at)
                     .unwrap_or(&0)
                     // +1 if paginating since the message at the end takes one line
-                    + self.is_paginating() as u16,
-                );
+                    + self.is_paginating() as u16 * self.pagination_hint_height(),
+                )
+            // the message shown while filtering takes one line
+            + self.is_filtering() as u16
+            + self.help_height(*layout);
 
         layout.line_offset = 0;
         layout.offset_y += height;
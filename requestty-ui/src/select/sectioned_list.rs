@@ -0,0 +1,188 @@
+use std::io;
+
+use super::List;
+use crate::{
+    backend::Backend,
+    layout::Layout,
+    style::{Attributes, Color},
+    widgets::write_truncated,
+};
+
+/// A [`List`] of items grouped into sections, each with a header.
+///
+/// Every section header and item takes a single row. The headers are dimmed and cannot be
+/// selected, so they are skipped when moving through the list. The items are indented by 2
+/// columns under their header, and the hovered item is coloured cyan.
+///
+/// The indices used by [`Select`] count the headers as well. Use [`item_at`] to map them back to
+/// a section and an item within it.
+///
+/// [`Select`]: crate::widgets::Select
+/// [`item_at`]: SectionedList::item_at
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{SectionedList, Select};
+///
+/// let list = SectionedList::new(vec![
+///     ("Appearance", vec!["Theme", "Font size"]),
+///     ("Privacy", vec!["Telemetry"]),
+/// ]);
+///
+/// let select = Select::new(list);
+/// // The first item is hovered, as the header cannot be selected
+/// assert_eq!(select.list.item_at(select.get_at()), Some((0, 0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SectionedList<T> {
+    sections: Vec<(T, Vec<T>)>,
+    // The index of the header of each section
+    starts: Vec<usize>,
+    len: usize,
+    page_size: usize,
+    should_loop: bool,
+}
+
+impl<T: AsRef<str>> SectionedList<T> {
+    /// Creates a new `SectionedList` from `(header, items)` pairs.
+    ///
+    /// By default, the page size is 15, and the list loops.
+    pub fn new(sections: Vec<(T, Vec<T>)>) -> Self {
+        let mut starts = Vec::with_capacity(sections.len());
+        let mut len = 0;
+
+        for (_, items) in &sections {
+            starts.push(len);
+            len += 1 + items.len();
+        }
+
+        Self {
+            sections,
+            starts,
+            len,
+            page_size: 15,
+            should_loop: true,
+        }
+    }
+
+    /// Creates a new `SectionedList` with the given `page_size`.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Creates a new `SectionedList` with the given `should_loop`.
+    pub fn with_should_loop(mut self, should_loop: bool) -> Self {
+        self.should_loop = should_loop;
+        self
+    }
+
+    /// The `(header, items)` pairs of the list.
+    pub fn sections(&self) -> &[(T, Vec<T>)] {
+        &self.sections
+    }
+
+    /// Consumes the list returning the `(header, items)` pairs.
+    pub fn into_sections(self) -> Vec<(T, Vec<T>)> {
+        self.sections
+    }
+
+    /// The section and the index of the item within it, at an index of the list.
+    ///
+    /// It is `None` if the index is of a section header, or is past the end of the list.
+    pub fn item_at(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.len {
+            return None;
+        }
+
+        let section = self.section_of(index);
+        match index - self.starts[section] {
+            0 => None,
+            offset => Some((section, offset - 1)),
+        }
+    }
+
+    /// The index in the list of an item in a section. This is the inverse of [`item_at`].
+    ///
+    /// [`item_at`]: SectionedList::item_at
+    ///
+    /// # Panics
+    ///
+    /// Panics if the section or the item does not exist.
+    pub fn index_of(&self, section: usize, item: usize) -> usize {
+        assert!(
+            item < self.sections[section].1.len(),
+            "item {} does not exist in section {}",
+            item,
+            section
+        );
+
+        self.starts[section] + 1 + item
+    }
+
+    fn section_of(&self, index: usize) -> usize {
+        match self.starts.binary_search(&index) {
+            Ok(section) => section,
+            Err(next_section) => next_section - 1,
+        }
+    }
+}
+
+impl<T: AsRef<str>> List for SectionedList<T> {
+    fn render_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        let line_width = layout.line_width() as usize;
+
+        let (section, item) = match self.item_at(index) {
+            Some(item) => item,
+            None => {
+                let header = &self.sections[self.section_of(index)].0;
+                backend.set_attributes(Attributes::DIM)?;
+                write_truncated(header.as_ref(), line_width, backend)?;
+                return backend.set_attributes(Attributes::empty());
+            }
+        };
+
+        backend.write_all(b"  ")?;
+
+        if hovered {
+            backend.set_fg(Color::Cyan)?;
+        }
+        write_truncated(
+            self.sections[section].1[item].as_ref(),
+            line_width.saturating_sub(2),
+            backend,
+        )?;
+        if hovered {
+            backend.set_fg(Color::Reset)?;
+        }
+
+        Ok(())
+    }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        self.item_at(index).is_some()
+    }
+
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn should_loop(&self) -> bool {
+        self.should_loop
+    }
+
+    fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+        1
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
@@ -11,6 +11,12 @@ struct List<T> {
     selectable: Vec<bool>,
     page_size: usize,
     should_loop: bool,
+    // The indices and matched lengths given to `render_matched_item`
+    matched: Vec<(usize, usize)>,
+    help: Vec<Option<&'static str>>,
+    // The arguments `on_page_change` and `on_highlight_change` were called with
+    pages: Vec<(usize, usize)>,
+    highlighted: Vec<usize>,
 }
 
 impl<T> List<T> {
@@ -20,9 +26,19 @@ impl<T> List<T> {
             selectable: Vec::new(),
             page_size: 15,
             should_loop: true,
+            matched: Vec::new(),
+            help: Vec::new(),
+            pages: Vec::new(),
+            highlighted: Vec::new(),
         }
     }
 
+    fn with_help(mut self, help: Vec<Option<&'static str>>) -> Self {
+        assert_eq!(help.len(), self.vec.len());
+        self.help = help;
+        self
+    }
+
     fn with_page_size(mut self, page_size: usize) -> Self {
         self.page_size = page_size;
         self
@@ -77,6 +93,36 @@ impl<T: Widget> super::List for List<T> {
     fn len(&self) -> usize {
         self.vec.len()
     }
+
+    fn render_matched_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        self.matched.push((index, matched));
+        self.render_item(index, hovered, layout, backend)
+    }
+
+    fn help(&self, index: usize) -> Option<&str> {
+        self.help.get(index).copied().flatten()
+    }
+
+    fn on_page_change(&mut self, page_start: usize, page_end: usize) {
+        self.pages.push((page_start, page_end));
+    }
+
+    fn on_highlight_change(&mut self, index: usize) {
+        self.highlighted.push(index);
+    }
+}
+
+impl<T: Widget> super::ReorderList for List<T> {
+    fn swap(&mut self, a: usize, b: usize) {
+        self.vec.swap(a, b)
+    }
 }
 
 /// Returns a Vec with things will render on a single line
@@ -527,6 +573,360 @@ fn test_handle_key() {
     assert_eq!(select.page_end_height, 5);
 }
 
+#[test]
+fn test_scrolloff() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    // The page fits 9 elements, as the last line shows a message
+    let mut select =
+        Select::new(List::new(single_line_vec(20)).with_page_size(10)).with_scrolloff(0);
+    select.height(&mut layout.clone());
+    select.init_page();
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+
+    // The hovered element can reach the edge of the page before it scrolls
+    select.set_at(8);
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 9);
+    assert_eq!((select.page_start, select.page_end), (1, 9));
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!((select.page_start, select.page_end), (1, 9));
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 7);
+    assert_eq!((select.page_start, select.page_end), (1, 9));
+
+    let mut select =
+        Select::new(List::new(single_line_vec(20)).with_page_size(10)).with_scrolloff(2);
+    select.height(&mut layout.clone());
+    select.init_page();
+
+    // 2 elements are kept below the hovered element
+    select.set_at(6);
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 7);
+    assert_eq!((select.page_start, select.page_end), (1, 9));
+    assert_eq!(select.page_end_height, 1);
+
+    // ...and above it when moving up
+    select.set_at(3);
+    assert_eq!((select.page_start, select.page_end), (1, 9));
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 2);
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+}
+
+#[test]
+fn test_item_spacing() {
+    use std::io::Write;
+
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+
+    // There is no spacing after the last element
+    let mut select =
+        Select::new(List::new(single_line_vec(5)).with_page_size(10)).with_item_spacing(1);
+    assert_eq!(select.height(&mut layout.clone()), 9);
+    assert!(!select.is_paginating());
+
+    // The 10 elements only take 10 lines, but with the spacing they need to be paginated
+    let mut select = Select::new(
+        List::new(single_line_vec(10))
+            .with_page_size(10)
+            .with_should_loop(false),
+    )
+    .with_item_spacing(1);
+    assert_eq!(select.height(&mut layout.clone()), 10);
+    assert!(select.is_paginating());
+
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    assert_eq!((select.page_start, select.page_end), (0, 4));
+    // Only the element is shown, and not the spacing after it
+    assert_eq!(select.page_end_height, 1);
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    expected.write_all(b"0 list item").unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    for i in 1..5 {
+        expected.move_cursor_to(0, i * 2).unwrap();
+        write!(expected, "{} list item", i).unwrap();
+    }
+    expected.move_cursor_to(0, 9).unwrap();
+    expected
+        .write_styled(&"(Move up and down to reveal more choices)".dark_grey())
+        .unwrap();
+    expected.move_cursor_to(0, 10).unwrap();
+
+    backend.assert_eq(&expected);
+
+    // As the last element has no spacing after it, the last 5 elements fit exactly
+    select.set_at(9);
+    assert_eq!((select.page_start, select.page_end), (5, 9));
+    assert_eq!((select.page_start_height, select.page_end_height), (2, 1));
+}
+
+#[test]
+fn test_max_visible() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select =
+        Select::new(List::new(single_line_vec(20)).with_page_size(15)).with_max_visible(7);
+    assert_eq!(select.height(&mut layout.clone()), 7);
+    select.init_page();
+    // 6 elements are shown, as the last line shows a message
+    assert_eq!((select.page_start, select.page_end), (0, 5));
+
+    select.set_at(4);
+    assert_eq!((select.page_start, select.page_end), (0, 5));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!((select.page_start, select.page_end), (1, 6));
+    assert!(select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 9);
+    assert_eq!((select.page_start, select.page_end), (5, 10));
+
+    // The height available in the layout also limits the page
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(15));
+    assert_eq!(select.height(&mut layout.with_max_height(8)), 8);
+    select.init_page();
+    assert_eq!((select.page_start, select.page_end), (0, 6));
+}
+
+#[test]
+fn test_scrollbar() {
+    use std::io::Write;
+
+    let size = (30, 20).into();
+    let layout = Layout::new(0, size);
+    let select = || {
+        Select::new(
+            List::new(single_line_vec(20))
+                .with_page_size(10)
+                .with_should_loop(false),
+        )
+    };
+
+    let mut scrolled = select().with_scrollbar(true);
+    let mut backend = TestBackend::new(size);
+    scrolled.render(&mut layout.clone(), &mut backend).unwrap();
+    // 9 of the 20 elements are shown, so the thumb covers about half of the track
+    assert_eq!(scrolled.scrollbar_thumb(9), (0, 5));
+
+    let mut expected = TestBackend::new(size);
+    select().render(&mut layout.clone(), &mut expected).unwrap();
+    for row in 0..9 {
+        expected.move_cursor_to(29, row).unwrap();
+        if row < 5 {
+            expected.set_attributes(Attributes::REVERSED).unwrap();
+            expected.write_all(b" ").unwrap();
+            expected.set_attributes(Attributes::empty()).unwrap();
+        } else {
+            expected
+                .write_styled(&crate::symbols::current().box_vertical.dark_grey())
+                .unwrap();
+        }
+    }
+    expected.move_cursor_to(0, 10).unwrap();
+    backend.assert_eq(&expected);
+
+    assert!(scrolled.handle_key(KeyCode::End.into()));
+    assert_eq!((scrolled.page_start, scrolled.page_end), (11, 19));
+    assert_eq!(scrolled.scrollbar_thumb(9), (4, 5));
+
+    // While looping around, the thumb cannot go past the end
+    scrolled.page_start = 16;
+    scrolled.page_end = 4;
+    assert_eq!(scrolled.scrollbar_thumb(9), (5, 4));
+
+    // The last column is left for the scrollbar even when it is not shown
+    assert_eq!(without_scrollbar(true, layout).available_width(), 29);
+    let mut backend = TestBackend::new(size);
+    Select::new(List::new(single_line_vec(3)))
+        .with_scrollbar(true)
+        .render(&mut layout.clone(), &mut backend)
+        .unwrap();
+    let mut expected = TestBackend::new(size);
+    Select::new(List::new(single_line_vec(3)))
+        .render(&mut layout.clone(), &mut expected)
+        .unwrap();
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_pagination_hint() {
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+    let list = || List::new(single_line_vec(20)).with_page_size(10);
+
+    // Without a hint, its line is used for another element
+    let mut select = Select::new(list()).with_pagination_hint(None);
+    assert_eq!(select.height(&mut layout.clone()), 10);
+    let mut backend = TestBackend::new(size);
+    let mut l = layout;
+    select.render(&mut l, &mut backend).unwrap();
+    assert_eq!((select.page_start, select.page_end), (0, 9));
+    assert_eq!(l, layout.with_offset(0, 10));
+    assert!(!backend.to_string().contains("reveal more"));
+
+    let mut select = Select::new(list()).with_pagination_hint(Some("More below".into()));
+    assert_eq!(select.height(&mut layout.clone()), 10);
+    let mut backend = TestBackend::new(size);
+    let mut l = layout;
+    select.render(&mut l, &mut backend).unwrap();
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+    assert_eq!(l, layout.with_offset(0, 10));
+    assert!(backend.to_string().contains("More below"));
+
+    // The hint is only shown while paginating
+    let mut backend = TestBackend::new(size);
+    Select::new(List::new(single_line_vec(3)))
+        .with_pagination_hint(Some("More below".into()))
+        .render(&mut layout.clone(), &mut backend)
+        .unwrap();
+    assert!(!backend.to_string().contains("More below"));
+}
+
+#[test]
+fn test_page_bounds() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+    assert_eq!(select.page_bounds(), (0, usize::MAX));
+    select
+        .render(&mut layout.clone(), &mut TestBackend::new((100, 20).into()))
+        .unwrap();
+    assert!(select.is_paginating());
+    assert_eq!(select.page_bounds(), (0, 8));
+
+    select.set_at(12);
+    let (start, end) = select.page_bounds();
+    assert!(start <= 12 && 12 <= end);
+
+    let mut select = Select::new(List::new(single_line_vec(5)));
+    select.height(&mut layout.clone());
+    assert!(!select.is_paginating());
+}
+
+#[test]
+fn test_at_before_render() {
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+
+    // The heights are not known yet, so the page is moved to `at` on the first render
+    let mut select = Select::new(List::new(single_line_vec(30)).with_page_size(10));
+    select.set_at(25);
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    assert_eq!(select.page_bounds(), (18, 26));
+    assert!(backend.to_string().contains("25 list item"));
+
+    let mut select = Select::new(List::new(single_line_vec(30)).with_page_size(10));
+    select.set_at(3);
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    assert_eq!(select.page_bounds(), (0, 8));
+}
+
+#[test]
+fn test_ignore_movements() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10))
+        .ignore_movements(&[Movement::PageUp, Movement::PageDown]);
+    select.height(&mut layout.clone());
+    select.init_page();
+
+    // The ignored movements bubble up without changing anything
+    assert!(!select.handle_key(KeyCode::PageDown.into()));
+    assert_eq!(select.get_at(), 0);
+    assert_eq!((select.page_start, select.page_end), (0, 8));
+    select.set_at(5);
+    assert!(!select.handle_key(KeyCode::PageUp.into()));
+    assert_eq!(select.get_at(), 5);
+
+    // ...while the others are still handled
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 6);
+    assert!(select.handle_key(KeyCode::Home.into()));
+    assert_eq!(select.get_at(), 0);
+}
+
+#[test]
+fn test_apply_order() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    // An unsorted list is left as it is
+    let select = Select::new(List::new(single_line_vec(5)));
+    assert_eq!(select.apply_order().vec, single_line_vec(5));
+
+    let key = |i: usize| (i * 3) % 5;
+    let mut select = Select::new(List::new(single_line_vec(5)));
+    select.height(&mut layout.clone());
+    select.sort_by(|a, b| key(a).cmp(&key(b)));
+    assert_eq!(
+        select.apply_order().vec,
+        [0, 2, 4, 1, 3]
+            .iter()
+            .map(|i| format!("{} list item", i))
+            .collect::<Vec<_>>()
+    );
+
+    // Hidden elements are placed where they would be without the filter
+    let mut select = Select::new(List::new(single_line_vec(5)));
+    select.height(&mut layout.clone());
+    select.sort_by(|a, b| b.cmp(&a));
+    select.start_filter();
+    select.filter_step(usize::MAX, |i| i % 2 == 0);
+    let mut reversed = single_line_vec(5);
+    reversed.reverse();
+    assert_eq!(select.apply_order().vec, reversed);
+}
+
+#[test]
+fn test_sort_by() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+    select.height(&mut layout.clone());
+    select.init_page();
+
+    select.sort_by(|a, b| b.cmp(&a));
+    // The hovered element stays the same, but is now shown last
+    assert_eq!(select.get_at(), 0);
+    assert_eq!(select.at, 19);
+    assert_eq!((select.page_start, select.page_end), (12, 0));
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 19);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 18);
+    assert_eq!(select.list.vec[select.get_at()], "18 list item");
+    assert_eq!((select.page_start, select.page_end), (14, 2));
+
+    select.set_at(10);
+    assert_eq!(select.at, 9);
+
+    // It can be sorted again
+    select.sort_by(|a, b| a.cmp(&b));
+    assert_eq!(select.get_at(), 10);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 11);
+
+    // The first and last selectable elements follow the order
+    let mut selectable = vec![true; 20];
+    selectable[0] = false;
+    let mut select = Select::new(List::new(single_line_vec(20)).with_selectable(selectable));
+    select.height(&mut layout.clone());
+    select.sort_by(|a, b| b.cmp(&a));
+    assert_eq!(select.get_at(), 1);
+    assert!(!select.handle_key(KeyCode::End.into()));
+    assert!(select.handle_key(KeyCode::Home.into()));
+    assert_eq!(select.get_at(), 19);
+    assert!(select.handle_key(KeyCode::End.into()));
+    assert_eq!(select.get_at(), 1);
+}
+
 #[test]
 fn test_render() {
     let size = (100, 20).into();
@@ -592,3 +992,904 @@ fn test_render() {
     crate::assert_backend_snapshot!(backend);
     assert_eq!(layout, base_layout.with_offset(20, 31));
 }
+
+#[test]
+fn test_on_page_change() {
+    let size = (100, 20).into();
+    let mut backend = TestBackend::new(size);
+    let mut select = Select::new(List::new(single_line_vec(20)).with_page_size(10));
+
+    let mut render = |select: &mut Select<_>| {
+        let mut layout = Layout::new(0, size);
+        backend.reset_with_layout(layout);
+        select.render(&mut layout, &mut backend).unwrap();
+    };
+
+    render(&mut select);
+    render(&mut select);
+    assert_eq!(select.list.pages, [(0, 8)]);
+
+    // Moving within the page should not change it
+    for _ in 0..7 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        render(&mut select);
+    }
+    assert_eq!(select.list.pages, [(0, 8)]);
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    render(&mut select);
+    assert_eq!(select.list.pages, [(0, 8), (1, 9)]);
+
+    assert!(select.handle_key(KeyCode::Up.into()));
+    render(&mut select);
+    assert_eq!(select.list.pages.len(), 2);
+
+    assert!(select.handle_key(KeyCode::End.into()));
+    render(&mut select);
+    assert_eq!(select.list.pages.len(), 3);
+    assert_eq!(select.list.pages[2], (12, 0));
+}
+
+#[test]
+fn test_on_highlight_change() {
+    let mut selectable = vec![true; 20];
+    selectable[1] = false;
+
+    let mut select = Select::new(
+        List::new(single_line_vec(20))
+            .with_page_size(10)
+            .with_selectable(selectable),
+    );
+    select.height(&mut Layout::new(0, (100, 20).into()));
+
+    // Skips over the unselectable element
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Home.into()));
+    // Wraps around to the end
+    assert!(select.handle_key(KeyCode::Up.into()));
+    // Already at the end, so this is not handled
+    assert!(!select.handle_key(KeyCode::End.into()));
+    assert!(select.handle_key(KeyCode::PageUp.into()));
+    // Setting the element which is already hovered does nothing
+    let at = select.get_at();
+    select.set_at(at);
+    select.set_at(5);
+    // Keys which aren't movements are not handled
+    assert!(!select.handle_key(KeyCode::Char('a').into()));
+
+    assert_eq!(select.list.highlighted, [2, 0, 19, at, 5]);
+}
+
+#[test]
+fn test_described_list() {
+    use super::List as _;
+    use crate::style::Attributes;
+    use std::io::Write;
+
+    let size = (40, 10).into();
+    let layout = Layout::new(0, size);
+
+    let mut list = super::DescribedList::new(
+        vec![
+            (
+                "build",
+                "Compile the current package and all of its dependencies",
+            ),
+            ("run tests", "Short"),
+        ],
+        8,
+    );
+
+    let mut backend = TestBackend::new(size);
+    list.render_item(0, true, layout, &mut backend).unwrap();
+    backend.move_cursor_to(0, 1).unwrap();
+    list.render_item(1, false, layout.with_offset(0, 1), &mut backend)
+        .unwrap();
+    backend.move_cursor_to(0, 2).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    expected.write_all(b"build").unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    expected.write_all(b"    ").unwrap();
+    expected.set_attributes(Attributes::DIM).unwrap();
+    expected
+        .write_all(b"Compile the current package...")
+        .unwrap();
+    expected.set_attributes(Attributes::empty()).unwrap();
+    expected.move_cursor_to(0, 1).unwrap();
+    expected.write_all(b"run...   ").unwrap();
+    expected.set_attributes(Attributes::DIM).unwrap();
+    expected.write_all(b"Short").unwrap();
+    expected.set_attributes(Attributes::empty()).unwrap();
+    expected.move_cursor_to(0, 2).unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_clipped_indicator() {
+    use std::io::Write;
+
+    /// Items with a title and a subtitle, which only draw the lines they are given
+    struct TwoLineList;
+
+    impl super::List for TwoLineList {
+        fn render_item<B: Backend>(
+            &mut self,
+            index: usize,
+            hovered: bool,
+            layout: Layout,
+            backend: &mut B,
+        ) -> io::Result<()> {
+            let visible = layout.visible_lines(2);
+            let start = visible.start;
+
+            for line in visible {
+                backend.move_cursor_to(layout.offset_x, layout.offset_y + line - start)?;
+
+                if hovered && line == start {
+                    backend.write_all(b"> ")?;
+                } else {
+                    backend.write_all(b"  ")?;
+                }
+
+                if line == 0 {
+                    write!(backend, "title {}", index)?;
+                } else {
+                    write!(backend, "subtitle {}", index)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        fn is_selectable(&self, _: usize) -> bool {
+            true
+        }
+
+        fn page_size(&self) -> usize {
+            5
+        }
+
+        fn should_loop(&self) -> bool {
+            true
+        }
+
+        fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+            2
+        }
+
+        fn len(&self) -> usize {
+            3
+        }
+    }
+
+    let size = (50, 10).into();
+    let mut layout = Layout::new(0, size);
+    let mut select = Select::new(TwoLineList);
+    select.maybe_update_heights(layout);
+    select.init_page();
+
+    // Both the hovered item at the start of the page and the item at the end of the page are
+    // clipped to a single line
+    select.at = 0;
+    select.page_start = 0;
+    select.page_start_height = 1;
+    select.page_end = 2;
+    select.page_end_height = 1;
+
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout, &mut backend).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.write_all(b"> subtitle 0").unwrap();
+    expected.move_cursor_to(0, 1).unwrap();
+    expected.write_all(b"  title 1").unwrap();
+    expected.move_cursor_to(0, 2).unwrap();
+    expected.write_all(b"  subtitle 1").unwrap();
+    expected.move_cursor_to(0, 3).unwrap();
+    expected.write_all(b"  title 2").unwrap();
+    expected.move_cursor_to(0, 4).unwrap();
+    expected
+        .write_styled(&"(Move up and down to reveal more choices)".dark_grey())
+        .unwrap();
+    expected.move_cursor_to(0, 5).unwrap();
+
+    backend.assert_eq(&expected);
+
+    // The hovered item at the end of the page is shown from its first line
+    select.at = 2;
+    select.page_start = 0;
+    select.page_start_height = 1;
+    select.page_end = 2;
+    select.page_end_height = 1;
+
+    let mut layout = Layout::new(0, size);
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout, &mut backend).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.write_all(b"  subtitle 0").unwrap();
+    expected.move_cursor_to(0, 1).unwrap();
+    expected.write_all(b"  title 1").unwrap();
+    expected.move_cursor_to(0, 2).unwrap();
+    expected.write_all(b"  subtitle 1").unwrap();
+    expected.move_cursor_to(0, 3).unwrap();
+    expected.write_all(b"> title 2").unwrap();
+    expected.move_cursor_to(0, 4).unwrap();
+    expected
+        .write_styled(&"(Move up and down to reveal more choices)".dark_grey())
+        .unwrap();
+    expected.move_cursor_to(0, 5).unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_sectioned_list() {
+    use crate::style::Attributes;
+    use std::io::Write;
+
+    let list = super::SectionedList::new(vec![
+        ("Appearance", vec!["Theme", "Font size"]),
+        ("Privacy", vec!["Telemetry"]),
+    ])
+    .with_should_loop(false);
+
+    assert_eq!(list.item_at(0), None);
+    assert_eq!(list.item_at(2), Some((0, 1)));
+    assert_eq!(list.item_at(3), None);
+    assert_eq!(list.item_at(4), Some((1, 0)));
+    assert_eq!(list.item_at(5), None);
+    assert_eq!(list.index_of(1, 0), 4);
+
+    let mut select = Select::new(list);
+    assert_eq!(select.get_at(), 1);
+
+    // Moving down from the last item of a section skips the next header
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.list.item_at(select.get_at()), Some((0, 1)));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 4);
+    assert_eq!(select.list.item_at(select.get_at()), Some((1, 0)));
+
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.list.item_at(select.get_at()), Some((0, 1)));
+
+    // The first header can't be hovered
+    assert!(select.handle_key(KeyCode::Home.into()));
+    assert_eq!(select.get_at(), 1);
+
+    let size = (20, 10).into();
+    let mut backend = TestBackend::new(size);
+    select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+
+    let mut expected = TestBackend::new(size);
+    for (i, (line, hovered)) in [
+        ("Appearance", None),
+        ("Theme", Some(true)),
+        ("Font size", Some(false)),
+        ("Privacy", None),
+        ("Telemetry", Some(false)),
+    ]
+    .iter()
+    .enumerate()
+    {
+        expected.move_cursor_to(0, i as u16).unwrap();
+        match hovered {
+            None => {
+                expected.set_attributes(Attributes::DIM).unwrap();
+                expected.write_all(line.as_bytes()).unwrap();
+                expected.set_attributes(Attributes::empty()).unwrap();
+            }
+            Some(true) => {
+                expected.write_all(b"  ").unwrap();
+                expected.write_styled(&line.cyan()).unwrap();
+            }
+            Some(false) => {
+                expected.write_all(b"  ").unwrap();
+                expected.write_all(line.as_bytes()).unwrap();
+            }
+        }
+    }
+    expected.move_cursor_to(0, 5).unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_selectable_indices() {
+    let list = List::new(single_line_vec(8))
+        .with_selectable(vec![false, true, true, false, false, true, false, true]);
+
+    assert_eq!(
+        super::selectable_indices(&list).collect::<Vec<_>>(),
+        vec![1, 2, 5, 7]
+    );
+    assert_eq!(
+        super::selectable_indices(&list).rev().collect::<Vec<_>>(),
+        vec![7, 5, 2, 1]
+    );
+
+    let list = List::new(single_line_vec(3)).with_selectable(vec![false; 3]);
+    assert_eq!(super::selectable_indices(&list).next(), None);
+}
+
+#[test]
+fn test_expand_hovered() {
+    use std::io::Write;
+
+    /// Every element takes a single line, except the hovered one which takes 3 lines
+    struct ExpandingList(usize);
+
+    impl super::List for ExpandingList {
+        fn render_item<B: Backend>(
+            &mut self,
+            index: usize,
+            _: bool,
+            _: Layout,
+            backend: &mut B,
+        ) -> io::Result<()> {
+            write!(backend, "{} list item", index)
+        }
+
+        fn render_expanded_item<B: Backend>(
+            &mut self,
+            index: usize,
+            layout: Layout,
+            backend: &mut B,
+        ) -> io::Result<()> {
+            write!(backend, "{} list item", index)?;
+            for line in 1..layout.max_height {
+                backend.move_cursor_to(layout.offset_x, layout.offset_y + line)?;
+                write!(backend, "  detail {}", line)?;
+            }
+            Ok(())
+        }
+
+        fn is_selectable(&self, _: usize) -> bool {
+            true
+        }
+
+        fn page_size(&self) -> usize {
+            10
+        }
+
+        fn should_loop(&self) -> bool {
+            true
+        }
+
+        fn height_at(&mut self, _: usize, _: Layout) -> u16 {
+            1
+        }
+
+        fn expanded_height_at(&mut self, _: usize, _: Layout) -> u16 {
+            3
+        }
+
+        fn len(&self) -> usize {
+            self.0
+        }
+    }
+
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+
+    let mut select = Select::new(ExpandingList(10)).with_expand_hovered(true);
+    let mut backend = TestBackend::new(size);
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    assert_eq!(select.height, 12);
+    assert_eq!((select.page_start, select.page_end), (0, 6));
+
+    let mut expected = TestBackend::new(size);
+    expected.write_all(b"0 list item").unwrap();
+    for line in 1..3 {
+        expected.move_cursor_to(0, line).unwrap();
+        write!(expected, "  detail {}", line).unwrap();
+    }
+    for i in 1..=6 {
+        expected.move_cursor_to(0, i as u16 + 2).unwrap();
+        write!(expected, "{} list item", i).unwrap();
+    }
+    expected.move_cursor_to(0, 9).unwrap();
+    expected
+        .write_styled(&"(Move up and down to reveal more choices)".dark_grey())
+        .unwrap();
+    expected.move_cursor_to(0, 10).unwrap();
+    backend.assert_eq(&expected);
+
+    // The page does not scroll while the expanded element fits in it
+    for at in 1..=5 {
+        assert!(select.handle_key(KeyCode::Down.into()));
+        assert_eq!(select.get_at(), at);
+        assert_eq!((select.page_start, select.page_end), (0, 6));
+    }
+    assert_eq!(select.page_start_height, 1);
+
+    // Expanding 6 pushes the elements after it out of the page, so it scrolls even though a
+    // list of single line elements would not
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!((select.page_start, select.page_end), (1, 7));
+    assert_eq!(select.page_end_height, 1);
+
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!((select.page_start, select.page_end), (1, 7));
+
+    select.set_at(1);
+    assert_eq!((select.page_start, select.page_end), (0, 6));
+    assert_eq!(select.heights.as_ref().unwrap().heights[1], 3);
+    assert_eq!(select.heights.as_ref().unwrap().heights[5], 1);
+    assert_eq!(select.height, 12);
+}
+
+#[test]
+fn test_inline_select() {
+    use std::io::Write;
+
+    let size = (100, 20).into();
+    // `? Color › ` is 10 columns wide
+    let layout = Layout::new(10, size);
+
+    let list = List::new(vec!["Red", "Green", "Blue", "Yellow"])
+        .with_selectable(vec![true, true, false, true]);
+    let mut select = InlineSelect::new(list);
+
+    let test = |select: &mut InlineSelect<List<&str>>, choice: &str| {
+        let mut backend = TestBackend::new_with_layout(size, layout);
+        let mut rendered_layout = layout;
+        select.render(&mut rendered_layout, &mut backend).unwrap();
+
+        let width = choice.len() as u16;
+        let mut expected = TestBackend::new_with_layout(size, layout);
+        expected.set_fg(Color::Cyan).unwrap();
+        expected.write_all(choice.as_bytes()).unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+        expected.move_cursor_to(10 + width, 0).unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(rendered_layout.line_offset, 10 + width);
+        assert_eq!(select.cursor_pos(layout), (10 + width, 0));
+        assert_eq!(select.list().vec[select.get_at()], choice);
+    };
+
+    test(&mut select, "Red");
+    assert!(select.handle_key(KeyCode::Right.into()));
+    test(&mut select, "Green");
+    // Blue cannot be selected, so it is skipped
+    assert!(select.handle_key(KeyCode::Right.into()));
+    assert_eq!(select.get_at(), 3);
+    test(&mut select, "Yellow");
+    assert!(select.handle_key(KeyCode::Right.into()));
+    test(&mut select, "Red");
+    assert!(select.handle_key(KeyCode::Left.into()));
+    test(&mut select, "Yellow");
+    assert!(!select.handle_key(KeyCode::Down.into()));
+}
+
+#[test]
+fn test_checkbox() {
+    use std::io::Write;
+
+    let size = (100, 20).into();
+    let layout = Layout::new(0, size);
+
+    let list = List::new(vec!["Header", "Red", "Green", "Blue"])
+        .with_selectable(vec![false, true, true, true]);
+    // The header cannot be checked
+    let mut checkbox = Checkbox::new(list).with_checked(&[0, 3]);
+    assert_eq!(checkbox.checked_indices(), vec![3]);
+
+    assert_eq!(checkbox.get_at(), 1);
+    assert!(checkbox.handle_key(KeyCode::Char(' ').into()));
+    assert!(checkbox.handle_key(KeyCode::Down.into()));
+    assert!(checkbox.handle_key(KeyCode::Down.into()));
+    assert!(checkbox.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(checkbox.checked_indices(), vec![1]);
+    assert!(checkbox.is_checked(1));
+    assert!(!checkbox.is_checked(3));
+
+    let mut backend = TestBackend::new(size);
+    checkbox.render(&mut layout.clone(), &mut backend).unwrap();
+
+    // The pointer comes before the marker, and the header has no marker
+    let mut expected = TestBackend::new(size);
+    for (i, (text, checked)) in [
+        ("Header", None),
+        ("Red", Some(true)),
+        ("Green", Some(false)),
+        ("Blue", Some(false)),
+    ]
+    .iter()
+    .enumerate()
+    {
+        let hovered = i == 3;
+        expected.move_cursor_to(0, i as u16).unwrap();
+        write_check_marker(hovered, *checked, &mut expected).unwrap();
+        if hovered {
+            expected.set_fg(Color::Cyan).unwrap();
+        }
+        expected.write_all(text.as_bytes()).unwrap();
+        if hovered {
+            expected.set_fg(Color::Reset).unwrap();
+        }
+        expected.set_fg(Color::Reset).unwrap();
+    }
+    expected.move_cursor_to(0, 4).unwrap();
+    backend.assert_eq(&expected);
+
+    let (list, checked) = checkbox.into_inner();
+    assert_eq!(list.vec.len(), 4);
+    assert_eq!(checked, vec![1]);
+}
+
+#[test]
+fn test_query_filter() {
+    let texts = vec!["Red", "Green", "Blue", "Grey", "Jade"];
+    let list = List::new(texts.clone());
+    let mut select =
+        Select::new(list).with_query_filter(texts.iter().map(|&text| text.to_owned()).collect());
+    select.height(&mut Layout::new(0, (100, 20).into()));
+
+    // Letters are typed into the query instead of moving
+    assert!(select.handle_key(KeyCode::Char('j').into()));
+    assert_eq!(select.query(), "j");
+    assert_eq!(select.get_at(), 4);
+    assert_eq!(select.len(), 1);
+
+    assert!(select.handle_key(KeyCode::Backspace.into()));
+    assert_eq!(select.query(), "");
+    assert!(!select.is_filtering());
+    assert_eq!(select.len(), 5);
+    assert!(!select.handle_key(KeyCode::Backspace.into()));
+
+    for c in "GE".chars() {
+        assert!(select.handle_key(KeyCode::Char(c).into()));
+    }
+    // Green and Grey match, ignoring case
+    assert_eq!(select.len(), 2);
+    assert_eq!(select.get_at(), 1);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 3);
+    assert_eq!(select.first_selectable, 0);
+    assert_eq!(select.last_selectable, 1);
+
+    assert!(select.handle_key(KeyCode::Char('x').into()));
+    assert!(select.is_empty());
+    assert!(!select.handle_key(KeyCode::Down.into()));
+
+    // Esc clears the whole query, and only does something if there is a query to clear
+    assert!(select.handle_key(KeyCode::Esc.into()));
+    assert_eq!(select.query(), "");
+    assert_eq!(select.len(), 5);
+    assert!(!select.handle_key(KeyCode::Esc.into()));
+
+    let mut select = Select::new(List::new(texts.clone()))
+        .with_query_filter(texts.iter().map(|&text| text.to_owned()).collect())
+        .with_query_matcher(|query, text| text.ends_with(query));
+    select.height(&mut Layout::new(0, (100, 20).into()));
+    assert!(select.handle_key(KeyCode::Char('e').into()));
+    assert_eq!(select.len(), 2);
+    assert_eq!(select.get_at(), 2);
+}
+
+#[test]
+fn test_type_ahead() {
+    let layout = Layout::new(0, (100, 20).into());
+    let texts = vec!["Apple", "Banana", "Blueberry", "Cherry", "Blackberry"];
+    let list = List::new(texts.clone()).with_selectable(vec![true, true, true, true, false]);
+    let mut select = Select::new(list)
+        .with_type_ahead(texts.iter().map(|&text| text.to_owned()).collect())
+        .with_bindings(crate::events::KeyBindings::arrows());
+    select.height(&mut layout.clone());
+
+    let type_key =
+        |select: &mut Select<List<&str>>, c: char| select.handle_key(KeyCode::Char(c).into());
+
+    assert!(type_key(&mut select, 'b'));
+    assert_eq!(select.get_at(), 1);
+    // A longer prefix keeps the hovered element if it still matches, and the case is ignored
+    assert!(type_key(&mut select, 'A'));
+    assert_eq!(select.get_at(), 1);
+    // Nothing starts with `bax`, so the `x` is dropped
+    assert!(!type_key(&mut select, 'x'));
+    assert!(type_key(&mut select, 'n'));
+    assert_eq!(select.get_at(), 1);
+
+    // The prefix is started again after a pause, and it moves on to the next match. Blackberry
+    // is not selectable, so it goes back around to Banana.
+    let paused = Instant::now().checked_sub(Duration::from_secs(5));
+    select.type_ahead.as_mut().unwrap().last_key = paused;
+    assert!(type_key(&mut select, 'b'));
+    assert_eq!(select.get_at(), 2);
+    select.type_ahead.as_mut().unwrap().last_key = paused;
+    assert!(type_key(&mut select, 'b'));
+    assert_eq!(select.get_at(), 1);
+
+    // Every element which matches is rendered with a hint, even if it is not selectable
+    let mut backend = TestBackend::new((100, 20).into());
+    select.render(&mut layout.clone(), &mut backend).unwrap();
+    assert_eq!(select.list.matched, vec![(1, 1), (2, 1), (4, 1)]);
+
+    // The movement keys still work
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 0);
+}
+
+#[test]
+fn test_should_loop_auto() {
+    let layout = Layout::new(0, (100, 20).into());
+
+    // Everything is visible, so the list does not loop
+    let mut select = Select::new(List::new(single_line_vec(5))).with_should_loop(ShouldLoop::Auto);
+    select.height(&mut layout.clone());
+
+    assert!(!select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 0);
+    select.set_at(4);
+    assert!(!select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 4);
+
+    // The list is paginated, so it loops even though `List::should_loop` is false
+    let mut select = Select::new(List::new(single_line_vec(20)).with_should_loop(false))
+        .with_should_loop(ShouldLoop::Auto);
+    select.height(&mut layout.clone());
+
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 19);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 0);
+}
+
+#[test]
+fn test_skip_policy() {
+    let layout = Layout::new(0, (100, 20).into());
+    let list = || {
+        List::new(single_line_vec(5))
+            .with_selectable(vec![false, true, true, false, true])
+            .with_should_loop(false)
+    };
+
+    let mut select = Select::new(list());
+    select.height(&mut layout.clone());
+
+    assert_eq!(select.get_at(), 1);
+    assert!(!select.handle_key(KeyCode::Up.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 4);
+
+    let mut select = Select::new(list()).with_skip_policy(SkipPolicy::Land);
+    select.height(&mut layout.clone());
+
+    // The header is not hovered at first, but it can be moved to
+    assert_eq!(select.get_at(), 1);
+    assert!(select.handle_key(KeyCode::Up.into()));
+    assert_eq!(select.get_at(), 0);
+    assert!(!select.handle_key(KeyCode::Up.into()));
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 3);
+    // It is up to the prompt to reject submitting the header
+    assert!(!super::List::is_selectable(&select.list, select.get_at()));
+
+    assert!(select.handle_key(KeyCode::End.into()));
+    assert_eq!(select.get_at(), 4);
+    assert!(select.handle_key(KeyCode::Home.into()));
+    assert_eq!(select.get_at(), 0);
+}
+
+#[test]
+fn test_select_with_preview() {
+    use std::io::Write;
+
+    let size = (40, 10).into();
+    let layout = Layout::new(0, size);
+
+    let list = List::new(vec!["first", "second", "third"]);
+    let mut select = SelectWithPreview::new(
+        list,
+        12,
+        Box::new(|i| format!("Preview of item {}\nwith a line that is too long to fit", i)),
+    );
+
+    let test = |select: &mut SelectWithPreview<List<&str>>, hovered: usize| {
+        let mut backend = TestBackend::new(size);
+        let mut rendered_layout = layout;
+        select.render(&mut rendered_layout, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        for (i, item) in ["first", "second", "third"].iter().enumerate() {
+            expected.move_cursor_to(0, i as u16).unwrap();
+            if i == hovered {
+                expected.set_fg(Color::Cyan).unwrap();
+            }
+            expected.write_all(item.as_bytes()).unwrap();
+            expected.set_fg(Color::Reset).unwrap();
+        }
+        // The preview starts after the list and the column separating them
+        let preview = format!("Preview of item {}", hovered);
+        let lines = [&*preview, "with a line that is too", "long to fit"];
+        for (i, line) in lines.iter().enumerate() {
+            expected.move_cursor_to(13, i as u16).unwrap();
+            expected.write_all(line.as_bytes()).unwrap();
+        }
+        expected.move_cursor_to(0, 3).unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(rendered_layout, layout.with_offset(0, 3));
+        assert_eq!(select.height(&mut layout.clone()), 3);
+    };
+
+    test(&mut select, 0);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 1);
+    test(&mut select, 1);
+    assert!(select.handle_key(KeyCode::End.into()));
+    test(&mut select, 2);
+}
+
+#[test]
+fn test_help() {
+    use std::io::Write;
+
+    let size = (20, 10).into();
+    let layout = Layout::new(0, size);
+
+    let list = List::new(vec!["first", "second", "third"]).with_help(vec![
+        Some("The first item"),
+        None,
+        Some("The third item, which has a longer help"),
+    ]);
+    let mut select = Select::new(list);
+
+    let test = |select: &mut Select<List<&str>>, hovered: usize, help: &[&str]| {
+        let mut backend = TestBackend::new(size);
+        let mut rendered_layout = layout;
+        select.render(&mut rendered_layout, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        for (i, item) in ["first", "second", "third"].iter().enumerate() {
+            expected.move_cursor_to(0, i as u16).unwrap();
+            if i == hovered {
+                expected.set_fg(Color::Cyan).unwrap();
+            }
+            expected.write_all(item.as_bytes()).unwrap();
+            expected.set_fg(Color::Reset).unwrap();
+        }
+        for (i, line) in help.iter().enumerate() {
+            expected.move_cursor_to(0, 3 + i as u16).unwrap();
+            expected.set_attributes(Attributes::DIM).unwrap();
+            expected.write_all(line.as_bytes()).unwrap();
+            expected.set_attributes(Attributes::empty()).unwrap();
+        }
+        let height = 3 + help.len() as u16;
+        expected.move_cursor_to(0, height).unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(rendered_layout, layout.with_offset(0, height));
+        assert_eq!(select.height(&mut layout.clone()), height);
+    };
+
+    test(&mut select, 0, &["The first item"]);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    test(&mut select, 1, &[]);
+    assert!(select.handle_key(KeyCode::Down.into()));
+    test(
+        &mut select,
+        2,
+        &["The third item,", "which has a longer", "help"],
+    );
+}
+
+#[test]
+fn test_filter_step() {
+    use std::{cell::Cell, io::Write};
+
+    let size = (20, 10).into();
+    let layout = Layout::new(0, size);
+
+    let items = vec!["apple", "banana", "avocado", "cherry", "apricot"];
+    let mut select = Select::new(List::new(items.clone()));
+
+    let checked = Cell::new(0);
+    let predicate = |i: usize| {
+        checked.set(checked.get() + 1);
+        items[i].starts_with('a')
+    };
+    select.start_filter();
+
+    let test = |select: &mut Select<List<&str>>, shown: &[&str], hovered: usize| {
+        let mut backend = TestBackend::new(size);
+        let mut rendered_layout = layout;
+        select.render(&mut rendered_layout, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        for (i, item) in shown.iter().enumerate() {
+            expected.move_cursor_to(0, i as u16).unwrap();
+            if i == hovered {
+                expected.set_fg(Color::Cyan).unwrap();
+            }
+            expected.write_all(item.as_bytes()).unwrap();
+            expected.set_fg(Color::Reset).unwrap();
+        }
+        let mut height = shown.len() as u16;
+        expected.move_cursor_to(0, height).unwrap();
+        if select.is_filtering() {
+            expected.write_styled(&"Filtering…".dark_grey()).unwrap();
+            height += 1;
+            expected.move_cursor_to(0, height).unwrap();
+        }
+
+        backend.assert_eq(&expected);
+        assert_eq!(rendered_layout, layout.with_offset(0, height));
+        assert_eq!(select.height(&mut layout.clone()), height);
+    };
+
+    // Nothing has been checked yet
+    assert!(select.is_filtering());
+    assert_eq!(checked.get(), 0);
+    test(&mut select, &[], 0);
+    assert!(!select.handle_key(KeyCode::Down.into()));
+
+    assert!(select.filter_step(2, predicate));
+    assert_eq!(checked.get(), 2);
+    test(&mut select, &["apple"], 0);
+
+    assert!(!select.filter_step(10, predicate));
+    assert_eq!(checked.get(), 5);
+    assert!(!select.is_filtering());
+    test(&mut select, &["apple", "avocado", "apricot"], 0);
+
+    assert!(select.handle_key(KeyCode::Down.into()));
+    assert_eq!(select.get_at(), 2);
+
+    // The hovered element stays hovered once everything is shown again
+    select.clear_filter();
+    assert!(!select.filter_step(10, predicate));
+    assert_eq!(select.get_at(), 2);
+    test(
+        &mut select,
+        &["apple", "banana", "avocado", "cherry", "apricot"],
+        2,
+    );
+}
+
+#[test]
+fn test_query_filter_tick() {
+    let texts: Vec<String> = (0..2500).map(|i| format!("item {}", i)).collect();
+    let mut select = Select::new(List::new(texts.clone())).with_query_filter(texts);
+    select.height(&mut Layout::new(0, (100, 20).into()));
+
+    // Only the first part of a long list is filtered when the query changes
+    assert!(select.handle_key(KeyCode::Char('9').into()));
+    assert!(select.is_filtering());
+    let len = select.len();
+
+    assert!(select.tick());
+    assert!(select.len() > len);
+    assert!(select.tick());
+    assert!(!select.is_filtering());
+    assert!(!select.tick());
+
+    // Every item with a 9 in it is shown
+    assert_eq!(
+        select.len(),
+        (0..2500).filter(|i| i.to_string().contains('9')).count()
+    );
+}
+
+#[test]
+#[should_panic(expected = "no element is hovered")]
+fn test_get_at_empty_filter() {
+    let mut select = Select::new(List::new(vec!["a", "b"]));
+    select.start_filter();
+    select.filter_step(usize::MAX, |_| false);
+
+    assert!(select.is_empty());
+    select.get_at();
+}
@@ -0,0 +1,194 @@
+use std::{
+    io,
+    ops::{Index, IndexMut},
+};
+
+use super::{List, Select};
+use crate::{backend::Backend, events::KeyEvent, layout::Layout, widgets::Text, Widget};
+
+/// A widget to select a single item from a list, with a preview of the hovered item shown next to
+/// the list.
+///
+/// The list is rendered as a [`Select`] in the first `list_width` columns, and the preview is
+/// rendered as a [`Text`] in the rest of the columns, leaving a single column between them. All
+/// keys are handled by the list. The preview is the text returned by the `preview` function for
+/// the index of the hovered item, and is recomputed whenever a different item is hovered.
+///
+/// The widget is as tall as the taller of the list and the preview, and the preview is cut short
+/// if it does not fit in the `max_height` of the layout.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{DescribedList, SelectWithPreview};
+///
+/// let commits = vec![
+///     ("3f2a1c", "Fix the build on windows"),
+///     ("9b8e7d", "Add a changelog"),
+/// ];
+///
+/// let list = DescribedList::new(commits.clone(), 8);
+/// let select = SelectWithPreview::new(
+///     list,
+///     40,
+///     Box::new(move |index| format!("commit {}\n\n{}", commits[index].0, commits[index].1)),
+/// );
+/// assert_eq!(select.get_at(), 0);
+/// ```
+pub struct SelectWithPreview<L> {
+    select: Select<L>,
+    list_width: u16,
+    preview: Box<dyn Fn(usize) -> String>,
+    // The rendered preview, along with the index of the item it is for
+    text: Option<(usize, Text<String>)>,
+}
+
+impl<L: std::fmt::Debug> std::fmt::Debug for SelectWithPreview<L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SelectWithPreview")
+            .field("at", &self.select.at)
+            .field("list_width", &self.list_width)
+            .field("list", &self.select.list)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: List> SelectWithPreview<L> {
+    /// Creates a new [`SelectWithPreview`], with the list taking `list_width` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no selectable items, or if `list.page_size()` is less than 5.
+    pub fn new(list: L, list_width: u16, preview: Box<dyn Fn(usize) -> String>) -> Self {
+        Self {
+            select: Select::new(list),
+            list_width,
+            preview,
+            text: None,
+        }
+    }
+
+    /// The index of the element that is currently being hovered.
+    pub fn get_at(&self) -> usize {
+        self.select.get_at()
+    }
+
+    /// Set the index of the element that is currently being hovered.
+    ///
+    /// The caller is responsible for making sure that it is a selectable element.
+    pub fn set_at(&mut self, at: usize) {
+        self.select.set_at(at);
+    }
+
+    /// The underlying list.
+    pub fn list(&self) -> &L {
+        &self.select.list
+    }
+
+    /// A mutable reference to the underlying list.
+    pub fn list_mut(&mut self) -> &mut L {
+        &mut self.select.list
+    }
+
+    /// Consumes the [`SelectWithPreview`] returning the original list.
+    pub fn into_inner(self) -> L {
+        self.select.list
+    }
+
+    /// Recomputes the preview if a different item is hovered than the one it was computed for.
+    fn update_preview(&mut self) -> &mut Text<String> {
+        let at = self.select.get_at();
+
+        match self.text {
+            Some((index, _)) if index == at => {}
+            _ => self.text = Some((at, Text::new((self.preview)(at)))),
+        }
+
+        &mut self.text.as_mut().expect("the preview was just set").1
+    }
+
+    /// Splits the layout into the layouts for the list and the preview.
+    fn split(&self, layout: Layout) -> (Layout, Layout) {
+        let (list_layout, mut preview_layout) = layout.split_h(self.list_width);
+
+        if preview_layout.available_width() > 0 {
+            preview_layout.offset_x += 1;
+        }
+
+        (list_layout, preview_layout)
+    }
+}
+
+impl<L: Index<usize>> SelectWithPreview<L> {
+    /// Returns a reference to the currently hovered item.
+    pub fn selected(&self) -> &L::Output {
+        self.select.selected()
+    }
+}
+
+impl<L: IndexMut<usize>> SelectWithPreview<L> {
+    /// Returns a mutable reference to the currently hovered item.
+    pub fn selected_mut(&mut self) -> &mut L::Output {
+        self.select.selected_mut()
+    }
+}
+
+impl<L: List> Widget for SelectWithPreview<L> {
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.select.handle_key(key)
+    }
+
+    fn render<B: Backend>(&mut self, layout: &mut Layout, b: &mut B) -> io::Result<()> {
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+        }
+
+        let (mut list_layout, mut preview_layout) = self.split(*layout);
+
+        self.select.render(&mut list_layout, b)?;
+
+        b.move_cursor_to(preview_layout.offset_x, preview_layout.offset_y)?;
+        if preview_layout.available_width() > 0 {
+            self.update_preview().render(&mut preview_layout, b)?;
+        }
+
+        layout.offset_y = list_layout.offset_y.max(preview_layout.offset_y);
+        b.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+
+    /// Returns the starting location of the layout. It should not be relied upon for a sensible
+    /// cursor position.
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        layout.offset_cursor((layout.line_offset, 0))
+    }
+
+    /// A list has no text to edit, so it does not need a cursor.
+    fn wants_cursor(&self) -> bool {
+        false
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        let mut height = 0;
+        if layout.line_offset != 0 {
+            layout.line_offset = 0;
+            layout.offset_y += 1;
+            height += 1;
+        }
+
+        let (mut list_layout, mut preview_layout) = self.split(*layout);
+
+        let list_height = self.select.height(&mut list_layout);
+        let preview_height = if preview_layout.available_width() > 0 {
+            self.update_preview().height(&mut preview_layout)
+        } else {
+            0
+        };
+
+        let content_height = list_height.max(preview_height);
+        layout.offset_y += content_height;
+
+        height + content_height
+    }
+}
@@ -0,0 +1,158 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::KeyEvent,
+    layout::Layout,
+    widgets::{self, Widget},
+    Prompt, Validation,
+};
+
+/// A [`Prompt`] made from a message and any [`Widget`] to enter the value.
+///
+/// The message is rendered like the built-in prompts, with the widget right after it. Keys are
+/// given to the widget. When the prompt is submitted, `validate` is called with the widget, and
+/// the error it returns is shown below the prompt. Once it passes, `finish` turns the widget into
+/// the value returned from [`Input::run`].
+///
+/// This makes it possible to ask for a value with a one-off widget, without implementing
+/// [`Widget`] and [`Prompt`] for a new type.
+///
+/// [`Input::run`]: crate::Input::run
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{
+///     backend::TestBackend,
+///     events::{KeyCode, TestEvents},
+///     widgets::{SimplePrompt, StringInput},
+///     Input, Validation,
+/// };
+///
+/// let prompt = SimplePrompt::new(
+///     "What is your name?",
+///     StringInput::new(),
+///     |input: &StringInput| {
+///         if input.value().is_empty() {
+///             Err("Please enter a name".to_owned())
+///         } else {
+///             Ok(Validation::Finish)
+///         }
+///     },
+///     |input: StringInput| input.finish(),
+/// )
+/// .with_hint("first name only");
+///
+/// let mut backend = TestBackend::new((50, 10).into());
+/// let mut events = TestEvents::new(vec![
+///     KeyCode::Enter.into(), // fails validation, since nothing has been entered
+///     KeyCode::Char('J').into(),
+///     KeyCode::Char('o').into(),
+///     KeyCode::Enter.into(),
+/// ]);
+///
+/// let name = Input::new(prompt, &mut backend).run(&mut events).unwrap();
+/// assert_eq!(name.as_deref(), Some("Jo"));
+/// ```
+pub struct SimplePrompt<M, W, V, F> {
+    prompt: widgets::Prompt<M>,
+    widget: W,
+    validate: V,
+    finish: F,
+}
+
+impl<M: std::fmt::Debug, W: std::fmt::Debug, V, F> std::fmt::Debug for SimplePrompt<M, W, V, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimplePrompt")
+            .field("prompt", &self.prompt)
+            .field("widget", &self.widget)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M, W, V, F, T> SimplePrompt<M, W, V, F>
+where
+    M: AsRef<str>,
+    W: Widget,
+    V: FnMut(&W) -> Result<Validation, String>,
+    F: FnOnce(W) -> T,
+{
+    /// Creates a new `SimplePrompt`.
+    pub fn new(message: M, widget: W, validate: V, finish: F) -> Self {
+        Self {
+            prompt: widgets::Prompt::new(message),
+            widget,
+            validate,
+            finish,
+        }
+    }
+
+    /// Sets the hint shown after the message, for example to show the default value.
+    pub fn with_hint(mut self, hint: &'static str) -> Self {
+        self.prompt = self.prompt.with_hint(hint);
+        self
+    }
+
+    /// The widget used to enter the value.
+    pub fn widget(&self) -> &W {
+        &self.widget
+    }
+
+    /// A mutable reference to the widget used to enter the value.
+    pub fn widget_mut(&mut self) -> &mut W {
+        &mut self.widget
+    }
+}
+
+impl<M: AsRef<str>, W: Widget, V, F> Widget for SimplePrompt<M, W, V, F> {
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        self.prompt.render(layout, backend)?;
+        self.widget.render(layout, backend)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        // The widget starts on the last line of the prompt
+        self.prompt.height(layout) - 1 + self.widget.height(layout)
+    }
+
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        let prompt_end = self.prompt.cursor_pos(layout);
+        self.widget.cursor_pos(layout.with_cursor_pos(prompt_end))
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        self.widget.handle_key(key)
+    }
+
+    fn wants_cursor(&self) -> bool {
+        self.widget.wants_cursor()
+    }
+}
+
+impl<M, W, V, F, T> Prompt for SimplePrompt<M, W, V, F>
+where
+    M: AsRef<str>,
+    W: Widget,
+    V: FnMut(&W) -> Result<Validation, String>,
+    F: FnOnce(W) -> T,
+{
+    type ValidateErr = String;
+    type Output = T;
+
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        (self.validate)(&self.widget)
+    }
+
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
+        (self.validate)(&self.widget).map(|_| ())
+    }
+
+    fn finish(self) -> Self::Output {
+        (self.finish)(self.widget)
+    }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message().as_ref())
+    }
+}
@@ -1,4 +1,6 @@
 use std::{
+    borrow::Cow,
+    collections::VecDeque,
     io::{self, Write},
     ops::Range,
 };
@@ -6,9 +8,11 @@ use std::{
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
-    backend::Backend,
+    backend::{Backend, MoveDirection},
     events::{KeyCode, KeyEvent, KeyModifiers, Movement},
     layout::Layout,
+    style::Color,
+    text::{wrap_lines_from, WrapMode},
 };
 
 /// A widget that inputs a string.
@@ -18,19 +22,78 @@ use crate::{
 ///
 /// If only a single character is required, use [`CharInput`].
 ///
+/// `Ctrl+Left` and `Ctrl+Right` move the cursor by words, and `Ctrl+W` or `Ctrl+Backspace` delete
+/// the word before the cursor. Runs of whitespace and punctuation are not part of any word.
+///
+/// Text deleted with `Ctrl+U` (to the start) or `Ctrl+K` (to the end) is kept in a kill buffer,
+/// and can be inserted back at the cursor with `Ctrl+Y`. Only the most recently deleted text is
+/// kept, unless [`with_emacs_keys`] is set.
+///
+/// `Ctrl+Z` undoes the last edit, and `Ctrl+Shift+Z` redoes it. `Ctrl+Y` also redoes an undone
+/// edit if there is one, and otherwise inserts the kill buffer. Consecutive characters typed
+/// without moving the cursor are undone together.
+///
+/// [`with_emacs_keys`]: StringInput::with_emacs_keys
 /// [`CharInput`]: crate::widgets::CharInput
 #[derive(Debug, Clone)]
 pub struct StringInput<F = super::widgets::FilterMapChar> {
     value: String,
+    /// The value the input started with, to compare against in `is_dirty`
+    initial: String,
     mask: Option<char>,
     hide_output: bool,
+    multiline: bool,
+    value_color: Option<Color>,
+    max_len: Option<usize>,
+    scroll_markers: bool,
+    /// The first character shown when the value scrolls horizontally
+    scroll: usize,
     /// The character length of the string
     value_len: usize,
     /// The position of the 'cursor' in characters
     at: usize,
+    /// The text which has been killed, with the most recent at the back
+    kill_ring: VecDeque<String>,
+    emacs_keys: bool,
+    /// The text inserted by the last key if it was a yank, so it can be replaced by `Alt+Y`
+    last_yank: Option<Yank>,
+    /// The states before each edit that can be undone, with the most recent at the back
+    undo_stack: VecDeque<Snapshot>,
+    /// The states undone with `Ctrl+Z`, with the most recently undone at the back
+    redo_stack: Vec<Snapshot>,
+    history_depth: usize,
+    /// Whether the last edit typed a character, so that the next typed character is part of the
+    /// same undo entry
+    is_typing: bool,
     filter_map: F,
 }
 
+/// Shown when some of the value is hidden to the left with [`StringInput::with_scroll_markers`].
+const SCROLL_LEFT: char = '‹';
+/// Shown when some of the value is hidden to the right with [`StringInput::with_scroll_markers`].
+const SCROLL_RIGHT: char = '›';
+
+/// The most kills that are kept with [`StringInput::with_emacs_keys`].
+const KILL_RING_SIZE: usize = 8;
+
+/// Text inserted from the kill ring.
+#[derive(Debug, Clone, Copy)]
+struct Yank {
+    /// The character index the text starts at
+    at: usize,
+    /// The length of the text in characters
+    len: usize,
+    /// How many kills before the most recent one the text is from
+    kill: usize,
+}
+
+/// The value and 'cursor' of a [`StringInput`] at some point, which can be restored.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    value: String,
+    at: usize,
+}
+
 impl StringInput {
     /// Creates a new [`StringInput`] which accepts all characters.
     pub fn new() -> Self {
@@ -43,14 +106,41 @@ impl<F> StringInput<F> {
     pub fn with_filter_map(filter_map: F) -> Self {
         Self {
             value: String::new(),
+            initial: String::new(),
             value_len: 0,
             at: 0,
+            kill_ring: VecDeque::new(),
+            emacs_keys: false,
+            last_yank: None,
+            undo_stack: VecDeque::new(),
+            redo_stack: Vec::new(),
+            history_depth: 100,
+            is_typing: false,
             filter_map,
             mask: None,
             hide_output: false,
+            multiline: false,
+            value_color: None,
+            max_len: None,
+            scroll_markers: false,
+            scroll: 0,
         }
     }
 
+    /// The value the input starts with, with the 'cursor' at its end.
+    ///
+    /// The value can be edited like anything that was typed, and [`is_dirty`] compares the
+    /// current value to it.
+    ///
+    /// [`is_dirty`]: StringInput::is_dirty
+    pub fn with_initial_value(mut self, value: String) -> Self {
+        self.value_len = value.chars().count();
+        self.at = self.value_len;
+        self.initial = value.clone();
+        self.value = value;
+        self
+    }
+
     /// A mask to render instead of the actual characters.
     ///
     /// This is useful for passwords.
@@ -67,6 +157,86 @@ impl<F> StringInput<F> {
         self
     }
 
+    /// Whether `Enter` should insert a newline instead of being ignored.
+    ///
+    /// Each line after the first starts at the beginning of the terminal line. This has no effect on
+    /// how a masked or hidden value is rendered.
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// The colour to render the value in as it is typed.
+    ///
+    /// The value is rendered in the default colour if this is not set. A masked value is rendered
+    /// in this colour as well.
+    pub fn with_value_color(mut self, color: Color) -> Self {
+        self.value_color = Some(color);
+        self
+    }
+
+    /// The maximum number of characters the value can have.
+    ///
+    /// Characters are counted as graphemes, so an emoji made of multiple code points counts as a
+    /// single character. Once the value is full, typed characters are ignored unless they join an
+    /// existing grapheme, such as a combining mark, and text inserted with `Ctrl+Y` is cut short
+    /// to fit. A value set with [`set_value`] is not cut short.
+    ///
+    /// [`set_value`]: StringInput::set_value
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Whether a value that is too wide should scroll horizontally instead of wrapping.
+    ///
+    /// The value is kept on the rest of the current line, and scrolls to keep the cursor in view.
+    /// A dim `‹` or `›` is shown at an edge where some of the value is hidden. This has no effect
+    /// on multiline or hidden values.
+    ///
+    /// If this is not set, it defaults to `false`.
+    pub fn with_scroll_markers(mut self, scroll_markers: bool) -> Self {
+        self.scroll_markers = scroll_markers;
+        self
+    }
+
+    /// Whether to keep more than one kill, like readline does with emacs keys.
+    ///
+    /// `Ctrl+A`, `Ctrl+E`, `Ctrl+K`, `Ctrl+U` and `Ctrl+D` are always available. When this is set:
+    /// - The last few kills are kept in a kill ring instead of only the most recent one.
+    /// - Words deleted with `Ctrl+W`, `Alt+Backspace` or `Alt+D` are killed as well.
+    /// - `Ctrl+Y` always inserts the most recent kill, and never redoes an undone edit.
+    /// - `Alt+Y` right after `Ctrl+Y` replaces the inserted text with the kill before it, cycling
+    ///   through the kill ring.
+    ///
+    /// If this is not set, it defaults to `false`.
+    pub fn with_emacs_keys(mut self, emacs_keys: bool) -> Self {
+        self.emacs_keys = emacs_keys;
+        self
+    }
+
+    /// The number of edits which can be undone.
+    ///
+    /// Once there are more edits, the oldest ones can no longer be undone. A depth of `0` disables
+    /// undo. If this is not set, it defaults to 100.
+    pub fn with_history_depth(mut self, history_depth: usize) -> Self {
+        self.history_depth = history_depth;
+        self
+    }
+
+    /// Whether the value has as many characters as the limit set with [`with_max_len`]. This is
+    /// always `false` if there is no limit.
+    ///
+    /// [`with_max_len`]: StringInput::with_max_len
+    pub fn is_full(&self) -> bool {
+        matches!(self.max_len, Some(max_len) if self.grapheme_len() >= max_len)
+    }
+
+    /// The number of graphemes in the value
+    fn grapheme_len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
     /// A helper that sets mask if mask is some, otherwise hides the output
     pub fn password(self, mask: Option<char>) -> Self {
         match mask {
@@ -90,15 +260,34 @@ impl<F> StringInput<F> {
         &self.value
     }
 
+    /// Whether the value is different from the one it started with, which is empty unless set
+    /// with [`with_initial_value`]. Editing the value back to what it started with makes it no
+    /// longer dirty.
+    ///
+    /// [`with_initial_value`]: StringInput::with_initial_value
+    pub fn is_dirty(&self) -> bool {
+        self.value != self.initial
+    }
+
     /// Sets the value
+    ///
+    /// This is not recorded as an edit which can be undone, but it stops the characters typed
+    /// after it from being undone together with the ones typed before it, and discards the edits
+    /// which can be redone.
     pub fn set_value(&mut self, value: String) {
+        self.set_externally();
         self.value_len = value.chars().count();
         self.value = value;
         self.set_at(self.at);
     }
 
     /// Replaces the value with the result of the function
+    ///
+    /// Like [`set_value`], this is not recorded as an edit which can be undone.
+    ///
+    /// [`set_value`]: StringInput::set_value
     pub fn replace_with<W: FnOnce(String) -> String>(&mut self, with: W) {
+        self.set_externally();
         self.value = with(std::mem::take(&mut self.value));
         let old_len = self.value_len;
         self.value_len = self.value.chars().count();
@@ -109,6 +298,223 @@ impl<F> StringInput<F> {
         }
     }
 
+    /// Resets the value to the one set with [`with_initial_value`], or clears it if there was
+    /// none, and moves the 'cursor' to its end.
+    ///
+    /// [`with_initial_value`]: StringInput::with_initial_value
+    pub fn reset(&mut self) {
+        self.set_externally();
+        self.value.clone_from(&self.initial);
+        self.value_len = self.value.chars().count();
+        self.at = self.value_len;
+    }
+
+    /// The text last deleted with `Ctrl+U` or `Ctrl+K`, which is inserted with `Ctrl+Y`.
+    pub fn kill_buffer(&self) -> &str {
+        self.kill_ring.back().map_or("", String::as_str)
+    }
+
+    /// Keeps the deleted `text` in the kill ring, dropping the oldest kill if it is full.
+    fn kill(&mut self, text: String) {
+        let size = if self.emacs_keys { KILL_RING_SIZE } else { 1 };
+        if self.kill_ring.len() == size {
+            self.kill_ring.pop_front();
+        }
+
+        self.kill_ring.push_back(text);
+    }
+
+    /// Reverts the value and 'cursor' to how they were before the last edit.
+    ///
+    /// Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        match self.undo_stack.pop_back() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.redo_stack.push(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Applies the edit last reverted with [`undo`] again. Any new edit discards the edits which
+    /// can be redone.
+    ///
+    /// Returns `false` if there is nothing to redo.
+    ///
+    /// [`undo`]: StringInput::undo
+    pub fn redo(&mut self) -> bool {
+        match self.redo_stack.pop() {
+            Some(snapshot) => {
+                let current = self.restore(snapshot);
+                self.undo_stack.push_back(current);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Called when the value is changed other than by a key, so that the change is not mixed up with
+    /// the edits in the history.
+    fn set_externally(&mut self) {
+        self.is_typing = false;
+        self.last_yank = None;
+        self.redo_stack.clear();
+    }
+
+    /// Replaces the value and 'cursor' with the `snapshot`, returning the current ones.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        self.is_typing = false;
+        self.last_yank = None;
+        self.value_len = snapshot.value.chars().count();
+
+        Snapshot {
+            value: std::mem::replace(&mut self.value, snapshot.value),
+            at: std::mem::replace(&mut self.at, snapshot.at),
+        }
+    }
+
+    /// Records the current state so that the edit about to be made can be undone. A typed
+    /// character is undone together with the characters typed just before it.
+    fn record_edit(&mut self, is_typing: bool) {
+        self.redo_stack.clear();
+
+        if !(is_typing && self.is_typing) && self.history_depth > 0 {
+            if self.undo_stack.len() == self.history_depth {
+                self.undo_stack.pop_front();
+            }
+
+            self.undo_stack.push_back(Snapshot {
+                value: self.value.clone(),
+                at: self.at,
+            });
+        }
+
+        self.is_typing = is_typing;
+    }
+
+    /// The text `kill` kills before the most recent one, cut short to fit in the `max_len`.
+    fn fitting_kill(&self, kill: usize) -> &str {
+        let text = match self.kill_ring.iter().rev().nth(kill) {
+            Some(text) => text,
+            None => return "",
+        };
+
+        match self.max_len {
+            Some(max_len) => {
+                let room = max_len.saturating_sub(self.grapheme_len());
+                let end = text
+                    .grapheme_indices(true)
+                    .nth(room)
+                    .map_or(text.len(), |(i, _)| i);
+                &text[..end]
+            }
+            None => text,
+        }
+    }
+
+    /// Inserts the text `kill` kills before the most recent one at the 'cursor', and moves the
+    /// 'cursor' to after it.
+    fn insert_kill(&mut self, kill: usize) {
+        let text = self.fitting_kill(kill).to_owned();
+
+        let byte_i = self.get_byte_i(self.at);
+        self.value.insert_str(byte_i, &text);
+
+        let len = text.chars().count();
+        self.last_yank = Some(Yank {
+            at: self.at,
+            len,
+            kill,
+        });
+        self.at += len;
+        self.value_len += len;
+    }
+
+    /// Inserts the kill buffer at the 'cursor', and moves the 'cursor' to after it. Only as much
+    /// of the kill buffer as fits in the `max_len` is inserted.
+    ///
+    /// Returns `false` if nothing was inserted.
+    fn yank(&mut self) -> bool {
+        if self.fitting_kill(0).is_empty() {
+            return false;
+        }
+
+        self.record_edit(false);
+        self.insert_kill(0);
+        true
+    }
+
+    /// Replaces the text inserted by the last yank with the kill before it in the kill ring,
+    /// going back to the most recent kill after the oldest one.
+    ///
+    /// Returns `false` if there is no other kill to replace it with.
+    fn yank_pop(&mut self, yank: Yank) -> bool {
+        let kill = (yank.kill + 1) % self.kill_ring.len();
+        if kill == yank.kill {
+            self.last_yank = Some(yank);
+            return false;
+        }
+
+        self.record_edit(false);
+
+        let start = self.get_byte_i(yank.at);
+        let end = self.get_byte_i(yank.at + yank.len);
+        self.value.replace_range(start..end, "");
+        self.value_len -= yank.len;
+        self.at = yank.at;
+
+        self.insert_kill(kill);
+        true
+    }
+
+    /// Inserts `text` at the 'cursor' as a single edit, and moves the 'cursor' to after it. This
+    /// is how pasted text is inserted, so that it is undone all at once.
+    ///
+    /// Each character goes through the `filter_map` like a typed character would. Line breaks are
+    /// only kept if the input is [`multiline`], and other control characters are dropped. Only as
+    /// much of the text as fits in the `max_len` is inserted.
+    ///
+    /// Returns `false` if nothing was inserted.
+    ///
+    /// [`multiline`]: StringInput::multiline
+    pub fn insert_str(&mut self, text: &str) -> bool
+    where
+        F: Fn(char) -> Option<char>,
+    {
+        let mut filtered: String = text
+            .replace("\r\n", "\n")
+            .chars()
+            .filter_map(|c| match c {
+                '\n' | '\r' if self.multiline => Some('\n'),
+                c if c.is_control() => None,
+                c => (self.filter_map)(c),
+            })
+            .collect();
+
+        if let Some(max_len) = self.max_len {
+            let room = max_len.saturating_sub(self.grapheme_len());
+            if let Some((end, _)) = filtered.grapheme_indices(true).nth(room) {
+                filtered.truncate(end);
+            }
+        }
+
+        if filtered.is_empty() {
+            return false;
+        }
+
+        self.record_edit(false);
+
+        let byte_i = self.get_byte_i(self.at);
+        self.value.insert_str(byte_i, &filtered);
+
+        let len = filtered.chars().count();
+        self.at += len;
+        self.value_len += len;
+        true
+    }
+
     /// Returns the inputted string
     pub fn finish(self) -> String {
         self.value
@@ -131,11 +537,13 @@ impl<F> StringInput<F> {
             .unwrap_or_else(|| self.value.char_indices().count())
     }
 
-    /// Get the word bound iterator for a given range
+    /// Get the word bound iterator for a given range. Runs of whitespace and punctuation are not
+    /// words, so they are skipped over when moving by words.
     fn word_iter(&self, r: Range<usize>) -> impl DoubleEndedIterator<Item = (usize, &str)> {
-        self.value[r]
-            .split_word_bound_indices()
-            .filter(|(_, s)| !s.chars().next().map(char::is_whitespace).unwrap_or(true))
+        self.value[r].split_word_bound_indices().filter(|(_, s)| {
+            !s.chars()
+                .all(|c| c.is_whitespace() || c.is_ascii_punctuation())
+        })
     }
 
     /// Returns the byte index of the start of the first word to the left (< byte_i)
@@ -154,17 +562,123 @@ impl<F> StringInput<F> {
             .unwrap_or_else(|| self.value.len())
     }
 
+    /// The text that is rendered for the first `len` characters of the value
+    fn rendered_text(&self, len: usize) -> Cow<'_, str> {
+        match self.mask {
+            // The mask is printed once for each character, including newlines
+            Some(mask) => Cow::Owned(mask.to_string().repeat(len)),
+            None => Cow::Borrowed(&self.value[..self.get_byte_i(len)]),
+        }
+    }
+
+    /// The range of characters shown if the value scrolls horizontally, or `None` if all of it is
+    /// shown. The scroll only moves as much as needed to keep the 'cursor' in view.
+    fn scroll_window(&mut self, layout: Layout) -> Option<Range<usize>> {
+        if !self.scroll_markers || self.multiline || self.hide_output {
+            return None;
+        }
+
+        let widths: Vec<_> = self
+            .rendered_text(self.value_len)
+            .chars()
+            .map(|c| textwrap::core::display_width(c.encode_utf8(&mut [0; 4])))
+            .collect();
+        let len = widths.len();
+        let width = layout.line_width() as usize;
+
+        // The last cell is always kept for the cursor
+        if widths.iter().sum::<usize>() < width {
+            self.scroll = 0;
+            return None;
+        }
+
+        // The room for the value when it is shown from `start`, which needs a cell for the marker
+        // on the left if some of it is hidden there
+        let room = |start: usize| width.saturating_sub(if start > 0 { 2 } else { 1 });
+        let at = self.at;
+
+        // The first start from which the 'cursor' is still shown. Either the rest of the value
+        // fits, or everything up to the 'cursor' fits along with the marker on the right. The
+        // widths up to both are added up as the start moves left.
+        let mut first = at;
+        let mut to_end: usize = widths[at..].iter().sum();
+        let mut to_cursor = widths.get(at).copied().unwrap_or(0);
+        while first > 0 {
+            let w = widths[first - 1];
+            let room = room(first - 1);
+            if to_end + w > room && (at == len || to_cursor + w >= room) {
+                break;
+            }
+
+            first -= 1;
+            to_end += w;
+            to_cursor += w;
+        }
+
+        // The scroll only moves right as far as needed to show the 'cursor'
+        let mut start = self.scroll.min(at).max(first);
+        let mut to_end: usize = widths[start..].iter().sum();
+        // Show as much of the value as fits when its end is in view, for example after deleting
+        while start > 0 && to_end + widths[start - 1] <= room(start - 1) {
+            start -= 1;
+            to_end += widths[start];
+        }
+
+        let end = if to_end <= room(start) {
+            len
+        } else {
+            // Some of the value is hidden on the right, so the marker needs a cell as well
+            let room = room(start).saturating_sub(1);
+            let mut used = 0;
+            start
+                + widths[start..]
+                    .iter()
+                    .take_while(|&&w| {
+                        used += w;
+                        used <= room
+                    })
+                    .count()
+        };
+
+        self.scroll = start;
+        Some(start..end)
+    }
+
+    /// The width of the characters in `range` when they are rendered.
+    fn shown_width(&self, range: Range<usize>) -> usize {
+        let text = self.rendered_text(range.end);
+        let start = text
+            .char_indices()
+            .nth(range.start)
+            .map_or(text.len(), |(i, _)| i);
+        textwrap::core::display_width(&text[start..])
+    }
+
     fn get_delete_movement(&self, key: KeyEvent) -> Option<Movement> {
         let mov = match key.code {
             KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => Movement::Home,
             KeyCode::Backspace if key.modifiers.contains(KeyModifiers::ALT) => Movement::PrevWord,
-            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::ALT) => Movement::PrevWord,
-            KeyCode::Char('w') if key.modifiers.contains(KeyModifiers::CONTROL) => Movement::Left,
+            KeyCode::Backspace if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Movement::PrevWord
+            }
+            KeyCode::Char('w')
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                Movement::PrevWord
+            }
             KeyCode::Backspace => Movement::Left,
 
             KeyCode::Char('k') if key.modifiers.contains(KeyModifiers::CONTROL) => Movement::End,
 
-            KeyCode::Delete if key.modifiers.contains(KeyModifiers::ALT) => Movement::NextWord,
+            KeyCode::Delete
+                if key
+                    .modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+            {
+                Movement::NextWord
+            }
             KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::ALT) => Movement::NextWord,
             KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => Movement::Right,
             KeyCode::Delete => Movement::Right,
@@ -186,14 +700,47 @@ impl<F> super::Widget for StringInput<F>
 where
     F: Fn(char) -> Option<char>,
 {
+    fn handle_paste(&mut self, text: &str) -> bool {
+        self.last_yank = None;
+        self.insert_str(text)
+    }
+
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let last_yank = self.last_yank.take();
+
+        if key.modifiers.contains(KeyModifiers::CONTROL) {
+            match key.code {
+                KeyCode::Char('z') if !key.modifiers.contains(KeyModifiers::SHIFT) => {
+                    return self.undo()
+                }
+                KeyCode::Char('z') | KeyCode::Char('Z') => return self.redo(),
+                KeyCode::Char('y') if !self.emacs_keys && !self.redo_stack.is_empty() => {
+                    return self.redo()
+                }
+                _ => {}
+            }
+        }
+
+        if self.emacs_keys
+            && key.code == KeyCode::Char('y')
+            && key.modifiers.contains(KeyModifiers::ALT)
+        {
+            return match last_yank {
+                Some(yank) => self.yank_pop(yank),
+                None => false,
+            };
+        }
+
         if let Some(movement) = self.get_delete_movement(key) {
+            self.record_edit(false);
+
             match movement {
                 Movement::Home => {
                     let byte_i = self.get_byte_i(self.at);
                     self.value_len -= self.at;
                     self.at = 0;
-                    self.value.replace_range(..byte_i, "");
+                    let killed = self.value.drain(..byte_i).collect();
+                    self.kill(killed);
                     return true;
                 }
                 Movement::PrevWord => {
@@ -202,7 +749,10 @@ where
                     let prev_word = self.find_word_left(byte_i);
                     self.at = self.get_char_i(prev_word);
                     self.value_len -= was_at - self.at;
-                    self.value.replace_range(prev_word..byte_i, "");
+                    let deleted = self.value.drain(prev_word..byte_i).collect();
+                    if self.emacs_keys {
+                        self.kill(deleted);
+                    }
                     return true;
                 }
                 Movement::Left if self.at == self.value_len => {
@@ -222,14 +772,18 @@ where
                 Movement::End => {
                     let byte_i = self.get_byte_i(self.at);
                     self.value_len = self.at;
-                    self.value.truncate(byte_i);
+                    let killed = self.value.split_off(byte_i);
+                    self.kill(killed);
                     return true;
                 }
                 Movement::NextWord => {
                     let byte_i = self.get_byte_i(self.at);
                     let next_word = self.find_word_right(byte_i);
                     self.value_len -= self.get_char_i(next_word) - self.at;
-                    self.value.replace_range(byte_i..next_word, "");
+                    let deleted = self.value.drain(byte_i..next_word).collect();
+                    if self.emacs_keys {
+                        self.kill(deleted);
+                    }
                     return true;
                 }
                 Movement::Right if self.at == self.value_len - 1 => {
@@ -248,7 +802,12 @@ where
             }
         }
 
-        match key.code {
+        if key.code == KeyCode::Char('y') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return self.yank();
+        }
+
+        let c = match key.code {
+            KeyCode::Enter if self.multiline => Some('\n'),
             // FIXME: all chars with ctrl and alt are ignored, even though only some
             // need to be ignored
             KeyCode::Char(c)
@@ -256,21 +815,31 @@ where
                     .modifiers
                     .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
             {
-                if let Some(c) = (self.filter_map)(c) {
-                    if self.at == self.value_len {
-                        self.value.push(c);
-                    } else {
-                        let byte_i = self.get_byte_i(self.at);
-                        self.value.insert(byte_i, c);
-                    };
-
-                    self.at += 1;
-                    self.value_len += 1;
-                    return true;
+                (self.filter_map)(c)
+            }
+            _ => None,
+        };
+
+        if let Some(c) = c {
+            let byte_i = self.get_byte_i(self.at);
+
+            // A character such as a combining mark joins the grapheme next to it instead of
+            // adding one, so it is allowed even once the value is full
+            if let Some(max_len) = self.max_len {
+                let mut value = self.value.clone();
+                value.insert(byte_i, c);
+
+                if value.graphemes(true).count() > max_len {
+                    return false;
                 }
             }
 
-            _ => {}
+            self.record_edit(true);
+            self.value.insert(byte_i, c);
+
+            self.at += 1;
+            self.value_len += 1;
+            return true;
         }
 
         match Movement::try_from_key(key) {
@@ -297,6 +866,8 @@ where
             _ => return false,
         }
 
+        // Characters typed after moving the cursor are undone separately
+        self.is_typing = false;
         true
     }
 
@@ -308,13 +879,48 @@ where
             return Ok(());
         }
 
-        if let Some(mask) = self.mask {
+        if let Some(color) = self.value_color {
+            backend.set_fg(color)?;
+        }
+
+        if let Some(window) = self.scroll_window(*layout) {
+            if window.start > 0 {
+                backend.set_fg(Color::DarkGrey)?;
+                write!(backend, "{}", SCROLL_LEFT)?;
+                backend.set_fg(self.value_color.unwrap_or(Color::Reset))?;
+            }
+
+            let shown: String = self
+                .rendered_text(self.value_len)
+                .chars()
+                .skip(window.start)
+                .take(window.len())
+                .collect();
+            backend.write_all(shown.as_bytes())?;
+
+            if window.end < self.value_len {
+                backend.set_fg(Color::DarkGrey)?;
+                write!(backend, "{}", SCROLL_RIGHT)?;
+                backend.set_fg(self.value_color.unwrap_or(Color::Reset))?;
+            }
+        } else if let Some(mask) = self.mask {
             print_mask(self.value_len, mask, backend)?;
+        } else if self.multiline {
+            for (i, line) in self.value.split('\n').enumerate() {
+                if i > 0 {
+                    backend.move_cursor(MoveDirection::NextLine(1))?;
+                }
+                backend.write_all(line.as_bytes())?;
+            }
         } else {
             // Terminal takes care of wrapping in case of large strings
             backend.write_all(self.value.as_bytes())?;
         }
 
+        if self.value_color.is_some() {
+            backend.set_fg(Color::Reset)?;
+        }
+
         // Adjust layout
         self.height(layout);
 
@@ -326,38 +932,38 @@ where
             return 1;
         }
 
-        let mut width = textwrap::core::display_width(&self.value) as u16;
-
-        if width > layout.line_width() {
-            width -= layout.line_width();
-
-            layout.line_offset = width % layout.width;
-            layout.offset_y += 1 + width / layout.width;
-
-            2 + width / layout.width
-        } else {
-            layout.line_offset += width;
-            1
+        if let Some(window) = self.scroll_window(*layout) {
+            let markers = (window.start > 0) as usize + (window.end < self.value_len) as usize;
+            let shown = self.shown_width(window);
+            layout.line_offset += (markers + shown) as u16;
+            return 1;
         }
+
+        let (x, y) = text_end(&self.rendered_text(self.value_len), *layout);
+        layout.line_offset = x;
+        layout.offset_y += y;
+        y + 1
     }
 
     fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
-        let display_at =
-            textwrap::core::display_width(&self.value[..self.get_byte_i(self.at)]) as u16;
-
         let relative_pos = if self.hide_output {
             // Nothing will be outputted so no need to move the cursor
             (layout.line_offset, 0)
-        } else if layout.line_width() > display_at {
-            // It is in the same line as the prompt
-            (layout.line_offset + display_at, 0)
+        } else if let Some(window) = self.scroll_window(layout) {
+            let marker = (window.start > 0) as usize;
+            let shown = self.shown_width(window.start..self.at);
+            (layout.line_offset + (marker + shown) as u16, 0)
         } else {
-            let at = display_at - layout.line_width();
-
-            (at % layout.width, 1 + at / layout.width)
+            text_end(&self.rendered_text(self.at), layout)
         };
 
-        layout.offset_cursor(relative_pos)
+        if relative_pos.1 == 0 {
+            layout.offset_cursor(relative_pos)
+        } else {
+            // The terminal wraps to the start of the line, so `offset_x` does not apply to lines
+            // after the first
+            (relative_pos.0, layout.offset_y + relative_pos.1)
+        }
     }
 }
 
@@ -367,6 +973,32 @@ impl Default for StringInput {
     }
 }
 
+/// Finds where the cursor ends relative to the layout after writing `text`. The text is written
+/// from `layout.line_offset`, and each new line starts at the beginning of the terminal line.
+///
+/// If the text exactly fills the last line, the cursor is at the start of the next line, as it
+/// cannot be placed past the end of a line.
+fn text_end(text: &str, layout: Layout) -> (u16, u16) {
+    let lines = wrap_lines_from(text, layout.line_width(), layout.width, WrapMode::Char);
+    let y = lines.len() as u16 - 1;
+    let x = textwrap::core::display_width(&lines[lines.len() - 1]) as u16;
+
+    let (x, line_width) = if y == 0 {
+        (
+            layout.line_offset + x,
+            layout.line_width() + layout.line_offset,
+        )
+    } else {
+        (x, layout.width)
+    };
+
+    if x >= line_width && x > 0 {
+        (0, y + 1)
+    } else {
+        (x, y)
+    }
+}
+
 fn print_mask<W: Write>(len: usize, mask: char, w: &mut W) -> io::Result<()> {
     let mut buf = [0; 4];
     let mask = mask.encode_utf8(&mut buf[..]);
@@ -419,7 +1051,11 @@ mod tests {
             ),
             (
                 KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
-                Movement::Left,
+                Movement::PrevWord,
+            ),
+            (
+                KeyEvent::new(KeyCode::Backspace, KeyModifiers::CONTROL),
+                Movement::PrevWord,
             ),
             (
                 KeyEvent::new(KeyCode::Backspace, KeyModifiers::empty()),
@@ -436,6 +1072,10 @@ mod tests {
                 KeyEvent::new(KeyCode::Delete, KeyModifiers::ALT),
                 Movement::NextWord,
             ),
+            (
+                KeyEvent::new(KeyCode::Delete, KeyModifiers::CONTROL),
+                Movement::NextWord,
+            ),
             (
                 KeyEvent::new(KeyCode::Char('d'), KeyModifiers::ALT),
                 Movement::NextWord,
@@ -494,6 +1134,56 @@ mod tests {
             .all(|(key, mov)| input.get_delete_movement(key).unwrap() == mov));
     }
 
+    #[test]
+    fn test_word_movement() {
+        let size = (100, 5).into();
+        let layout = Layout::new(0, size);
+        let ctrl = |code| KeyEvent::new(code, KeyModifiers::CONTROL);
+
+        let test = |input: &mut StringInput, value: &str, at: u16| {
+            assert_eq!(input.value(), value);
+            assert_eq!(input.cursor_pos(layout), (at, 0));
+
+            let mut backend = TestBackend::new(size);
+            input.render(&mut layout.clone(), &mut backend).unwrap();
+            let mut expected = TestBackend::new(size);
+            expected.write_all(value.as_bytes()).unwrap();
+            backend.assert_eq(&expected);
+        };
+
+        let mut input = StringInput::new();
+        input.set_value("héllo, wörld... again".into());
+        input.set_at(21);
+
+        // The punctuation is skipped along with the whitespace
+        assert!(input.handle_key(ctrl(KeyCode::Left)));
+        test(&mut input, "héllo, wörld... again", 16);
+        assert!(input.handle_key(ctrl(KeyCode::Left)));
+        test(&mut input, "héllo, wörld... again", 7);
+        assert!(input.handle_key(ctrl(KeyCode::Left)));
+        test(&mut input, "héllo, wörld... again", 0);
+        assert!(!input.handle_key(ctrl(KeyCode::Left)));
+
+        assert!(input.handle_key(ctrl(KeyCode::Right)));
+        test(&mut input, "héllo, wörld... again", 7);
+        assert!(input.handle_key(ctrl(KeyCode::Right)));
+        test(&mut input, "héllo, wörld... again", 16);
+
+        // Deletes the word before the cursor, along with the punctuation and whitespace after it
+        assert!(input.handle_key(ctrl(KeyCode::Char('w'))));
+        test(&mut input, "héllo, again", 7);
+        assert!(input.handle_key(ctrl(KeyCode::Backspace)));
+        test(&mut input, "again", 0);
+
+        input.set_value("wörld, again".into());
+        assert!(input.handle_key(ctrl(KeyCode::Delete)));
+        test(&mut input, "again", 0);
+
+        assert!(input.handle_key(ctrl(KeyCode::Right)));
+        test(&mut input, "again", 5);
+        assert!(!input.handle_key(ctrl(KeyCode::Right)));
+    }
+
     #[test]
     fn test_render() {
         fn test(text: &str, line_offset: u16, offset_y: u16) {
@@ -520,6 +1210,85 @@ mod tests {
         test(UNICODE, 70, 4);
     }
 
+    #[test]
+    fn test_value_color() {
+        let size = (20, 5).into();
+        let layout = Layout::new(5, size);
+
+        let mut input = StringInput::new().with_value_color(Color::Cyan);
+        input.set_value("hello".into());
+
+        let mut backend = TestBackend::new(size);
+        backend.move_cursor_to(5, 0).unwrap();
+        let mut l = layout;
+        input.render(&mut l, &mut backend).unwrap();
+
+        let mut expected = TestBackend::new(size);
+        expected.move_cursor_to(5, 0).unwrap();
+        expected.set_fg(Color::Cyan).unwrap();
+        expected.write_all(b"hello").unwrap();
+        expected.set_fg(Color::Reset).unwrap();
+
+        backend.assert_eq(&expected);
+        assert_eq!(l, layout.with_line_offset(10));
+    }
+
+    #[test]
+    fn test_scroll_markers() {
+        let size = (25, 5).into();
+        let layout = Layout::new(5, size);
+
+        let mut input = StringInput::new().with_scroll_markers(true);
+
+        let render = |input: &mut StringInput, left: bool, text: &str, right: bool| {
+            let mut backend = TestBackend::new(size);
+            backend.move_cursor_to(5, 0).unwrap();
+            let mut l = layout;
+            input.render(&mut l, &mut backend).unwrap();
+
+            let mut expected = TestBackend::new(size);
+            expected.move_cursor_to(5, 0).unwrap();
+            if left {
+                expected.set_fg(Color::DarkGrey).unwrap();
+                expected.write_all("‹".as_bytes()).unwrap();
+                expected.set_fg(Color::Reset).unwrap();
+            }
+            expected.write_all(text.as_bytes()).unwrap();
+            if right {
+                expected.set_fg(Color::DarkGrey).unwrap();
+                expected.write_all("›".as_bytes()).unwrap();
+                expected.set_fg(Color::Reset).unwrap();
+            }
+
+            backend.assert_eq(&expected);
+            l.line_offset
+        };
+
+        // Values which fit are not changed
+        input.set_value("hello".into());
+        assert_eq!(render(&mut input, false, "hello", false), 10);
+
+        input.set_value("abcdefghijklmnopqrstuvwxyz".into());
+        input.set_at(26);
+        assert_eq!(render(&mut input, true, "ijklmnopqrstuvwxyz", false), 24);
+        assert_eq!(input.cursor_pos(layout), (24, 0));
+
+        input.set_at(0);
+        assert_eq!(render(&mut input, false, "abcdefghijklmnopqr", true), 24);
+        assert_eq!(input.cursor_pos(layout), (5, 0));
+
+        // The window only moves as far as needed for the cursor to be shown
+        input.set_at(20);
+        assert_eq!(render(&mut input, true, "efghijklmnopqrstu", true), 24);
+        assert_eq!(input.cursor_pos(layout), (22, 0));
+        assert_eq!(input.height(&mut layout.clone()), 1);
+
+        // Without the markers, the value wraps instead
+        let mut input = StringInput::new();
+        input.set_value("abcdefghijklmnopqrstuvwxyz".into());
+        assert_eq!(input.height(&mut layout.clone()), 2);
+    }
+
     #[test]
     fn test_handle_key() {
         let mut input = StringInput::with_filter_map(|c| if c == 'i' { None } else { Some(c) });
@@ -623,6 +1392,343 @@ mod tests {
         assert_eq!(input.value().chars().count(), 386);
     }
 
+    #[test]
+    fn test_kill_buffer() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        let mut input = StringInput::new();
+        input.set_value("hello wörld".into());
+
+        // Nothing has been killed yet
+        assert!(!input.handle_key(ctrl('y')));
+
+        input.set_at(6);
+        assert!(input.handle_key(ctrl('u')));
+        assert_eq!(input.value(), "wörld");
+        assert_eq!(input.kill_buffer(), "hello ");
+        assert_eq!(input.get_at(), 0);
+
+        // Yanking restores the killed text
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hello wörld");
+        assert_eq!(input.get_at(), 6);
+
+        // The kill buffer is kept after yanking, so it can be yanked again
+        input.set_at(11);
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hello wörldhello ");
+        assert_eq!(input.get_at(), 17);
+
+        // Killing again replaces the kill buffer
+        input.set_at(7);
+        assert!(input.handle_key(ctrl('k')));
+        assert_eq!(input.value(), "hello w");
+        assert_eq!(input.kill_buffer(), "örldhello ");
+        assert_eq!(input.get_at(), 7);
+
+        // Killing nothing keeps the kill buffer
+        assert!(!input.handle_key(ctrl('k')));
+        assert_eq!(input.kill_buffer(), "örldhello ");
+
+        input.set_at(1);
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hörldhello ello w");
+        assert_eq!(input.get_at(), 11);
+    }
+
+    #[test]
+    fn test_emacs_keys() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let alt = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT);
+
+        let mut input = StringInput::new().with_emacs_keys(true);
+        input.set_value("one two three".into());
+
+        // Nothing has been yanked yet
+        assert!(!input.handle_key(alt('y')));
+
+        input.set_at(8);
+        assert!(input.handle_key(ctrl('k')));
+        assert!(input.handle_key(ctrl('w')));
+        assert!(input.handle_key(ctrl('a')));
+        assert!(input.handle_key(alt('d')));
+        assert_eq!(input.value(), "");
+        assert_eq!(input.kill_buffer(), "one ");
+
+        // Ctrl+Y yanks even after an undo
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "one ");
+        assert!(input.handle_key(ctrl('e')));
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "one one ");
+
+        // Alt+Y cycles through the older kills
+        assert!(input.handle_key(alt('y')));
+        assert_eq!(input.value(), "one two ");
+        assert!(input.handle_key(alt('y')));
+        assert_eq!(input.value(), "one three");
+        assert!(input.handle_key(alt('y')));
+        assert_eq!(input.value(), "one one ");
+        assert_eq!(input.get_at(), 8);
+
+        // Each replacement can be undone
+        assert!(input.undo());
+        assert_eq!(input.value(), "one three");
+
+        // Alt+Y only works right after a yank
+        assert!(input.handle_key(ctrl('y')));
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert!(!input.handle_key(alt('y')));
+
+        // Without emacs keys, only the last kill is kept and words are not killed
+        let mut input = StringInput::new();
+        input.set_value("one two".into());
+        input.set_at(7);
+        assert!(input.handle_key(ctrl('w')));
+        assert_eq!(input.kill_buffer(), "");
+        assert!(input.handle_key(ctrl('u')));
+        assert!(!input.handle_key(alt('y')));
+        assert!(input.handle_key(ctrl('y')));
+        assert!(!input.handle_key(alt('y')));
+        assert_eq!(input.value(), "one ");
+    }
+
+    #[test]
+    fn test_undo() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+        let ctrl_shift = |c| {
+            KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+            )
+        };
+
+        let mut input = StringInput::new();
+        assert!(!input.undo());
+        assert!(!input.redo());
+
+        for c in "hello".chars() {
+            assert!(input.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(input.handle_key(KeyCode::Left.into()));
+        for c in "XY".chars() {
+            assert!(input.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "hello");
+
+        // Each deletion is undone separately
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "hellXo");
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "hellXYo");
+        assert_eq!(input.get_at(), 6);
+
+        // Typing after moving the cursor is undone separately from typing before it
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "hello");
+        assert_eq!(input.get_at(), 4);
+        assert!(input.handle_key(ctrl('z')));
+        assert_eq!(input.value(), "");
+        assert!(!input.handle_key(ctrl('z')));
+
+        assert!(input.handle_key(ctrl_shift('z')));
+        assert_eq!(input.value(), "hello");
+        assert_eq!(input.get_at(), 4);
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hellXYo");
+
+        // A new edit discards what can be redone, so Ctrl+Y inserts the kill buffer again
+        assert!(input.handle_key(ctrl('k')));
+        assert_eq!(input.value(), "hellXY");
+        assert!(input.handle_key(ctrl('z')));
+        assert!(input.handle_key(KeyCode::Char('!').into()));
+        assert!(!input.redo());
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "hellXY!oo");
+
+        // Only the most recent edits are kept
+        let mut input = StringInput::new().with_history_depth(2);
+        for c in "a b c".chars() {
+            assert!(input.handle_key(KeyCode::Char(c).into()));
+            assert!(input.handle_key(KeyCode::Home.into()));
+        }
+        assert!(input.undo());
+        assert!(input.undo());
+        assert_eq!(input.value(), "b a");
+        assert!(!input.undo());
+    }
+
+    #[test]
+    fn test_max_len() {
+        let ctrl = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL);
+
+        let mut input = StringInput::new().with_max_len(4);
+        // The emoji with a skin tone is two code points, but a single grapheme
+        input.set_value("a👍🏽".into());
+        input.set_at(3);
+        assert!(!input.is_full());
+
+        assert!(input.handle_key(KeyCode::Char('b').into()));
+        assert!(input.handle_key(KeyCode::Char('c').into()));
+        assert!(input.is_full());
+        assert!(!input.handle_key(KeyCode::Char('d').into()));
+        assert_eq!(input.value(), "a👍🏽bc");
+
+        // A combining mark joins the last grapheme, so it can still be typed
+        assert!(input.handle_key(KeyCode::Char('\u{301}').into()));
+        assert_eq!(input.value(), "a👍🏽bc\u{301}");
+        assert_eq!(input.get_at(), 6);
+        assert!(input.is_full());
+        // But a wide character is a grapheme of its own
+        assert!(!input.handle_key(KeyCode::Char('界').into()));
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "a👍🏽bc");
+
+        // Movement and deletion still work once the value is full
+        assert!(input.handle_key(KeyCode::Left.into()));
+        assert!(input.handle_key(KeyCode::Backspace.into()));
+        assert_eq!(input.value(), "a👍🏽c");
+        assert!(!input.is_full());
+
+        // Yanking only inserts as much as fits
+        input.set_at(0);
+        assert!(input.handle_key(ctrl('k')));
+        assert_eq!(input.kill_buffer(), "a👍🏽c");
+        assert!(input.handle_key(KeyCode::Char('x').into()));
+        assert!(input.handle_key(KeyCode::Char('y').into()));
+        assert!(input.handle_key(ctrl('y')));
+        assert_eq!(input.value(), "xya👍🏽");
+        assert_eq!(input.get_at(), 5);
+        assert!(input.is_full());
+        assert!(!input.handle_key(ctrl('y')));
+
+        // There is no limit by default
+        let mut input = StringInput::new();
+        input.set_value("a".repeat(1000));
+        assert!(!input.is_full());
+        assert!(input.handle_key(KeyCode::Char('a').into()));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut input = StringInput::new();
+        input.set_value(UNICODE.into());
+        input.set_at(20);
+
+        input.reset();
+        assert_eq!(input.value(), "");
+        assert_eq!(input.get_at(), 0);
+
+        input.handle_key(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty()));
+        assert_eq!(input.value(), "a");
+        assert_eq!(input.get_at(), 1);
+    }
+
+    #[test]
+    fn test_reset_initial_value() {
+        let mut input = StringInput::new().with_initial_value(UNICODE.into());
+        input.set_at(3);
+        input.handle_key(KeyCode::Backspace.into());
+
+        input.reset();
+        assert_eq!(input.value(), UNICODE);
+        assert_eq!(input.get_at(), UNICODE.chars().count());
+    }
+
+    #[test]
+    fn test_is_dirty() {
+        let backspace = KeyEvent::from(KeyCode::Backspace);
+
+        let mut input = StringInput::new();
+        assert!(!input.is_dirty());
+        input.handle_key(KeyCode::Char('a').into());
+        assert!(input.is_dirty());
+        input.handle_key(backspace);
+        assert!(!input.is_dirty());
+
+        let mut input = StringInput::new().with_initial_value("abc".into());
+        assert_eq!(input.get_at(), 3);
+        assert!(!input.is_dirty());
+        input.handle_key(backspace);
+        assert!(input.is_dirty());
+        input.handle_key(KeyCode::Char('c').into());
+        assert!(!input.is_dirty());
+        input.handle_key(backspace);
+        input.reset();
+        assert!(!input.is_dirty());
+    }
+
+    #[test]
+    fn test_insert_str() {
+        let mut input = StringInput::with_filter_map(|c| if c == '_' { None } else { Some(c) })
+            .with_max_len(10);
+        input.set_value("ab".into());
+        input.set_at(1);
+
+        assert!(input.insert_str("x_y\r\nz"));
+        assert_eq!(input.value(), "axyzb");
+        assert_eq!(input.get_at(), 4);
+
+        // Only as much as fits is inserted
+        assert!(input.handle_paste("0123456789"));
+        assert_eq!(input.value(), "axyz01234b");
+        assert_eq!(input.get_at(), 9);
+        assert!(!input.insert_str("5"));
+
+        // Each paste is undone at once
+        assert!(input.undo());
+        assert_eq!(input.value(), "axyzb");
+        assert!(input.undo());
+        assert_eq!(input.value(), "ab");
+        assert_eq!(input.get_at(), 1);
+
+        let mut input = StringInput::new().multiline(true);
+        assert!(input.insert_str("a\r\nb\rc\td"));
+        assert_eq!(input.value(), "a\nb\ncd");
+        assert!(!input.insert_str("\t"));
+    }
+
+    #[test]
+    fn test_multiline() {
+        let layout = Layout::new(5, (20, 10).into());
+
+        let mut input = StringInput::new().multiline(true);
+        for c in "hello".chars() {
+            input.handle_key(KeyCode::Char(c).into());
+        }
+        assert!(input.handle_key(KeyCode::Enter.into()));
+        for c in "world".chars() {
+            input.handle_key(KeyCode::Char(c).into());
+        }
+
+        assert_eq!(input.value(), "hello\nworld");
+        assert_eq!(input.height(&mut layout.clone()), 2);
+        assert_eq!(input.cursor_pos(layout), (5, 1));
+
+        input.set_at(3);
+        assert_eq!(input.cursor_pos(layout), (8, 0));
+
+        // The second line wraps around as it is longer than the terminal width
+        input.set_value("hello\n".to_owned() + &"a".repeat(25));
+        let mut l = layout;
+        assert_eq!(input.height(&mut l), 3);
+        assert_eq!(l, layout.with_offset(0, 2).with_line_offset(5));
+        input.set_at(31);
+        assert_eq!(input.cursor_pos(layout), (5, 2));
+
+        // The cursor is at the start of the wrapped part of the line when the line before it is full
+        input.set_at(26);
+        assert_eq!(input.cursor_pos(layout), (0, 2));
+        input.set_at(10);
+        assert_eq!(input.cursor_pos(layout), (4, 1));
+
+        // Enter is ignored unless multiline is set
+        let mut input = StringInput::new();
+        assert!(!input.handle_key(KeyCode::Enter.into()));
+    }
+
     #[test]
     fn test_height() {
         fn test(text: &str, indent: usize, max_width: usize, height: u16) {
@@ -686,5 +1792,18 @@ mod tests {
 
         layout.offset_y = 3;
         assert_eq!(input.cursor_pos(layout), (35, 4));
+
+        // Only the first line is shifted by offset_x, as the terminal wraps to the start of the line
+        layout.offset_x = 4;
+        assert_eq!(input.cursor_pos(layout), (39, 4));
+        input.set_at(4);
+        assert_eq!(input.cursor_pos(layout), (13, 3));
+
+        // A wide character which does not fit at the end of the line is wrapped as a whole
+        let layout = Layout::new(9, (10, 20).into());
+        input.set_value("你好".into());
+        input.set_at(1);
+        assert_eq!(input.cursor_pos(layout), (2, 1));
+        assert_eq!(input.height(&mut layout.clone()), 2);
     }
 }
@@ -1,6 +1,10 @@
 //! A module to control the looks of text.
 
-use std::{fmt::Display, io};
+use std::{
+    fmt::{self, Display},
+    io,
+    str::FromStr,
+};
 
 /// Some content with a particular style applied.
 ///
@@ -89,6 +93,154 @@ pub enum Color {
     Ansi(u8),
 }
 
+impl Color {
+    /// Creates a [`Color::Rgb`] from a hex code of the form `#rrggbb`. The leading `#` is optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty_ui::style::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#1e90ff"), Ok(Color::Rgb(0x1e, 0x90, 0xff)));
+    /// assert!(Color::from_hex("#1e90f").is_err());
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseColorError::new(hex));
+        }
+
+        let component = |i: usize| u8::from_str_radix(&digits[i..(i + 2)], 16).unwrap();
+
+        Ok(Color::Rgb(component(0), component(2), component(4)))
+    }
+}
+
+/// Parses a color from a name such as `dark_grey`, a hex code of the form `#rrggbb`, or an ANSI
+/// color code of the form `ansi(n)`.
+///
+/// Names are the same as the variants of [`Color`], and are case insensitive. Words can be
+/// separated by `_`, `-` or nothing, so `dark_grey`, `dark-grey` and `DarkGrey` are all the same
+/// color. `gray` can be used in place of `grey`.
+///
+/// The [`Display`] implementation of [`Color`] gives a string which can be parsed back to the same
+/// color.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::style::Color;
+///
+/// assert_eq!("dark_grey".parse(), Ok(Color::DarkGrey));
+/// assert_eq!("#1e90ff".parse(), Ok(Color::Rgb(0x1e, 0x90, 0xff)));
+/// assert_eq!("ansi(202)".parse(), Ok(Color::Ansi(202)));
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        if trimmed.starts_with('#') {
+            return Color::from_hex(trimmed).map_err(|_| ParseColorError::new(s));
+        }
+
+        let lower = trimmed.to_ascii_lowercase().replace("gray", "grey");
+
+        // Only the two words of a name can be separated, so `r-e-d` is not a color
+        let name = match lower.find(['_', '-']) {
+            Some(i) if &lower[..i] == "dark" || &lower[..i] == "light" => {
+                format!("{}{}", &lower[..i], &lower[(i + 1)..])
+            }
+            _ => lower,
+        };
+
+        let color = match &*name {
+            "reset" => Color::Reset,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "grey" => Color::Grey,
+            "darkgrey" => Color::DarkGrey,
+            "lightred" => Color::LightRed,
+            "lightgreen" => Color::LightGreen,
+            "lightyellow" => Color::LightYellow,
+            "lightblue" => Color::LightBlue,
+            "lightmagenta" => Color::LightMagenta,
+            "lightcyan" => Color::LightCyan,
+            "white" => Color::White,
+            // The code is parsed from the input as it was given
+            _ if name.starts_with("ansi(") => trimmed[5..]
+                .strip_suffix(')')
+                .and_then(|code| code.trim().parse().ok())
+                .map(Color::Ansi)
+                .ok_or_else(|| ParseColorError::new(s))?,
+            _ => return Err(ParseColorError::new(s)),
+        };
+
+        Ok(color)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Color::Reset => "reset",
+            Color::Black => "black",
+            Color::Red => "red",
+            Color::Green => "green",
+            Color::Yellow => "yellow",
+            Color::Blue => "blue",
+            Color::Magenta => "magenta",
+            Color::Cyan => "cyan",
+            Color::Grey => "grey",
+            Color::DarkGrey => "dark_grey",
+            Color::LightRed => "light_red",
+            Color::LightGreen => "light_green",
+            Color::LightYellow => "light_yellow",
+            Color::LightBlue => "light_blue",
+            Color::LightMagenta => "light_magenta",
+            Color::LightCyan => "light_cyan",
+            Color::White => "white",
+            Color::Rgb(r, g, b) => return write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Ansi(code) => return write!(f, "ansi({})", code),
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// The error returned when a [`Color`] could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl ParseColorError {
+    fn new(input: &str) -> Self {
+        Self {
+            input: input.to_owned(),
+        }
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid color `{}`, expected a color name, `#rrggbb` or `ansi(n)`",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
 bitflags::bitflags! {
     /// Attributes change the way a piece of text is displayed.
     pub struct Attributes: u16 {
@@ -423,3 +575,71 @@ impl<T, I: Into<Styled<T>>> Stylize<T> for I {
         styled
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color() {
+        let colors = [
+            Color::Reset,
+            Color::Black,
+            Color::Red,
+            Color::Green,
+            Color::Yellow,
+            Color::Blue,
+            Color::Magenta,
+            Color::Cyan,
+            Color::Grey,
+            Color::DarkGrey,
+            Color::LightRed,
+            Color::LightGreen,
+            Color::LightYellow,
+            Color::LightBlue,
+            Color::LightMagenta,
+            Color::LightCyan,
+            Color::White,
+            Color::Rgb(0x1e, 0x90, 0xff),
+            Color::Rgb(0, 0, 0),
+            Color::Ansi(0),
+            Color::Ansi(255),
+        ];
+
+        for &color in colors.iter() {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+
+        assert_eq!("dark_grey".parse(), Ok(Color::DarkGrey));
+        assert_eq!("DarkGray".parse(), Ok(Color::DarkGrey));
+        assert_eq!("light-blue".parse(), Ok(Color::LightBlue));
+        assert_eq!(" ansi( 42 ) ".parse(), Ok(Color::Ansi(42)));
+        assert_eq!("#1E90FF".parse(), Ok(Color::Rgb(0x1e, 0x90, 0xff)));
+        assert_eq!(Color::from_hex("1e90ff"), Ok(Color::Rgb(0x1e, 0x90, 0xff)));
+        assert_eq!(Color::Rgb(0x1e, 0x90, 0xff).to_string(), "#1e90ff");
+
+        for invalid in [
+            "",
+            "purple",
+            "#1e90f",
+            "#1e90fg",
+            "ansi(256)",
+            "ansi(x)",
+            "ansi(-1)",
+            "ansi(4_2)",
+            "ansi",
+            "r-e-d",
+            "dark__grey",
+            "dark-grey-",
+        ]
+        .iter()
+        {
+            assert_eq!(invalid.parse::<Color>(), Err(ParseColorError::new(invalid)));
+        }
+
+        assert_eq!(
+            ParseColorError::new("purple").to_string(),
+            "invalid color `purple`, expected a color name, `#rrggbb` or `ansi(n)`"
+        );
+    }
+}
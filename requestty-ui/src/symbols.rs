@@ -85,6 +85,10 @@ pub struct SymbolSet {
     pub box_horizontal: char,
     /// Character for the vertical edge of a box.
     pub box_vertical: char,
+    /// Character for a filled cell of a gauge.
+    pub gauge_filled: char,
+    /// Character for an empty cell of a gauge.
+    pub gauge_empty: char,
 }
 
 /// The default [`SymbolSet`].
@@ -113,6 +117,10 @@ pub const UNICODE: SymbolSet = SymbolSet {
     box_horizontal: '─',
     /// `'│' U+2502`
     box_vertical: '│',
+    // `'■' U+25A0`
+    gauge_filled: '■',
+    // `'□' U+25A1`
+    gauge_empty: '□',
 };
 
 /// A [`SymbolSet`] based exclusively on ASCII characters.
@@ -131,4 +139,6 @@ pub const ASCII: SymbolSet = SymbolSet {
     box_bottom_left: '\'',
     box_horizontal: '-',
     box_vertical: '|',
+    gauge_filled: '#',
+    gauge_empty: '-',
 };
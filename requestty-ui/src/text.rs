@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::{backend, layout::Layout, Widget};
 
 /// A string that can render over multiple lines.
@@ -131,15 +133,90 @@ impl<S: AsRef<str>> From<S> for Text<S> {
     }
 }
 
+fn fill(text: &str, layout: Layout) -> String {
+    wrap_lines_from(
+        text,
+        layout.line_width(),
+        layout.available_width(),
+        WrapMode::Word,
+    )
+    .join("\n")
+}
+
+/// How text which does not fit in the given width is broken into lines.
+///
+/// See [`wrap_lines`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WrapMode {
+    /// Lines are broken between words, and a word is only broken if it is longer than a line. This
+    /// is how [`Text`] wraps.
+    Word,
+    /// Lines are broken at the last character that fits, which is how the terminal wraps text
+    /// written past the end of a line.
+    Char,
+}
+
+/// Splits the text into the lines it takes when rendered `width` columns wide.
+///
+/// Each `'\n'` in the text always starts a new line. The widths of characters are their display
+/// widths, so wide characters (like CJK characters) take 2 columns, and combining marks take
+/// none.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{wrap_lines, WrapMode};
+///
+/// let lines: Vec<_> = wrap_lines("Hello World\nHi", 8, WrapMode::Word).collect();
+/// assert_eq!(lines, ["Hello", "World", "Hi"]);
+///
+/// let lines: Vec<_> = wrap_lines("Hello World", 8, WrapMode::Char).collect();
+/// assert_eq!(lines, ["Hello Wo", "rld"]);
+/// ```
+pub fn wrap_lines(text: &str, width: u16, wrap: WrapMode) -> impl Iterator<Item = Cow<'_, str>> {
+    wrap_lines_from(text, width, width, wrap).into_iter()
+}
+
+/// The number of lines the text takes when rendered `width` columns wide.
+///
+/// This is the number of lines given by [`wrap_lines`].
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::widgets::{wrapped_height, WrapMode};
+///
+/// assert_eq!(wrapped_height("Hello World", 8, WrapMode::Word), 2);
+/// assert_eq!(wrapped_height("", 8, WrapMode::Word), 1);
+/// ```
+pub fn wrapped_height(text: &str, width: u16, wrap: WrapMode) -> u16 {
+    wrap_lines_from(text, width, width, wrap).len() as u16
+}
+
 // 200 spaces to remove allocation for indent
 static SPACES: &str = "                                                                                                                                                                                                        ";
 
-fn fill(text: &str, layout: Layout) -> String {
+/// Splits the text into lines, where the first line has `first_width` columns, and the rest have
+/// `width` columns.
+pub(crate) fn wrap_lines_from(
+    text: &str,
+    first_width: u16,
+    width: u16,
+    wrap: WrapMode,
+) -> Vec<Cow<'_, str>> {
+    match wrap {
+        WrapMode::Word => word_wrap(text, first_width, width),
+        WrapMode::Char => char_wrap(text, first_width, width),
+    }
+}
+
+fn word_wrap(text: &str, first_width: u16, width: u16) -> Vec<Cow<'_, str>> {
     // This won't allocate until the **highly unlikely** case that there is a line
     // offset of more than 200.
     let s: String;
 
-    let indent_len = layout.line_offset as usize;
+    // A shorter first line is an indent as far as textwrap is concerned
+    let indent_len = width.saturating_sub(first_width) as usize;
 
     let indent = if SPACES.len() > indent_len {
         &SPACES[..indent_len]
@@ -148,14 +225,48 @@ fn fill(text: &str, layout: Layout) -> String {
         &s[..]
     };
 
-    let mut text = textwrap::fill(
+    let mut lines = textwrap::wrap(
         text,
-        textwrap::Options::new(layout.available_width() as usize).initial_indent(indent),
+        textwrap::Options::new(width as usize).initial_indent(indent),
     );
 
-    drop(text.drain(..indent_len));
+    if indent_len > 0 {
+        if let Some(first) = lines.first_mut() {
+            *first = Cow::Owned(first.get(indent_len..).unwrap_or("").to_owned());
+        }
+    }
+
+    lines
+}
+
+fn char_wrap(text: &str, first_width: u16, width: u16) -> Vec<Cow<'_, str>> {
+    let mut lines = Vec::new();
+    let mut buf = [0; 4];
+
+    for line in text.split('\n') {
+        let mut line_width = if lines.is_empty() { first_width } else { width };
+        let mut start = 0;
+        let mut used = 0;
+
+        for (i, c) in line.char_indices() {
+            let c_width = textwrap::core::display_width(c.encode_utf8(&mut buf)) as u16;
+
+            // A character wider than a whole line is left on its own line, as it cannot be put
+            // anywhere else
+            if used + c_width > line_width && (i > start || line_width < width) {
+                lines.push(Cow::Borrowed(&line[start..i]));
+                start = i;
+                used = 0;
+                line_width = width;
+            }
+
+            used += c_width;
+        }
+
+        lines.push(Cow::Borrowed(&line[start..]));
+    }
 
-    text
+    lines
 }
 
 #[cfg(test)]
@@ -188,6 +299,52 @@ mod tests {
         test(UNICODE, 40, 80, 7);
     }
 
+    #[test]
+    fn test_wrap_lines() {
+        fn lines(text: &str, width: u16, wrap: WrapMode) -> Vec<Cow<'_, str>> {
+            wrap_lines(text, width, wrap).collect()
+        }
+
+        // Explicit newlines always start a new line, even if the text fits
+        assert_eq!(lines("ab\n\ncd", 10, WrapMode::Word), ["ab", "", "cd"]);
+        assert_eq!(lines("ab\n\ncd", 10, WrapMode::Char), ["ab", "", "cd"]);
+        assert_eq!(wrapped_height("ab\ncd efgh", 5, WrapMode::Word), 3);
+        assert_eq!(wrapped_height("ab\ncd efgh", 5, WrapMode::Char), 3);
+
+        // CJK characters take 2 columns each, and are not split across lines
+        assert_eq!(lines("你好世界", 4, WrapMode::Char), ["你好", "世界"]);
+        assert_eq!(lines("你好世界", 5, WrapMode::Char), ["你好", "世界"]);
+        assert_eq!(wrapped_height("你好 世界", 5, WrapMode::Word), 2);
+        // A character wider than the line is put on its own line
+        assert_eq!(lines("你好", 1, WrapMode::Char), ["你", "好"]);
+
+        // Combining marks do not take any columns
+        let combining = "e\u{301}e\u{301}e\u{301}";
+        assert_eq!(wrapped_height(combining, 3, WrapMode::Char), 1);
+        assert_eq!(
+            lines(combining, 2, WrapMode::Char),
+            ["e\u{301}e\u{301}", "e\u{301}"]
+        );
+        assert_eq!(wrapped_height(combining, 3, WrapMode::Word), 1);
+
+        assert_eq!(wrapped_height("", 10, WrapMode::Char), 1);
+    }
+
+    #[test]
+    fn test_wrap_lines_from() {
+        // The first line is shorter, like when the text starts after a prompt
+        assert_eq!(
+            wrap_lines_from("abcdefgh", 3, 5, WrapMode::Char),
+            ["abc", "defgh"]
+        );
+        assert_eq!(
+            wrap_lines_from("ab cd ef", 3, 5, WrapMode::Word),
+            ["ab", "cd ef"]
+        );
+        // A wide character which does not fit in the rest of the first line moves to the next one
+        assert_eq!(wrap_lines_from("你", 1, 5, WrapMode::Char), ["", "你"]);
+    }
+
     #[test]
     fn test_text_height() {
         let mut layout = Layout::new(40, (80, 100).into());
@@ -0,0 +1,153 @@
+use std::io;
+
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    layout::Layout,
+    widgets::write_truncated,
+};
+
+/// A widget with a label that can be toggled on and off.
+///
+/// It is rendered on a single line as `[x] label` when it is on, and `[ ] label` when it is off.
+/// It is toggled with `Space` or `Enter`, and places the cursor inside the box.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{events::KeyCode, widgets::Toggle, Widget};
+///
+/// let mut toggle = Toggle::new("Send anonymous usage statistics");
+/// assert!(!toggle.value());
+///
+/// toggle.handle_key(KeyCode::Char(' ').into());
+/// assert!(toggle.value());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Toggle<S> {
+    label: S,
+    value: bool,
+}
+
+impl<S: AsRef<str>> Toggle<S> {
+    /// Creates a new `Toggle` which is off.
+    pub fn new(label: S) -> Self {
+        Self {
+            label,
+            value: false,
+        }
+    }
+
+    /// Creates a new `Toggle` with the given value.
+    pub fn with_value(mut self, value: bool) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Whether the toggle is on.
+    pub fn value(&self) -> bool {
+        self.value
+    }
+
+    /// Sets whether the toggle is on.
+    pub fn set_value(&mut self, value: bool) {
+        self.value = value;
+    }
+
+    /// The label of the toggle.
+    pub fn label(&self) -> &S {
+        &self.label
+    }
+
+    /// Consumes the toggle returning the label.
+    pub fn into_label(self) -> S {
+        self.label
+    }
+}
+
+impl<S: AsRef<str>> super::Widget for Toggle<S> {
+    /// The label is cut short with '...' if it does not fit on the line.
+    fn render<B: Backend>(&mut self, layout: &mut Layout, backend: &mut B) -> io::Result<()> {
+        let max_width = layout.line_width() as usize;
+
+        layout.offset_y += 1;
+        layout.line_offset = 0;
+
+        if self.value {
+            backend.write_all(b"[x] ")?;
+        } else {
+            backend.write_all(b"[ ] ")?;
+        }
+
+        write_truncated(self.label.as_ref(), max_width.saturating_sub(4), backend)?;
+
+        backend.move_cursor_to(layout.offset_x, layout.offset_y)
+    }
+
+    fn height(&mut self, layout: &mut Layout) -> u16 {
+        layout.offset_y += 1;
+        layout.line_offset = 0;
+        1
+    }
+
+    /// Returns the location of the box
+    fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+        layout.offset_cursor((layout.line_offset + 1, 0))
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> bool {
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                self.value = !self.value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{backend::TestBackend, Widget};
+
+    #[test]
+    fn test_render() {
+        let size = (20, 5).into();
+        let layout = Layout::new(0, size).with_offset(0, 1);
+
+        for &value in &[true, false] {
+            let mut toggle = Toggle::new("A very long label").with_value(value);
+            let mut backend = TestBackend::new_with_layout(size, layout);
+            let mut rendered_layout = layout;
+            toggle.render(&mut rendered_layout, &mut backend).unwrap();
+
+            let mut expected = TestBackend::new_with_layout(size, layout);
+            if value {
+                expected.write_all(b"[x] A very long...").unwrap();
+            } else {
+                expected.write_all(b"[ ] A very long...").unwrap();
+            }
+            expected.move_cursor_to(0, 2).unwrap();
+
+            backend.assert_eq(&expected);
+            assert_eq!(rendered_layout, layout.with_offset(0, 2));
+            assert_eq!(toggle.height(&mut layout.clone()), 1);
+            assert_eq!(toggle.cursor_pos(layout), (1, 1));
+        }
+    }
+
+    #[test]
+    fn test_handle_key() {
+        let mut toggle = Toggle::new("label");
+
+        assert!(toggle.handle_key(KeyCode::Char(' ').into()));
+        assert!(toggle.value());
+        assert!(toggle.handle_key(KeyCode::Enter.into()));
+        assert!(!toggle.value());
+        assert!(!toggle.handle_key(KeyCode::Char('x').into()));
+        assert!(!toggle.value());
+    }
+}
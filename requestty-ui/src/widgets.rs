@@ -4,13 +4,25 @@ use std::io;
 
 use textwrap::{core::Fragment, WordSeparator};
 
-use crate::{backend::Backend, events::KeyEvent, layout::Layout};
+use crate::{
+    backend::Backend,
+    events::{KeyCode, KeyEvent},
+    layout::Layout,
+    style::Attributes,
+};
 
 pub use crate::char_input::CharInput;
-pub use crate::prompt::{Delimiter, Prompt};
-pub use crate::select::{List, Select};
+pub use crate::editable_value::EditableValue;
+pub use crate::form::Form;
+pub use crate::prompt::{bold_message, is_message_bold, AnswerAlign, Delimiter, Prompt};
+pub use crate::select::{
+    fuzzy_matches, selectable_indices, write_check_marker, Checkbox, DescribedList, InlineSelect,
+    List, ReorderList, SectionedList, Select, SelectWithPreview, ShouldLoop, SkipPolicy,
+};
+pub use crate::simple_prompt::SimplePrompt;
 pub use crate::string_input::StringInput;
-pub use crate::text::Text;
+pub use crate::text::{wrap_lines, wrapped_height, Text, WrapMode};
+pub use crate::toggle::Toggle;
 
 /// The default type for `filter_map` in [`StringInput`] and [`CharInput`]
 pub type FilterMapChar = fn(char) -> Option<char>;
@@ -20,6 +32,163 @@ pub(crate) fn no_filter(c: char) -> Option<char> {
     Some(c)
 }
 
+/// Writes `s` on a single line using at most `max_width` columns. If it doesn't fit, it is cut short
+/// and '...' is added to the end.
+///
+/// Returns the number of columns written.
+pub(crate) fn write_truncated<B: Backend>(
+    s: &str,
+    max_width: usize,
+    backend: &mut B,
+) -> io::Result<usize> {
+    truncate_with(s, max_width, |text| backend.write_all(text.as_bytes()))
+}
+
+/// Cuts `s` short like [`write_truncated`], passing the pieces to `write` instead of a backend.
+pub(crate) fn truncate_with<F: FnMut(&str) -> io::Result<()>>(
+    s: &str,
+    max_width: usize,
+    mut write: F,
+) -> io::Result<usize> {
+    if max_width <= 3 {
+        for _ in 0..max_width {
+            write(".")?;
+        }
+
+        return Ok(max_width);
+    }
+
+    let total_width = textwrap::core::display_width(s);
+    if total_width <= max_width {
+        write(s)?;
+        return Ok(total_width);
+    }
+
+    let mut width = 0;
+    let mut written = 0;
+    let mut prev_whitespace_len = 0;
+    let max_width = max_width - 3; // leave space for the '...'
+
+    for word in WordSeparator::UnicodeBreakProperties.find_words(s) {
+        width += word.width() as usize + prev_whitespace_len;
+        if width > max_width {
+            break;
+        }
+
+        // Write out the whitespace only if the next word can also fit
+        for _ in 0..prev_whitespace_len {
+            write(" ")?;
+        }
+        write(&word)?;
+        written = width;
+
+        prev_whitespace_len = word.whitespace_width() as usize;
+    }
+
+    write("...")?;
+
+    Ok(written + 3)
+}
+
+/// What to cut short when a label and its annotation do not fit on a line together. See
+/// [`write_annotated`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The label is cut short so that the whole annotation is shown. This is the default.
+    TruncateLabel,
+    /// The annotation is cut short so that the whole label is shown.
+    TruncateAnnotation,
+    /// The annotation is not shown at all, and only the label is written.
+    DropAnnotation,
+}
+
+#[allow(clippy::derivable_impls)] // `#[default]` on enums needs a newer compiler than the MSRV
+impl Default for OverflowPolicy {
+    fn default() -> Self {
+        OverflowPolicy::TruncateLabel
+    }
+}
+
+/// Writes `label` on a single line, with `annotation` aligned to the right of `max_width` columns.
+///
+/// At least one column is left empty between the label and the annotation, and the annotation is
+/// dimmed. If they do not fit together, the `policy` decides which of them is cut short with
+/// '...'. Whatever is cut short, neither of them is written past `max_width` columns.
+///
+/// Returns the number of columns written.
+///
+/// # Examples
+///
+/// ```
+/// use requestty_ui::{
+///     backend::TestBackend,
+///     widgets::{write_annotated, OverflowPolicy},
+/// };
+///
+/// let mut backend = TestBackend::new((20, 1).into());
+/// let written = write_annotated(
+///     "Install dependencies",
+///     "3s",
+///     20,
+///     OverflowPolicy::TruncateLabel,
+///     &mut backend,
+/// )
+/// .unwrap();
+///
+/// // "Install...        3s"
+/// assert_eq!(written, 20);
+/// ```
+pub fn write_annotated<B: Backend>(
+    label: &str,
+    annotation: &str,
+    max_width: usize,
+    policy: OverflowPolicy,
+    backend: &mut B,
+) -> io::Result<usize> {
+    let label_width = textwrap::core::display_width(label);
+    let annotation_width = textwrap::core::display_width(annotation);
+
+    // One column is left empty between the label and the annotation
+    let (label_width, annotation_width) = if label_width + 1 + annotation_width <= max_width {
+        (label_width, annotation_width)
+    } else {
+        match policy {
+            OverflowPolicy::TruncateLabel => {
+                let annotation_width = annotation_width.min(max_width.saturating_sub(1));
+                (
+                    max_width.saturating_sub(annotation_width + 1),
+                    annotation_width,
+                )
+            }
+            OverflowPolicy::TruncateAnnotation => {
+                let label_width = label_width.min(max_width);
+                (label_width, max_width.saturating_sub(label_width + 1))
+            }
+            OverflowPolicy::DropAnnotation => (label_width.min(max_width), 0),
+        }
+    };
+
+    let written = write_truncated(label, label_width, backend)?;
+
+    if annotation_width == 0 {
+        return Ok(written);
+    }
+
+    // The annotation may be narrower than the space given to it once it is cut short, so its
+    // actual width is needed to align it to the right
+    let annotation_width = truncate_with(annotation, annotation_width, |_| Ok(()))?;
+
+    for _ in written..(max_width - annotation_width) {
+        backend.write_all(b" ")?;
+    }
+
+    backend.set_attributes(Attributes::DIM)?;
+    write_truncated(annotation, annotation_width, backend)?;
+    backend.set_attributes(Attributes::empty())?;
+
+    Ok(max_width)
+}
+
 /// A trait to represent renderable objects.
 ///
 /// There are 2 purposes of a widget.
@@ -62,6 +231,44 @@ pub trait Widget {
 
     /// Handle a key input. It should return whether key was handled.
     fn handle_key(&mut self, key: KeyEvent) -> bool;
+
+    /// Handle text which was pasted all at once. It should return whether the text was handled.
+    ///
+    /// By default, each character is given to [`handle_key`] as if it was typed, with line breaks
+    /// (`\n`, `\r` or `\r\n`) as `Enter`. Widgets which take text should insert it in a single step
+    /// instead.
+    ///
+    /// [`handle_key`]: Widget::handle_key
+    fn handle_paste(&mut self, text: &str) -> bool {
+        let mut chars = text.chars().peekable();
+        let mut handled = false;
+
+        while let Some(c) = chars.next() {
+            let key = match c {
+                '\r' => {
+                    // A `\r\n` is a single line break
+                    chars.next_if_eq(&'\n');
+                    KeyCode::Enter
+                }
+                '\n' => KeyCode::Enter,
+                c => KeyCode::Char(c),
+            };
+            handled = self.handle_key(key.into()) || handled;
+        }
+
+        handled
+    }
+
+    /// Whether the widget wants the cursor to be shown.
+    ///
+    /// If this returns `false`, the cursor will be hidden by the [`Input`] runner and
+    /// [`cursor_pos`] will not be called. By default, it returns `true`.
+    ///
+    /// [`Input`]: crate::Input
+    /// [`cursor_pos`]: Widget::cursor_pos
+    fn wants_cursor(&self) -> bool {
+        true
+    }
 }
 
 impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
@@ -75,34 +282,7 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
         layout.offset_y += 1;
         layout.line_offset = 0;
 
-        if max_width <= 3 {
-            for _ in 0..max_width {
-                backend.write_all(b".")?;
-            }
-        } else if textwrap::core::display_width(self) > max_width {
-            let mut width = 0;
-            let mut prev_whitespace_len = 0;
-            let max_width = max_width - 3; // leave space for the '...'
-
-            for word in WordSeparator::UnicodeBreakProperties.find_words(self) {
-                width += word.width() as usize + prev_whitespace_len;
-                if width > max_width {
-                    break;
-                }
-
-                // Write out the whitespace only if the next word can also fit
-                for _ in 0..prev_whitespace_len {
-                    backend.write_all(b" ")?;
-                }
-                backend.write_all(word.as_bytes())?;
-
-                prev_whitespace_len = word.whitespace_width() as usize;
-            }
-
-            backend.write_all(b"...")?;
-        } else {
-            backend.write_all(self.as_bytes())?;
-        }
+        write_truncated(self, max_width, backend)?;
 
         backend
             .move_cursor_to(layout.offset_x, layout.offset_y)
@@ -128,3 +308,108 @@ impl<T: std::ops::Deref<Target = str> + ?Sized> Widget for T {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::backend::TestBackend;
+
+    #[test]
+    fn test_handle_paste() {
+        #[derive(Default)]
+        struct Keys(Vec<KeyCode>);
+
+        impl Widget for Keys {
+            fn render<B: Backend>(&mut self, _: &mut Layout, _: &mut B) -> io::Result<()> {
+                Ok(())
+            }
+
+            fn height(&mut self, _: &mut Layout) -> u16 {
+                0
+            }
+
+            fn cursor_pos(&mut self, layout: Layout) -> (u16, u16) {
+                layout.offset_cursor((0, 0))
+            }
+
+            fn handle_key(&mut self, key: KeyEvent) -> bool {
+                self.0.push(key.code);
+                true
+            }
+        }
+
+        let paste = |text: &str| {
+            let mut keys = Keys::default();
+            assert!(keys.handle_paste(text));
+            keys.0
+        };
+
+        let a_enter_b = vec![KeyCode::Char('a'), KeyCode::Enter, KeyCode::Char('b')];
+
+        assert_eq!(paste("a\nb"), a_enter_b);
+        assert_eq!(paste("a\rb"), a_enter_b);
+        assert_eq!(paste("a\r\nb"), a_enter_b);
+        assert_eq!(
+            paste("a\n\r\r\n"),
+            vec![
+                KeyCode::Char('a'),
+                KeyCode::Enter,
+                KeyCode::Enter,
+                KeyCode::Enter
+            ]
+        );
+        assert!(!Keys::default().handle_paste(""));
+    }
+
+    #[test]
+    fn test_write_annotated() {
+        let size = (16, 1).into();
+
+        let render = |label: &str, policy| {
+            let mut backend = TestBackend::new(size);
+            let written = write_annotated(label, "annotation", 16, policy, &mut backend).unwrap();
+            (written, backend)
+        };
+
+        let expected = |label: &str, padding: usize, annotation: &str| {
+            let mut backend = TestBackend::new(size);
+            backend.write_all(label.as_bytes()).unwrap();
+            backend.write_all(" ".repeat(padding).as_bytes()).unwrap();
+            backend.set_attributes(Attributes::DIM).unwrap();
+            backend.write_all(annotation.as_bytes()).unwrap();
+            backend.set_attributes(Attributes::empty()).unwrap();
+            backend
+        };
+
+        // Both fit, so every policy gives the same result
+        for &policy in &[
+            OverflowPolicy::TruncateLabel,
+            OverflowPolicy::TruncateAnnotation,
+            OverflowPolicy::DropAnnotation,
+        ] {
+            let (written, backend) = render("label", policy);
+            assert_eq!(written, 16);
+            backend.assert_eq(&expected("label", 1, "annotation"));
+        }
+
+        let (written, backend) = render("long label", OverflowPolicy::TruncateLabel);
+        assert_eq!(written, 16);
+        backend.assert_eq(&expected("...", 3, "annotation"));
+
+        // The annotation is cut short at a word boundary, but still aligned to the right
+        let (written, backend) = render("long label", OverflowPolicy::TruncateAnnotation);
+        assert_eq!(written, 16);
+        backend.assert_eq(&expected("long label", 3, "..."));
+
+        let (written, backend) = render("long label", OverflowPolicy::DropAnnotation);
+        assert_eq!(written, 10);
+        backend.assert_eq(&expected("long label", 0, ""));
+
+        // The label takes the whole line, so there is no space left for the annotation
+        let (written, backend) = render("a very long label", OverflowPolicy::TruncateAnnotation);
+        assert_eq!(written, 14);
+        backend.assert_eq(&expected("a very long...", 0, ""));
+    }
+}
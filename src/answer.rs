@@ -1,6 +1,10 @@
 use std::{
+    borrow::Borrow,
     collections::hash_map::{Entry, HashMap, IntoIter},
+    convert::TryFrom,
+    fmt,
     hash::Hash,
+    io,
     iter::FromIterator,
     ops::{Deref, DerefMut},
 };
@@ -44,6 +48,12 @@ pub enum Answer {
     /// [`multi_select`]: crate::question::Question::multi_select
     /// [`order_select`]: crate::question::Question::order_select
     ListItems(Vec<ListItem>),
+    /// Skipped will be returned by [`optional`] questions which the user skipped.
+    ///
+    /// It is treated as absent by [`Answers::get`].
+    ///
+    /// [`optional`]: crate::question::InputBuilder::optional
+    Skipped,
 }
 
 impl Answer {
@@ -193,8 +203,79 @@ impl Answer {
             _ => Err(self),
         }
     }
+
+    /// Returns `true` if the answer is [`Answer::Skipped`].
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, Self::Skipped)
+    }
+
+    /// The name of the variant of the answer, for example `"Int"` for [`Answer::Int`].
+    pub fn variant_name(&self) -> &'static str {
+        match self {
+            Self::String(_) => "String",
+            Self::ListItem(_) => "ListItem",
+            Self::ExpandItem(_) => "ExpandItem",
+            Self::Int(_) => "Int",
+            Self::Float(_) => "Float",
+            Self::Bool(_) => "Bool",
+            Self::ListItems(_) => "ListItems",
+            Self::Skipped => "Skipped",
+        }
+    }
 }
 
+/// The error returned when converting an [`Answer`] to a type it does not hold.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use requestty::Answer;
+///
+/// let answer = Answer::String("10".into());
+/// let err = i64::try_from(answer).unwrap_err();
+///
+/// assert_eq!(err.expected(), "Int");
+/// assert_eq!(err.found(), "String");
+/// assert_eq!(err.to_string(), "expected an `Int` answer, found `String`");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnswerTypeError {
+    expected: &'static str,
+    found: &'static str,
+}
+
+impl AnswerTypeError {
+    /// The name of the variant that the conversion needed.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The name of the variant that the answer actually was.
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+}
+
+impl fmt::Display for AnswerTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The variants which start with a vowel are `Int` and `ExpandItem`
+        let article = if self.expected.starts_with(|c| "AEIOU".contains(c)) {
+            "an"
+        } else {
+            "a"
+        };
+
+        write!(
+            f,
+            "expected {} `{}` answer, found `{}`",
+            article, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for AnswerTypeError {}
+
 macro_rules! impl_from {
     ($from:ty => $storage:ident) => {
         impl From<$from> for Answer {
@@ -213,6 +294,46 @@ impl_from!(ExpandItem => ExpandItem);
 impl_from!(ListItem => ListItem);
 impl_from!(Vec<ListItem> => ListItems);
 
+macro_rules! impl_try_from {
+    ($to:ty => $storage:ident) => {
+        impl TryFrom<Answer> for $to {
+            type Error = AnswerTypeError;
+
+            fn try_from(ans: Answer) -> Result<Self, Self::Error> {
+                match ans {
+                    Answer::$storage(v) => Ok(v),
+                    _ => Err(AnswerTypeError {
+                        expected: stringify!($storage),
+                        found: ans.variant_name(),
+                    }),
+                }
+            }
+        }
+
+        impl TryFrom<&Answer> for $to {
+            type Error = AnswerTypeError;
+
+            fn try_from(ans: &Answer) -> Result<Self, Self::Error> {
+                match ans {
+                    Answer::$storage(v) => Ok(v.clone()),
+                    _ => Err(AnswerTypeError {
+                        expected: stringify!($storage),
+                        found: ans.variant_name(),
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_try_from!(String => String);
+impl_try_from!(i64 => Int);
+impl_try_from!(f64 => Float);
+impl_try_from!(bool => Bool);
+impl_try_from!(ExpandItem => ExpandItem);
+impl_try_from!(ListItem => ListItem);
+impl_try_from!(Vec<ListItem> => ListItems);
+
 impl From<Vec<OrderSelectItem>> for Answer {
     fn from(v: Vec<OrderSelectItem>) -> Self {
         Answer::ListItems(v.into_iter().map(|o| o.into()).collect())
@@ -290,6 +411,19 @@ impl std::fmt::Debug for Answers {
 }
 
 impl Answers {
+    /// Returns a reference to the answer of the question with the given name.
+    ///
+    /// Unlike [`HashMap::get`], this returns [`None`] if the question was [skipped].
+    ///
+    /// [skipped]: Answer::Skipped
+    pub fn get<Q>(&self, name: &Q) -> Option<&Answer>
+    where
+        String: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.answers.get(name).filter(|answer| !answer.is_skipped())
+    }
+
     pub(crate) fn insert(&mut self, name: String, answer: Answer) -> &mut Answer {
         match self.answers.entry(name) {
             Entry::Occupied(entry) => {
@@ -300,6 +434,113 @@ impl Answers {
             Entry::Vacant(entry) => entry.insert(answer),
         }
     }
+
+    /// Writes the answers as shell variable assignments, one `name=value` per line, so that they
+    /// can be used with `eval` in a shell script.
+    ///
+    /// The answers are written in the order of their names. Any character in a name which cannot be
+    /// used in a shell variable is replaced with `_`. Text answers are single quoted, and numbers
+    /// and bools (as `true` or `false`) are written as is. The text of each item of an
+    /// [`Answer::ListItems`] is written on its own line within the quotes. [Skipped] answers are
+    /// not written.
+    ///
+    /// [Skipped]: Answer::Skipped
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`InvalidInput`] is returned without writing anything if a name is empty,
+    /// or if two names are the same once their characters are replaced, like `is-admin` and
+    /// `is_admin`, since one variable would overwrite the other.
+    ///
+    /// [`InvalidInput`]: io::ErrorKind::InvalidInput
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers};
+    ///
+    /// let answers: Answers = vec![
+    ///     ("name".to_owned(), Answer::String("John Doe".into())),
+    ///     ("is-admin".to_owned(), Answer::Bool(false)),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut env = Vec::new();
+    /// answers.write_env(&mut env).unwrap();
+    /// assert_eq!(env, b"is_admin=false\nname='John Doe'\n");
+    /// ```
+    pub fn write_env<W: io::Write>(&self, w: &mut W) -> io::Result<()> {
+        let mut answers: Vec<_> = self
+            .answers
+            .iter()
+            .filter(|(_, answer)| !answer.is_skipped())
+            .map(|(name, answer)| (env_name(name), name, answer))
+            .collect();
+        answers.sort_unstable_by(|a, b| a.1.cmp(b.1));
+
+        let mut names = std::collections::HashMap::new();
+        for &(ref var, name, _) in &answers {
+            if var.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "an empty name cannot be a shell variable",
+                ));
+            }
+            if let Some(other) = names.insert(var, name) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "`{}` and `{}` are both written as the shell variable `{}`",
+                        other, name, var
+                    ),
+                ));
+            }
+        }
+
+        for (var, _, answer) in answers {
+            write!(w, "{}=", var)?;
+
+            match answer {
+                Answer::String(s) => write_shell_quoted(s, w)?,
+                Answer::ListItem(item) => write_shell_quoted(&item.text, w)?,
+                Answer::ExpandItem(item) => write_shell_quoted(&item.text, w)?,
+                Answer::Int(i) => write!(w, "{}", i)?,
+                Answer::Float(f) => write!(w, "{}", f)?,
+                Answer::Bool(b) => write!(w, "{}", b)?,
+                Answer::ListItems(items) => {
+                    let text: Vec<_> = items.iter().map(|item| &item.text[..]).collect();
+                    write_shell_quoted(&text.join("\n"), w)?
+                }
+                Answer::Skipped => unreachable!("skipped answers are filtered out"),
+            }
+            w.write_all(b"\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The name of the shell variable for the answer named `name`, with every character which cannot
+/// be used in a shell variable replaced with `_`.
+fn env_name(name: &str) -> String {
+    name.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if c == '_' || c.is_ascii_alphabetic() || (i > 0 && c.is_ascii_digit()) {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Single quotes `s`, so that it is taken literally by a shell.
+fn write_shell_quoted<W: io::Write>(s: &str, w: &mut W) -> io::Result<()> {
+    // A single quote can't be escaped within single quotes, so the quotes are closed, an escaped
+    // quote is written, and they are opened again
+    write!(w, "'{}'", s.replace('\'', "'\\''"))
 }
 
 impl From<HashMap<String, Answer>> for Answers {
@@ -354,3 +595,132 @@ impl IntoIterator for Answers {
         self.answers.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use super::*;
+
+    fn answers() -> Vec<Answer> {
+        vec![
+            Answer::String("string".into()),
+            Answer::ListItem((0, "list item").into()),
+            Answer::ExpandItem(('e', "expand item").into()),
+            Answer::Int(-3),
+            Answer::Float(1.5),
+            Answer::Bool(true),
+            Answer::ListItems(vec![(1, "list items").into()]),
+            Answer::Skipped,
+        ]
+    }
+
+    /// Checks that only the answer of the expected variant converts, and that the error names the
+    /// variant of every other answer
+    fn check<T>(expected: &'static str, value: T)
+    where
+        T: TryFrom<Answer, Error = AnswerTypeError>
+            + for<'a> TryFrom<&'a Answer, Error = AnswerTypeError>
+            + PartialEq
+            + fmt::Debug
+            + Clone,
+    {
+        for answer in answers() {
+            if answer.variant_name() == expected {
+                assert_eq!(T::try_from(&answer), Ok(value.clone()));
+                assert_eq!(T::try_from(answer), Ok(value.clone()));
+            } else {
+                let err = AnswerTypeError {
+                    expected,
+                    found: answer.variant_name(),
+                };
+                assert_eq!(T::try_from(&answer), Err(err));
+                assert_eq!(T::try_from(answer), Err(err));
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_from() {
+        check("String", String::from("string"));
+        check("ListItem", ListItem::from((0, "list item")));
+        check("ExpandItem", ExpandItem::from(('e', "expand item")));
+        check("Int", -3i64);
+        check("Float", 1.5f64);
+        check("Bool", true);
+        check("ListItems", vec![ListItem::from((1, "list items"))]);
+
+        let n: Result<i64, _> = Answer::Skipped.try_into();
+        assert_eq!(
+            n.unwrap_err().to_string(),
+            "expected an `Int` answer, found `Skipped`"
+        );
+
+        let s: Result<String, _> = Answer::Int(3).try_into();
+        assert_eq!(
+            s.unwrap_err().to_string(),
+            "expected a `String` answer, found `Int`"
+        );
+    }
+
+    #[test]
+    fn test_write_env() {
+        let answers: Answers = vec![
+            (
+                "name".to_owned(),
+                Answer::String("it's \"quoted\" text".into()),
+            ),
+            ("list-item".to_owned(), Answer::ListItem((0, "a b").into())),
+            ("expand".to_owned(), Answer::ExpandItem(('e', "c").into())),
+            ("1int".to_owned(), Answer::Int(-3)),
+            ("float".to_owned(), Answer::Float(1.5)),
+            ("bool".to_owned(), Answer::Bool(true)),
+            (
+                "items".to_owned(),
+                Answer::ListItems(vec![(1, "d").into(), (2, "e f").into()]),
+            ),
+            ("skipped".to_owned(), Answer::Skipped),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut env = Vec::new();
+        answers.write_env(&mut env).unwrap();
+
+        assert_eq!(
+            String::from_utf8(env).unwrap(),
+            "_int=-3\n\
+             bool=true\n\
+             expand='c'\n\
+             float=1.5\n\
+             items='d\ne f'\n\
+             list_item='a b'\n\
+             name='it'\\''s \"quoted\" text'\n"
+        );
+    }
+
+    #[test]
+    fn test_write_env_invalid_names() {
+        let write_env = |names: &[&str]| {
+            let answers: Answers = names
+                .iter()
+                .map(|&name| (name.to_owned(), Answer::Bool(true)))
+                .collect();
+            let mut env = Vec::new();
+            let err = answers.write_env(&mut env).unwrap_err();
+            // Nothing is written if any name is invalid
+            assert!(env.is_empty());
+            err
+        };
+
+        let err = write_env(&["is-admin", "is_admin"]);
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(
+            err.to_string(),
+            "`is-admin` and `is_admin` are both written as the shell variable `is_admin`"
+        );
+
+        let err = write_env(&["a", ""]);
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
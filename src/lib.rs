@@ -148,10 +148,11 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod answer;
+mod plan;
 mod prompt_module;
 pub mod question;
 
-use ui::{backend::Backend, events::EventIterator};
+use ui::{backend::Backend, events::EventIterator, style::Stylize};
 
 /// A macro to easily write an iterator of [`Question`]s.
 ///
@@ -218,10 +219,81 @@ use ui::{backend::Backend, events::EventIterator};
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
 pub use r#macro::questions;
 
-pub use answer::{Answer, Answers, ExpandItem, ListItem};
-pub use prompt_module::PromptModule;
+/// A derive to ask for the fields of a struct which have not been set yet.
+///
+/// Every field must be an [`Option`] of [`String`], [`i64`], [`f64`] or [`bool`], which are asked
+/// with an [`input`], [`int`], [`float`] or [`confirm`] question respectively. The question is
+/// named after the field, and options can be given in a `prompt` attribute with the same syntax as
+/// the [`questions`] macro, for example `#[prompt(message: "...", default: 5)]`.
+///
+/// The derive adds the following inherent methods to the struct, it does not implement any trait:
+/// - `missing_questions(&self) -> Vec<Question<'static>>`: the questions for the fields which are
+///   [`None`].
+/// - `fill_from_answers(&mut self, answers: &Answers)`: sets the fields which are [`None`] from the
+///   answers.
+/// - `prompt_missing_with(&mut self, backend, events) -> Result<()>`: asks the missing questions
+///   with the given [`Backend`] and [`EventIterator`], and fills the fields from the answers.
+///
+/// [`input`]: crate::Question::input
+/// [`int`]: crate::Question::int
+/// [`float`]: crate::Question::float
+/// [`confirm`]: crate::Question::confirm
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{Answer, Answers, PromptMissing};
+///
+/// #[derive(Default, PromptMissing)]
+/// struct Config {
+///     #[prompt(message: "What is your name?")]
+///     name: Option<String>,
+///     #[prompt(message: "How old are you?", validate: |age, _| if age > 0 {
+///         Ok(())
+///     } else {
+///         Err("Please enter a positive age".to_owned())
+///     })]
+///     age: Option<i64>,
+///     #[prompt(default: true)]
+///     subscribe: Option<bool>,
+/// }
+///
+/// let mut config = Config {
+///     name: Some("John Doe".to_owned()),
+///     ..Config::default()
+/// };
+///
+/// let questions = config.missing_questions();
+/// assert_eq!(questions.len(), 2);
+///
+/// let answers: Answers = vec![
+///     ("name".to_owned(), Answer::String("Jane Doe".to_owned())),
+///     ("age".to_owned(), Answer::Int(42)),
+///     ("subscribe".to_owned(), Answer::Bool(false)),
+/// ]
+/// .into_iter()
+/// .collect();
+/// config.fill_from_answers(&answers);
+///
+/// // Fields which are already set are not changed
+/// assert_eq!(config.name.as_deref(), Some("John Doe"));
+/// assert_eq!(config.age, Some(42));
+/// assert_eq!(config.subscribe, Some(false));
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use r#macro::PromptMissing;
+
+pub use answer::{Answer, AnswerTypeError, Answers, ExpandItem, ListItem};
+pub use plan::{plan, Plan};
+pub use prompt_module::{DuplicateNameError, PromptModule};
 pub use question::{Choice::Choice, Choice::DefaultSeparator, Choice::Separator, Question};
-pub use ui::{symbols, ErrorKind, OnEsc, Result};
+pub use ui::{
+    events::KeyBindings,
+    symbols,
+    widgets::{bold_message, AnswerAlign},
+    ErrorKind, OnEsc, Result,
+};
 
 /// A module that re-exports all the things required for writing custom [`Prompt`]s.
 ///
@@ -235,14 +307,80 @@ pub mod prompt {
     };
 }
 
+/// Estimates the total number of rows taken up by the given questions on a terminal of the given
+/// `size`, without asking them.
+///
+/// This can be used to decide whether the questions fit on the screen before prompting them. The
+/// questions that are excluded are the ones which will not be asked based on the `answers` known
+/// beforehand. See [`Question::estimated_height`] for more details.
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{Answers, Question};
+///
+/// let mut questions = vec![
+///     Question::input("name").build(),
+///     Question::confirm("anonymous").when(false).build(),
+///     Question::select("theme")
+///         .choices(vec!["Light", "Dark"])
+///         .build(),
+/// ];
+///
+/// let height = requestty::estimated_height(&mut questions, &Answers::default(), (80, 24).into());
+/// assert_eq!(height, 4);
+/// ```
+pub fn estimated_height(
+    questions: &mut [Question<'_>],
+    answers: &Answers,
+    size: ui::backend::Size,
+) -> u16 {
+    questions
+        .iter_mut()
+        .map(|question| question.estimated_height(answers, size))
+        .fold(0, u16::saturating_add)
+}
+
 /// Prompt all the questions in the given iterator, with the default [`Backend`] and [`EventIterator`].
+///
+/// See [`prompt_with`] for more details.
 #[cfg(any(feature = "crossterm", feature = "termion"))]
 #[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
 pub fn prompt<'a, Q>(questions: Q) -> Result<Answers>
 where
     Q: IntoIterator<Item = Question<'a>>,
 {
-    PromptModule::new(questions.into_iter()).prompt_all()
+    let stdout = std::io::stdout();
+    let mut stdout = ui::backend::get_backend(stdout.lock());
+    let mut events = ui::events::get_events();
+
+    prompt_with(questions, &mut stdout, &mut events)
+}
+
+/// Prompt all the questions in the given iterator, with the default [`EventIterator`], and write
+/// the answers to `writer` as shell variable assignments.
+///
+/// This is useful for shell scripts, which can `eval "$(program)"` to get the answers in variables.
+/// Since the output of the program is captured, the prompts are drawn on stderr instead of stdout,
+/// so `writer` would usually be stdout. See [`Answers::write_env`] for the format of the output,
+/// and when it fails.
+///
+/// This is only available with the `crossterm` feature, as `termion` needs stdout to be the
+/// terminal.
+#[cfg(feature = "crossterm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "crossterm")))]
+pub fn prompt_to_env<'a, Q, W>(questions: Q, writer: &mut W) -> Result<Answers>
+where
+    Q: IntoIterator<Item = Question<'a>>,
+    W: std::io::Write,
+{
+    let stderr = std::io::stderr();
+    let mut stderr = ui::backend::get_backend(stderr.lock());
+    let mut events = ui::events::get_events();
+
+    let answers = prompt_with(questions, &mut stderr, &mut events)?;
+    answers.write_env(writer)?;
+    Ok(answers)
 }
 
 /// Prompt the given question, with the default [`Backend`] and [`EventIterator`].
@@ -261,13 +399,361 @@ pub fn prompt_one<'a, I: Into<Question<'a>>>(question: I) -> Result<Answer> {
 }
 
 /// Prompt all the questions in the given iterator, with the given [`Backend`] and [`EventIterator`].
+///
+/// The answers are stored by the names of the questions, and a question is skipped if there is
+/// already an answer with its name. So if a question has the same name as an earlier question which
+/// is always answered, it would never be asked. Since this is most likely a mistake, an
+/// [`ErrorKind::IoError`] of kind [`InvalidInput`] is returned instead. It wraps a
+/// [`DuplicateNameError`].
+///
+/// If the number of questions is known up front, such as for a [`Vec`] or an array, the names are
+/// checked before anything is asked. Otherwise, the questions are only taken from the iterator as
+/// they are asked, so the error is returned when the duplicate question is reached, after the
+/// earlier questions have been answered.
+///
+/// Questions can still share a name if the earlier ones have a `when` condition or are `optional`,
+/// for example to ask for the same answer in different ways, or if the later ones set
+/// `ask_if_answered`.
+///
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
 pub fn prompt_with<'a, Q, B, E>(questions: Q, backend: &mut B, events: &mut E) -> Result<Answers>
 where
     Q: IntoIterator<Item = Question<'a>>,
     B: Backend,
     E: EventIterator,
 {
-    PromptModule::new(questions.into_iter()).prompt_all_with(backend, events)
+    let duplicate_err =
+        |err| ErrorKind::from(std::io::Error::new(std::io::ErrorKind::InvalidInput, err));
+    let questions = questions.into_iter();
+
+    if let (lower, Some(upper)) = questions.size_hint() {
+        if lower == upper {
+            let questions: Vec<_> = questions.collect();
+
+            let mut names = prompt_module::NameCheck::default();
+            for question in &questions {
+                names.check(question).map_err(duplicate_err)?;
+            }
+
+            return PromptModule::new(questions).prompt_all_with(backend, events);
+        }
+    }
+
+    let mut duplicate = None;
+    let answers = PromptModule::new(prompt_module::CheckNames::new(questions, &mut duplicate))
+        .prompt_all_with(backend, events)?;
+
+    match duplicate {
+        Some(err) => Err(duplicate_err(err)),
+        None => Ok(answers),
+    }
+}
+
+/// Prompt all the questions, with the default [`Backend`] and [`EventIterator`], and then
+/// validate all the answers together.
+///
+/// See [`prompt_validated_with`] for more details.
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+pub fn prompt_validated<'a, F, Q, V>(
+    questions: F,
+    validate: V,
+    restart_from: Option<&str>,
+) -> Result<Answers>
+where
+    F: FnMut() -> Q,
+    Q: IntoIterator<Item = Question<'a>>,
+    V: Fn(&Answers) -> std::result::Result<(), String>,
+{
+    let stdout = std::io::stdout();
+    let mut stdout = ui::backend::get_backend(stdout.lock());
+    let mut events = ui::events::get_events();
+
+    prompt_validated_with(questions, validate, restart_from, &mut stdout, &mut events)
+}
+
+/// Prompt all the questions, with the given [`Backend`] and [`EventIterator`], and then validate
+/// all the answers together.
+///
+/// This is useful for constraints which involve more than one answer, for example that an end
+/// date is after a start date. Once all the questions are asked, `validate` is called with the
+/// answers. If it returns an error, the error is shown and the questions are asked again starting
+/// from the question named `restart_from`, keeping the answers to the questions before it. This
+/// repeats until `validate` succeeds. If `restart_from` is `None`, or there is no question with
+/// that name, all the questions are asked again.
+///
+/// As questions can only be asked once, `questions` is called to create them every time they are
+/// asked.
+///
+/// # Examples
+///
+/// ```no_run
+/// use requestty::{Answers, Question};
+///
+/// let answers = requestty::prompt_validated(
+///     || {
+///         vec![
+///             Question::int("start").build(),
+///             Question::int("end").build(),
+///         ]
+///     },
+///     |answers: &Answers| {
+///         if answers["end"].as_int() > answers["start"].as_int() {
+///             Ok(())
+///         } else {
+///             Err("The end must be after the start".into())
+///         }
+///     },
+///     Some("end"),
+/// )?;
+/// # Ok::<(), requestty::ErrorKind>(())
+/// ```
+pub fn prompt_validated_with<'a, F, Q, V, B, E>(
+    mut questions: F,
+    validate: V,
+    restart_from: Option<&str>,
+    backend: &mut B,
+    events: &mut E,
+) -> Result<Answers>
+where
+    F: FnMut() -> Q,
+    Q: IntoIterator<Item = Question<'a>>,
+    V: Fn(&Answers) -> std::result::Result<(), String>,
+    B: Backend,
+    E: EventIterator,
+{
+    let mut answers = prompt_with(questions(), backend, events)?;
+
+    loop {
+        let err = match validate(&answers) {
+            Ok(()) => return Ok(answers),
+            Err(err) => err,
+        };
+
+        backend.write_styled(&symbols::current().cross.red())?;
+        backend.write_all(b" ")?;
+        backend.write_all(err.as_bytes())?;
+        backend.write_all(b"\n")?;
+        backend.flush()?;
+
+        let mut questions: Vec<_> = questions().into_iter().collect();
+        let start = restart_from
+            .and_then(|name| {
+                questions
+                    .iter()
+                    .position(|question| question.name() == name)
+            })
+            .unwrap_or(0);
+        let questions = questions.split_off(start);
+
+        // The answers to the questions which are asked again have to be removed, otherwise the
+        // questions are skipped as they are already answered
+        for question in &questions {
+            answers.remove(question.name());
+        }
+
+        answers = PromptModule::new(questions)
+            .with_answers(answers)
+            .prompt_all_with(backend, events)?;
+    }
+}
+
+/// The messages of the questions asked after the review in [`prompt_with_review`].
+///
+/// # Examples
+///
+/// ```
+/// use requestty::ReviewMessages;
+///
+/// let messages = ReviewMessages {
+///     submit: "Create the project?",
+///     ..ReviewMessages::default()
+/// };
+/// assert_eq!(messages.change, "Which answer do you want to change?");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReviewMessages<'m> {
+    /// The message of the confirmation to submit the answers.
+    ///
+    /// Defaults to `Submit?`.
+    pub submit: &'m str,
+    /// The message of the choice of the answer to change, if the answers are not submitted.
+    ///
+    /// Defaults to `Which answer do you want to change?`.
+    pub change: &'m str,
+}
+
+impl Default for ReviewMessages<'_> {
+    fn default() -> Self {
+        Self {
+            submit: "Submit?",
+            change: "Which answer do you want to change?",
+        }
+    }
+}
+
+/// Prompt all the questions, with the default [`Backend`] and [`EventIterator`], and then show a
+/// review of the answers before they are submitted.
+///
+/// See [`prompt_with_review_with`] for more details.
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "crossterm", feature = "termion"))))]
+pub fn prompt_with_review<'a, F, Q>(questions: F, messages: ReviewMessages<'_>) -> Result<Answers>
+where
+    F: FnMut() -> Q,
+    Q: IntoIterator<Item = Question<'a>>,
+{
+    let stdout = std::io::stdout();
+    let mut stdout = ui::backend::get_backend(stdout.lock());
+    let mut events = ui::events::get_events();
+
+    prompt_with_review_with(questions, messages, &mut stdout, &mut events)
+}
+
+/// Prompt all the questions, with the given [`Backend`] and [`EventIterator`], and then show a
+/// review of the answers before they are submitted.
+///
+/// Once all the questions are asked, every answer is shown again in the same way as when the
+/// question was answered (see [`Question::render_answered`]), followed by a confirmation to submit
+/// them. If it is declined, one of the answers can be chosen by the message of its question to be
+/// changed. That question is asked again with the previous answer as its default, and then the
+/// `when` of every later question is checked again, since it may depend on the changed answer.
+/// Questions which are no longer asked lose their answers, and questions which are now asked are
+/// asked. The review is then shown once more, and this repeats until the answers are submitted.
+///
+/// The messages of the confirmation and the choice of answer are set by `messages`.
+///
+/// Questions which cannot be rendered as answered, like [`custom`](Question::custom) prompts, are
+/// left out of the review. As questions can only be asked once, `questions` is called to create
+/// them every time they are needed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use requestty::{Question, ReviewMessages};
+///
+/// let answers = requestty::prompt_with_review(
+///     || {
+///         vec![
+///             Question::input("name").build(),
+///             Question::int("age").build(),
+///         ]
+///     },
+///     ReviewMessages::default(),
+/// )?;
+/// # Ok::<(), requestty::ErrorKind>(())
+/// ```
+pub fn prompt_with_review_with<'a, F, Q, B, E>(
+    mut questions: F,
+    messages: ReviewMessages<'_>,
+    backend: &mut B,
+    events: &mut E,
+) -> Result<Answers>
+where
+    F: FnMut() -> Q,
+    Q: IntoIterator<Item = Question<'a>>,
+    B: Backend,
+    E: EventIterator,
+{
+    let mut answers = prompt_with(questions(), backend, events)?;
+
+    loop {
+        // The index of each reviewed question, along with its name and message
+        let mut reviewed: Vec<(usize, String, String)> = Vec::new();
+
+        for (i, mut question) in questions().into_iter().enumerate() {
+            let name = question.name().to_owned();
+            if reviewed.iter().any(|(_, reviewed, _)| *reviewed == name) {
+                continue;
+            }
+
+            let answer = match answers.get(&name) {
+                Some(answer) => answer,
+                None => continue,
+            };
+
+            let message = question.message(&answers);
+            match question.render_answered(answer, &answers, backend) {
+                Ok(()) => reviewed.push((i, name, message)),
+                // Either the question cannot be rendered as answered at all, or the answer is from
+                // another question with the same name
+                Err(ErrorKind::IoError(ref e)) if e.kind() == std::io::ErrorKind::InvalidInput => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        // There is nothing which can be changed
+        if reviewed.is_empty() {
+            return Ok(answers);
+        }
+
+        let submit = Question::confirm("submit")
+            .message(messages.submit)
+            .default(true)
+            .build();
+
+        if prompt_one_with(submit, backend, events)?.as_bool() != Some(false) {
+            return Ok(answers);
+        }
+
+        let change = Question::select("change")
+            .message(messages.change)
+            .choices(reviewed.iter().map(|(_, _, message)| message.clone()))
+            .build();
+
+        let chosen = prompt_one_with(change, backend, events)?
+            .as_list_item()
+            .map(|item| item.index)
+            .expect("select questions are answered with a list item");
+        let (index, ref name, _) = reviewed[chosen];
+
+        let mut earlier: Vec<_> = questions().into_iter().collect();
+        let later = earlier.split_off(index + 1);
+        let mut question = earlier
+            .pop()
+            .expect("`questions` returned fewer questions than before");
+
+        // The previous answer has to be removed, otherwise the question is skipped as it is
+        // already answered. It is kept if the question is not asked anymore.
+        let previous = answers.remove(name);
+        if let Some(ref previous) = previous {
+            question.prefill(previous);
+        }
+        match question.ask(&answers, backend, events)? {
+            Some((name, answer)) => {
+                answers.insert(name, answer);
+            }
+            None => {
+                if let Some(previous) = previous {
+                    answers.insert(name.clone(), previous);
+                }
+            }
+        }
+
+        // The answers of the later questions which are not asked anymore are removed, unless an
+        // earlier question with the same name is still answered
+        let mut kept: std::collections::HashSet<String> = earlier
+            .iter()
+            .map(|question| question.name().to_owned())
+            .collect();
+        kept.insert(name.clone());
+
+        for mut question in later {
+            let name = question.name().to_owned();
+
+            if !question.is_enabled(&answers) {
+                if !kept.contains(&name) {
+                    answers.remove(&name);
+                }
+                continue;
+            }
+
+            kept.insert(name);
+            if let Some((name, answer)) = question.ask(&answers, backend, events)? {
+                answers.insert(name, answer);
+            }
+        }
+    }
 }
 
 /// Prompt the given question, with the given [`Backend`] and [`EventIterator`].
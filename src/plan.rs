@@ -0,0 +1,69 @@
+use crate::{Answers, Question};
+
+/// What would happen to a question if it were prompted.
+///
+/// Each variant holds the name of the question. See [`plan`] for more details.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Plan {
+    /// The question would be asked.
+    Asked(String),
+    /// The question would not be asked as `when` returned `false`.
+    Skipped(String),
+    /// The question would not be asked as it is already present in the answers, and
+    /// `ask_if_answered` is not set.
+    AutoAnswered(String),
+}
+
+impl Plan {
+    /// The name of the question.
+    pub fn name(&self) -> &str {
+        match self {
+            Plan::Asked(name) | Plan::Skipped(name) | Plan::AutoAnswered(name) => name,
+        }
+    }
+}
+
+/// Records which of the given questions would be asked, without touching the terminal.
+///
+/// This is a dry run of [`prompt`] that can be used to check the `when` logic of a set of
+/// questions. Every question is checked against the given `answers` in the same way as it would
+/// be before prompting it, and `when` is called if it is a function. As nothing is actually asked,
+/// the `answers` are not added to, so a question which depends on the answer of a previous
+/// question in the list sees the same `answers` as the others.
+///
+/// [`prompt`]: crate::prompt()
+///
+/// # Examples
+///
+/// ```
+/// use requestty::{Answer, Answers, Plan, Question};
+///
+/// let questions = vec![
+///     Question::confirm("anonymous").build(),
+///     Question::input("name")
+///         .when(|previous_answers: &Answers| match previous_answers.get("anonymous") {
+///             Some(ans) => !ans.as_bool().unwrap(),
+///             None => true,
+///         })
+///         .build(),
+/// ];
+///
+/// let answers: Answers = std::iter::once(("anonymous".to_owned(), Answer::Bool(true))).collect();
+///
+/// assert_eq!(
+///     requestty::plan(questions, &answers),
+///     vec![
+///         Plan::AutoAnswered("anonymous".to_owned()),
+///         Plan::Skipped("name".to_owned()),
+///     ]
+/// );
+/// ```
+pub fn plan<'a, Q>(questions: Q, answers: &Answers) -> Vec<Plan>
+where
+    Q: IntoIterator<Item = Question<'a>>,
+{
+    questions
+        .into_iter()
+        .map(|question| question.plan(answers))
+        .collect()
+}
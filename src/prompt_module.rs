@@ -1,3 +1,5 @@
+use std::{collections::HashSet, fmt};
+
 use ui::{backend::Backend, events::EventIterator};
 
 use crate::{Answer, Answers, Question};
@@ -7,11 +9,18 @@ use crate::{Answer, Answers, Question};
 /// Unlike [`prompt`], this allows you to control how many questions you want to ask, and ask with
 /// previous answers as well.
 ///
+/// The questions are taken from the iterator one at a time as they are asked, and unlike
+/// [`prompt_with`], their names are not checked for duplicates.
+///
 /// [`prompt`]: crate::prompt()
+/// [`prompt_with`]: crate::prompt_with
 #[derive(Debug, Clone, PartialEq)]
 pub struct PromptModule<Q> {
     questions: Q,
     answers: Answers,
+    show_steps: bool,
+    set_title: bool,
+    step: usize,
 }
 
 impl<'a, Q> PromptModule<Q>
@@ -26,6 +35,9 @@ where
         Self {
             answers: Answers::default(),
             questions: questions.into_iter(),
+            show_steps: true,
+            set_title: false,
+            step: 0,
         }
     }
 
@@ -35,6 +47,42 @@ where
         self
     }
 
+    /// Show the progress through the questions as `[current/total]` in each prompt.
+    ///
+    /// The step is counted over all the questions, including the ones which are not asked because
+    /// of `when` or `ask_if_answered`. It is only shown if there is more than one question, and
+    /// their number is known upfront, which is the case for a [`Vec`] or an array for example. A
+    /// question which has its own [`step`] set is not changed.
+    ///
+    /// If it is not set, it defaults to `true`.
+    ///
+    /// [`step`]: crate::question::InputBuilder::step
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{PromptModule, Question};
+    ///
+    /// let module = PromptModule::new(vec![
+    ///     Question::input("name").build(),
+    ///     Question::confirm("anonymous").build(),
+    /// ])
+    /// .show_steps(false);
+    /// ```
+    pub fn show_steps(mut self, show_steps: bool) -> Self {
+        self.show_steps = show_steps;
+        self
+    }
+
+    /// Set the window title to the message of each question while it is asked.
+    ///
+    /// The title is restored once the question is answered. If it is not set, it defaults to
+    /// `false`.
+    pub fn set_title_to_message(mut self, set_title: bool) -> Self {
+        self.set_title = set_title;
+        self
+    }
+
     /// Prompt a single question with the default [`Backend`] and [`EventIterator`].
     ///
     /// This may or may not actually prompt the question based on what `when` and `ask_if_answered`
@@ -61,7 +109,19 @@ where
         B: Backend,
         E: EventIterator,
     {
-        for question in self.questions.by_ref() {
+        while let Some(mut question) = self.questions.next() {
+            self.step += 1;
+
+            if self.show_steps {
+                if let (remaining, Some(upper)) = self.questions.size_hint() {
+                    if remaining == upper && self.step + remaining > 1 {
+                        question.set_default_step(self.step, self.step + remaining);
+                    }
+                }
+            }
+
+            question.set_title_to_message(self.set_title);
+
             if let Some((name, answer)) = question.ask(&self.answers, backend, events)? {
                 return Ok(Some(self.answers.insert(name, answer)));
             }
@@ -108,6 +168,115 @@ where
     }
 }
 
+/// The error returned by [`prompt_with`] when a question can never be asked, since an earlier
+/// question with the same name is always answered.
+///
+/// It is returned inside an [`ErrorKind::IoError`] of kind [`InvalidInput`].
+///
+/// [`prompt_with`]: crate::prompt_with
+/// [`ErrorKind::IoError`]: crate::ErrorKind::IoError
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateNameError {
+    name: String,
+}
+
+impl DuplicateNameError {
+    /// The name shared by the question and the earlier question.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for DuplicateNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the question `{}` can never be asked, since an earlier question with the same name is \
+             always answered",
+            self.name
+        )
+    }
+}
+
+impl std::error::Error for DuplicateNameError {}
+
+/// Finds the questions which can never be asked, because an earlier question with the same name is
+/// always answered.
+#[derive(Default)]
+pub(crate) struct NameCheck {
+    answered: HashSet<String>,
+}
+
+impl NameCheck {
+    /// Checks the next question, after all the questions passed to this so far.
+    pub(crate) fn check(&mut self, question: &Question<'_>) -> Result<(), DuplicateNameError> {
+        let name = question.name();
+
+        if self.answered.contains(name) && !question.asks_if_answered() {
+            return Err(DuplicateNameError {
+                name: name.to_owned(),
+            });
+        }
+
+        if question.is_always_answered() {
+            self.answered.insert(name.to_owned());
+        }
+
+        Ok(())
+    }
+}
+
+/// An iterator over questions which ends at the first question that can never be asked, because
+/// an earlier question with the same name is always answered. The error is stored in `duplicate`.
+pub(crate) struct CheckNames<'e, I> {
+    questions: I,
+    names: NameCheck,
+    duplicate: &'e mut Option<DuplicateNameError>,
+}
+
+impl<'e, I> CheckNames<'e, I> {
+    pub(crate) fn new(questions: I, duplicate: &'e mut Option<DuplicateNameError>) -> Self {
+        Self {
+            questions,
+            names: NameCheck::default(),
+            duplicate,
+        }
+    }
+}
+
+impl<'a, I> Iterator for CheckNames<'_, I>
+where
+    I: Iterator<Item = Question<'a>>,
+{
+    type Item = Question<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.duplicate.is_some() {
+            return None;
+        }
+
+        let question = self.questions.next()?;
+
+        match self.names.check(&question) {
+            Ok(()) => Some(question),
+            Err(e) => {
+                *self.duplicate = Some(e);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.duplicate.is_some() {
+            (0, Some(0))
+        } else {
+            // The questions only end early on an error, in which case the answers are not used
+            self.questions.size_hint()
+        }
+    }
+}
+
 /// A macro to easily write a [`PromptModule`].
 ///
 /// # Usage
@@ -3,7 +3,11 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use ui::{style::Color, widgets::List, Widget};
+use ui::{
+    style::{Attributes, Color},
+    widgets::{List, ReorderList, Text},
+    Widget,
+};
 
 use crate::ExpandItem;
 
@@ -96,30 +100,106 @@ impl<T> IndexMut<usize> for SelectList<T> {
     }
 }
 
-impl<T: Widget> List for SelectList<T> {
-    fn render_item<B: ui::backend::Backend>(
+impl<T: Widget> SelectList<T> {
+    /// Renders the item at `index` like [`List::render_item`], except that the item is rendered by
+    /// `render` with the layout after the pointer. It is dimmed if it is not `selectable`.
+    pub(crate) fn render_item_with<B, F>(
         &mut self,
         index: usize,
         hovered: bool,
+        selectable: bool,
         mut layout: ui::layout::Layout,
         b: &mut B,
-    ) -> io::Result<()> {
+        render: F,
+    ) -> io::Result<()>
+    where
+        B: ui::backend::Backend,
+        F: FnOnce(&mut T, &mut ui::layout::Layout, &mut B) -> io::Result<()>,
+    {
         if hovered {
             b.set_fg(Color::Cyan)?;
             write!(b, "{} ", ui::symbols::current().pointer)?;
         } else {
             b.write_all(b"  ")?;
 
-            if !self.is_selectable(index) {
+            if !selectable {
                 b.set_fg(Color::DarkGrey)?;
             }
         }
 
         layout.offset_x += 2;
-        self.choices[index].render(&mut layout, b)?;
+        render(&mut self.choices[index], &mut layout, b)?;
 
         b.set_fg(Color::Reset)
     }
+}
+
+impl SelectList<Choice<Text<String>>> {
+    /// Renders the choice at `index` like [`List::render_item`], with its first `matched`
+    /// characters underlined. `prefix` renders what comes between the pointer and the text.
+    ///
+    /// The text is only underlined if it fits on the line, otherwise it is rendered as usual.
+    pub(crate) fn render_matched_item_with<B, F>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: ui::layout::Layout,
+        b: &mut B,
+        prefix: F,
+    ) -> io::Result<()>
+    where
+        B: ui::backend::Backend,
+        F: FnOnce(&mut ui::layout::Layout, &mut B) -> io::Result<()>,
+    {
+        let selectable = self.is_selectable(index);
+        self.render_item_with(
+            index,
+            hovered,
+            selectable,
+            layout,
+            b,
+            |choice, layout, b| {
+                prefix(layout, b)?;
+
+                let text = match choice {
+                    Choice::Choice(text)
+                        if !text.text.contains('\n')
+                            && textwrap::core::display_width(&text.text)
+                                <= layout.line_width() as usize =>
+                    {
+                        &text.text
+                    }
+                    _ => return choice.render(layout, b),
+                };
+
+                let split = text
+                    .char_indices()
+                    .nth(matched)
+                    .map_or(text.len(), |(i, _)| i);
+
+                b.set_attributes(Attributes::UNDERLINED)?;
+                b.write_all(&text.as_bytes()[..split])?;
+                b.set_attributes(Attributes::empty())?;
+                b.write_all(&text.as_bytes()[split..])
+            },
+        )
+    }
+}
+
+impl<T: Widget> List for SelectList<T> {
+    fn render_item<B: ui::backend::Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        layout: ui::layout::Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        let selectable = self.is_selectable(index);
+        self.render_item_with(index, hovered, selectable, layout, b, |item, layout, b| {
+            item.render(layout, b)
+        })
+    }
 
     fn is_selectable(&self, index: usize) -> bool {
         (self.is_selectable)(&self.choices[index])
@@ -144,6 +224,19 @@ impl<T: Widget> List for SelectList<T> {
     }
 }
 
+impl<T: Widget> ReorderList for SelectList<T> {
+    fn swap(&mut self, a: usize, b: usize) {
+        self.choices.swap(a, b);
+
+        // the default should keep pointing to the same choice
+        if self.default == a {
+            self.default = b;
+        } else if self.default == b {
+            self.default = a;
+        }
+    }
+}
+
 pub(crate) type ChoiceList<T> = SelectList<Choice<T>>;
 
 impl<T> std::iter::FromIterator<T> for ChoiceList<T> {
@@ -231,7 +324,43 @@ impl<T> Choice<T> {
     }
 }
 
+/// Whether the choice at `index` is disabled, given the reason each choice is disabled, or `None`
+/// if it is not. Choices after the end of `disabled` are not disabled.
+pub(crate) fn is_disabled(disabled: &[Option<String>], index: usize) -> bool {
+    matches!(disabled.get(index), Some(Some(_)))
+}
+
+/// The reason the choice at `index` is disabled, if it is disabled with a non-empty reason.
+pub(crate) fn disabled_reason(disabled: &[Option<String>], index: usize) -> Option<&str> {
+    match disabled.get(index) {
+        Some(Some(reason)) if !reason.is_empty() => Some(reason),
+        _ => None,
+    }
+}
+
 #[inline]
+/// The text of a disabled choice followed by the `reason` it is disabled in brackets, if there is a
+/// reason and they fit on a single line of `line_width`.
+pub(crate) fn with_disabled_reason(
+    choice: &Choice<Text<String>>,
+    reason: Option<&str>,
+    line_width: u16,
+) -> Option<String> {
+    match (choice, reason) {
+        (Choice::Choice(text), Some(reason)) if !text.text.contains('\n') => {
+            let width = textwrap::core::display_width(&text.text)
+                + textwrap::core::display_width(reason)
+                + 3;
+            if width <= line_width as usize {
+                Some(format!("{} ({})", text.text, reason))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn get_sep_str<T>(separator: &Choice<T>) -> &str {
     match separator {
         Choice::Choice(_) => unreachable!(),
@@ -289,3 +418,22 @@ impl<I: Into<String>> From<(I, bool)> for Choice<(String, bool)> {
         Choice::Choice((text.into(), checked))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_swap_keeps_default() {
+        let mut list: ChoiceList<String> = (0..3).map(|i| i.to_string()).collect();
+        list.set_default(1);
+
+        list.swap(0, 1);
+        assert_eq!(list.default(), Some(0));
+        list.swap(0, 2);
+        assert_eq!(list.default(), Some(2));
+        list.swap(0, 1);
+        assert_eq!(list.default(), Some(2));
+        assert_eq!(list[2].as_ref().unwrap_choice(), "1");
+    }
+}
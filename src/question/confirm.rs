@@ -4,7 +4,8 @@ use ui::{
     backend::Backend,
     events::{EventIterator, KeyEvent},
     style::Stylize,
-    widgets, Prompt, Validation, Widget,
+    widgets::{self, AnswerAlign},
+    Prompt, Validation, Widget,
 };
 
 use super::{Options, TransformByVal as Transform};
@@ -71,9 +72,18 @@ impl Prompt for ConfirmPrompt<'_> {
                 .expect("Validation would fail if there was no answer and no default"),
         }
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl<'a> Confirm<'a> {
+    /// Sets the default to a previous answer.
+    pub(super) fn prefill(&mut self, answer: bool) {
+        self.default = Some(answer);
+    }
+
     fn into_confirm_prompt(self, message: &'a str) -> ConfirmPrompt<'a> {
         let hint = match self.default {
             Some(true) => "Y/n",
@@ -91,22 +101,29 @@ impl<'a> Confirm<'a> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_confirm_prompt(&message), b)
-            .on_esc(on_esc)
+        let mut prompt = self.into_confirm_prompt(&message);
+        prompt.prompt.set_step(opts.step);
+
+        let ans = ui::Input::new(prompt, b)
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans, answers, b, |ans| {
-            let ans = if ans { "Yes" } else { "No" };
-            b.write_styled(&ans.cyan())?;
-        })
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
     }
+
+    crate::impl_write_answer!(self; Transform<'_, bool>, bool; |message, ans, b| {
+        let ans = if ans { "Yes" } else { "No" };
+        b.write_styled(&ans.cyan())?;
+    });
 }
 
 /// The builder for a [`confirm`] prompt.
@@ -190,6 +207,39 @@ impl<'a> ConfirmBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the confirm
@@ -229,6 +279,21 @@ impl<'a> ConfirmBuilder<'a> {
     by val bool; confirm
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let confirm = Question::confirm("anonymous")
+    ///     .display_transform(|anonymous, previous_answers| {
+    ///         if anonymous { "Anonymous".into() } else { "Named".into() }
+    ///     })
+    ///     .build();
+    /// ```
+    by val bool; confirm
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
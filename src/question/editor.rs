@@ -5,7 +5,13 @@ use std::{
     process::Command,
 };
 
-use ui::{backend::Backend, events::EventIterator, style::Stylize, widgets, Validation, Widget};
+use ui::{
+    backend::Backend,
+    events::EventIterator,
+    style::Stylize,
+    widgets::{self, AnswerAlign},
+    Validation, Widget,
+};
 
 use super::{Filter, Options, Transform, Validate};
 use crate::{Answer, Answers, Question};
@@ -51,7 +57,7 @@ fn get_editor() -> Command {
             if cfg!(windows) {
                 vec!["notepad".into()]
             } else {
-                vec!["vim".into()]
+                vec!["vi".into()]
             }
         });
 
@@ -130,13 +136,22 @@ impl ui::Prompt for EditorPrompt<'_, '_> {
             _ => self.ans,
         }
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl Editor<'_> {
+    /// Sets the default to a previous answer.
+    pub(super) fn prefill(&mut self, answer: String) {
+        self.default = Some(answer);
+    }
+
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -167,7 +182,8 @@ impl Editor<'_> {
             EditorPrompt {
                 prompt: widgets::Prompt::new(&*message)
                     .with_hint("Press <enter> to launch your preferred editor.")
-                    .with_delim(widgets::Delimiter::None),
+                    .with_delim(widgets::Delimiter::None)
+                    .with_optional_step(opts.step),
                 editor: self,
                 file,
                 ans: String::new(),
@@ -175,12 +191,18 @@ impl Editor<'_> {
             },
             b,
         )
-        .on_esc(on_esc)
+        .on_esc(opts.on_esc)
+        .skip_key(opts.skip_key)
+        .set_title_to_message(opts.set_title)
+        // The editor is run while validating, and needs the terminal to itself
+        .suspend_on_submit(true)
         .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |_ans| b
-            .write_styled(&"Received".dark_grey())?)
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
     }
+
+    crate::impl_write_answer!(self; Transform<'_, str>, String [ref]; |message, _ans, b| b
+        .write_styled(&"Received".dark_grey())?);
 }
 
 /// The builder for the [`Question::editor`] prompt.
@@ -188,7 +210,7 @@ impl Editor<'_> {
 /// Once the user exits their editor, the contents of the temporary file are read in as the
 /// result. The editor to use can be specified by the [`editor`] method. If unspecified, the editor
 /// is determined by the `$VISUAL` or `$EDITOR` environment variables. If neither of those are
-/// present, `vim` (for unix) or `notepad` (for windows) is used.
+/// present, `vi` (for unix) or `notepad` (for windows) is used.
 ///
 /// <img
 ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/editor.gif"
@@ -270,6 +292,39 @@ impl<'a> EditorBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let editor = Question::editor("description")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the file
@@ -314,7 +369,7 @@ impl<'a> EditorBuilder<'a> {
     /// Use a specific editor instead of the default editor
     ///
     /// If unspecified, the editor is determined by the `$VISUAL` or `$EDITOR` environment
-    /// variables. If neither of those are present, `vim` (for unix) or `notepad` (for windows) is
+    /// variables. If neither of those are present, `vi` (for unix) or `notepad` (for windows) is
     /// used.
     ///
     /// # Examples
@@ -380,6 +435,21 @@ impl<'a> EditorBuilder<'a> {
     str; editor
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let editor = Question::editor("description")
+    ///     .display_transform(|description, previous_answers| {
+    ///         format!("{} lines", description.lines().count())
+    ///     })
+    ///     .build();
+    /// ```
+    str; editor
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
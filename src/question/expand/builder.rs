@@ -104,6 +104,39 @@ impl<'a> ExpandBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default key for the expand
@@ -335,6 +368,19 @@ impl<'a> ExpandBuilder<'a> {
     ExpandItem; expand
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let expand = Question::expand("overwrite")
+    ///     .display_transform(|choice, previous_answers| choice.text.to_lowercase())
+    ///     .build();
+    /// ```
+    ExpandItem; expand
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
@@ -4,7 +4,7 @@ use ui::{
     backend::{Backend, MoveDirection},
     events::{EventIterator, KeyEvent},
     style::{Color, Stylize},
-    widgets::{self, Text},
+    widgets::{self, AnswerAlign, Text},
     Prompt, Validation, Widget,
 };
 
@@ -113,21 +113,27 @@ impl<F: Fn(char) -> Option<char>> Prompt for ExpandPrompt<'_, F> {
     type Output = ExpandItem;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
-        match self.input.value().unwrap_or(self.select.list.default) {
-            'h' => {
-                self.expanded = true;
-                self.input.clear_value();
-                self.select.list.selected = None;
-                Ok(Validation::Continue)
-            }
-            _ => Ok(Validation::Finish),
-        }
+        let expanded = match self.input.value() {
+            // Typing the help key toggles the list of options
+            Some('h') => !self.expanded,
+            None if self.select.list.default == 'h' => true,
+            _ => return Ok(Validation::Finish),
+        };
+
+        self.expanded = expanded;
+        self.input.clear_value();
+        self.select.list.selected = None;
+        Ok(Validation::Continue)
     }
 
     fn finish(self) -> Self::Output {
         let c = self.input.value().unwrap_or(self.select.list.default);
         self.finish_with(c)
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
@@ -276,6 +282,11 @@ impl widgets::List for Expand<'_> {
 }
 
 impl Expand<'_> {
+    /// Sets the default to the key of a previous answer.
+    pub(super) fn prefill(&mut self, answer: &ExpandItem) {
+        self.default = answer.key;
+    }
+
     fn has_valid_default(&self) -> bool {
         self.default == 'h'
             || self.choices.choices.iter().any(
@@ -322,7 +333,7 @@ impl Expand<'_> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
@@ -347,7 +358,9 @@ impl Expand<'_> {
 
         let ans = ui::Input::new(
             ExpandPrompt {
-                prompt: widgets::Prompt::new(&*message).with_hint(&hint),
+                prompt: widgets::Prompt::new(&*message)
+                    .with_optional_step(opts.step)
+                    .with_hint(&hint),
                 input: widgets::CharInput::with_filter_map(|c| {
                     let c = c.to_ascii_lowercase();
                     hint.chars()
@@ -359,15 +372,20 @@ impl Expand<'_> {
             },
             b,
         )
-        .on_esc(on_esc)
+        .on_esc(opts.on_esc)
+        .skip_key(opts.skip_key)
+        .set_title_to_message(opts.set_title)
         .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
+    }
+
+    crate::impl_write_answer!(self; Transform<'_, ExpandItem>, ExpandItem [ref]; |message, ans, b| b
+        .write_styled(
             &ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
                 .cyan()
-        )?)
-    }
+        )?);
 }
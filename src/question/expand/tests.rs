@@ -224,3 +224,28 @@ fn test_cursor_pos() {
     assert!(expand.handle_key(KeyCode::Char('c').into()));
     assert_eq!(expand.cursor_pos(layout), (11, 10));
 }
+
+#[test]
+fn test_toggle_expanded() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(0, size);
+
+    expand!(let mut expand; "message");
+
+    assert!(expand.handle_key(KeyCode::Char('h').into()));
+    assert_eq!(expand.validate(), Ok(Validation::Continue));
+    assert!(expand.expanded);
+
+    // Submitting the default help option keeps the options listed
+    assert_eq!(expand.validate(), Ok(Validation::Continue));
+    assert!(expand.expanded);
+
+    assert!(expand.handle_key(KeyCode::Char('H').into()));
+    assert_eq!(expand.validate(), Ok(Validation::Continue));
+    assert!(!expand.expanded);
+    assert_eq!(expand.input.value(), None);
+
+    let mut layout = base_layout;
+    assert_eq!(expand.height(&mut layout), 1);
+    assert_eq!(layout, base_layout.with_line_offset(21));
+}
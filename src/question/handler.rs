@@ -1,6 +1,6 @@
-use std::fmt;
+use std::{fmt, io};
 
-use ui::backend::Backend;
+use ui::{backend::Backend, layout::Layout, style::Color, widgets, Widget};
 
 use super::Completions;
 use crate::Answers;
@@ -72,8 +72,78 @@ handler!(Validate, ?Sized FnMut(&T, &Answers) -> Result<(), String>);
 handler!(ValidateByVal, FnMut(T, &Answers) -> Result<(), String>);
 handler!(ValidateOnKey, ?Sized FnMut(&T, &Answers) -> bool);
 handler!(ValidateOnKeyByVal, FnMut(T, &Answers) -> bool);
+handler!(Accessory, ?Sized FnMut(&T, &Answers) -> String);
 handler!(Transform, ?Sized FnOnce(&T, &Answers, &mut dyn Backend) -> std::io::Result<()>);
 handler!(
     TransformByVal,
     FnOnce(T, &Answers, &mut dyn Backend) -> std::io::Result<()>
 );
+
+/// A hint which is recomputed every time the prompt is rendered.
+#[derive(Default)]
+pub(super) struct DynamicHint<'a>(pub(super) Option<Box<dyn FnMut() -> String + 'a>>);
+
+impl DynamicHint<'_> {
+    /// Recomputes the hint of the `prompt` if there is one. An empty hint is rendered as if there
+    /// was no hint.
+    pub(super) fn refresh<M: AsRef<str>>(&mut self, prompt: &mut widgets::Prompt<M, String>) {
+        if let Some(ref mut hint) = self.0 {
+            let hint = hint();
+            prompt.set_hint(if hint.is_empty() { None } else { Some(hint) });
+        }
+    }
+}
+
+impl fmt::Debug for DynamicHint<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(_) => f.write_str("DynamicHint(_)"),
+            None => f.write_str("DynamicHint(None)"),
+        }
+    }
+}
+
+impl Accessory<'_, str> {
+    /// Renders the accessory on its own line after the prompt, given the current text of the
+    /// input. The layout is moved to the start of the line after it.
+    pub(super) fn render<B: Backend>(
+        &mut self,
+        text: &str,
+        answers: &Answers,
+        layout: &mut Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        if let Accessory::Sync(ref mut accessory) = self {
+            layout.offset_y += 1;
+            layout.line_offset = 0;
+            b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+
+            b.set_fg(Color::DarkGrey)?;
+            accessory(text, answers).render(layout, b)?;
+            b.set_fg(Color::Reset)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves the layout past the accessory in the same way as `render`, returning the number of
+    /// lines it adds to the height of the prompt.
+    pub(super) fn height(&self, layout: &mut Layout) -> u16 {
+        match self {
+            Accessory::Sync(_) => {
+                layout.offset_y += 2;
+                layout.line_offset = 0;
+                2
+            }
+            Accessory::None => 0,
+        }
+    }
+
+    /// The position the input starts at, given where the prompt ends.
+    pub(super) fn input_pos(&self, prompt_end: (u16, u16)) -> (u16, u16) {
+        match self {
+            Accessory::Sync(_) => (0, prompt_end.1 + 2),
+            Accessory::None => prompt_end,
+        }
+    }
+}
@@ -141,6 +141,58 @@ macro_rules! impl_validate_on_key_builder {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_accessory_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// A line to show between the message and the input, which is updated as the user types.
+        ///
+        /// It is a [`FnMut`] that is given the text typed so far and the previous [`Answers`], and
+        /// should return the line to show. It can be used for help on the current input, or a live
+        /// preview of the answer. The line is dimmed, and is cut short if it does not fit.
+        ///
+        /// [`Answers`]: crate::Answers
+        ///
+        ///
+        $(#[$meta])*
+        pub fn accessory<F>(mut self, accessory: F) -> Self
+        where
+            F: FnMut(&str, &$crate::Answers) -> String + 'a,
+        {
+            self.$inner.accessory = $crate::question::Accessory::Sync(Box::new(accessory));
+            self
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_dynamic_hint_builder {
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ $inner:ident) => {
+        /// A hint to show after the message, which is recomputed every time the prompt is
+        /// rendered.
+        ///
+        /// It is a [`FnMut`] that should return the hint. It is not given the text typed so far, but
+        /// it can share state with a function that is, such as [`validate_on_key`].
+        ///
+        /// [`validate_on_key`]: Self::validate_on_key
+        ///
+        ///
+        $(#[$meta])*
+        pub fn dynamic_hint<F>(mut self, dynamic_hint: F) -> Self
+        where
+            F: FnMut() -> String + 'a,
+        {
+            self.$inner.dynamic_hint = $crate::question::DynamicHint(Some(Box::new(dynamic_hint)));
+            self
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! impl_transform_builder {
@@ -179,10 +231,48 @@ macro_rules! impl_transform_builder {
 
 #[doc(hidden)]
 #[macro_export]
-macro_rules! write_final {
-    ($transform:expr, $message:expr, $ans:ident $([$tt:tt])?, $answers:expr, $backend:expr, |$ident:ident| $custom:expr) => {{
-        ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
+macro_rules! impl_display_transform_builder {
+    ($(#[$meta:meta])+ $t:ty; $inner:ident) => {
+        $crate::impl_display_transform_builder!($(#[$meta])* impl &$t; $inner);
+    };
 
+    ($(#[$meta:meta])+ by val $t:ty; $inner:ident) => {
+        $crate::impl_display_transform_builder!($(#[$meta])* impl $t; $inner);
+    };
+
+    // NOTE: the 2 extra lines at the end of each doc comment is intentional -- it makes sure that
+    // other docs that come from the macro invocation have appropriate spacing
+    ($(#[$meta:meta])+ impl $t:ty; $inner:ident) => {
+        /// Change the text shown for the answer once the question is finished, without changing the
+        /// answer that is returned.
+        ///
+        /// It is a [`FnOnce`] that is given the answer and previous [`Answers`], and returns the text
+        /// to display in place of the answer. It is a simpler form of [`transform`], and since both
+        /// change the same thing, only the one which is called last is used.
+        ///
+        /// When the user submits, `validate` is called first. Once it passes, `filter` is applied
+        /// to get the answer, and then `display_transform` is given the filtered answer.
+        ///
+        /// [`Answers`]: crate::Answers
+        /// [`transform`]: Self::transform
+        ///
+        ///
+        $(#[$meta])*
+        pub fn display_transform<F>(self, display_transform: F) -> Self
+        where
+            F: FnOnce($t, &$crate::Answers) -> String + 'a,
+        {
+            self.transform(move |ans: $t, answers: &$crate::Answers, b: &mut dyn Backend| {
+                b.write_styled(&ui::style::Stylize::cyan(display_transform(ans, answers)))
+            })
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! write_final {
+    (@answer $transform:expr, $ans:ident $([$tt:tt])?, $answers:expr, $backend:ident, |$ident:ident| $custom:expr) => {
         // Weird reborrowing trick to make sure ans is not moved when $tt is ref, but is copied when
         // $tt is not there
         match (&$ans, $transform) {
@@ -192,6 +282,34 @@ macro_rules! write_final {
                 $backend.write_styled(&ui::style::Stylize::dark_grey("Skipped"))?;
             }
         }
+    };
+
+    ($transform:expr, $message:expr, $ans:ident $([$tt:tt])?, $answer_align:expr, $offset_x:expr, $answers:expr, $backend:ident, |$ident:ident| $custom:expr) => {{
+        ui::widgets::Prompt::write_finished_message(&$message, $ans.is_none(), $backend)?;
+
+        match $answer_align {
+            ui::widgets::AnswerAlign::Left => {
+                $crate::write_final!(@answer $transform, $ans $([$tt])?, $answers, $backend, |$ident| $custom);
+            }
+            answer_align => {
+                // The answer is recorded first so that it can be measured before it is written
+                let mut record = ui::backend::RecordBackend::new($backend.size()?);
+                {
+                    let $backend = &mut record;
+                    $crate::write_final!(@answer $transform, $ans $([$tt])?, $answers, $backend, |$ident| $custom);
+                }
+
+                // The finished message is written starting at `$offset_x`, and wraps at the edge of
+                // the terminal
+                let size = $backend.size()?;
+                let column = ($offset_x as usize
+                    + ui::widgets::Prompt::finished_message_width(&$message) as usize)
+                    % (size.width.max(1) as usize);
+                let layout = ui::layout::Layout::new(column as u16, size);
+                answer_align.write_padding(record.width(), layout, $backend)?;
+                record.replay($backend)?;
+            }
+        }
 
         $backend.write_all(b"\n")?;
         $backend.flush()?;
@@ -199,3 +317,65 @@ macro_rules! write_final {
         Ok($ans.map($crate::answer::Answer::from))
     }};
 }
+
+/// Implements `render_answered` and `write_answer` for a question, which write the finished prompt
+/// with [`write_final!`].
+///
+/// The arguments of `write_answer` which come after the answer are listed with the value
+/// `render_answered` passes for them, in which `$self` is the question being rendered. If the
+/// finished prompt is not written at the start of the line, `@offset_x` is the column it starts at.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_write_answer {
+    (
+        $(#[$meta:meta])*
+        $self:ident; $transform:ty, $ans_ty:ty $([$tt:tt])?;
+        $($extra:ident: $extra_ty:ty = $extra_value:expr;)*
+        $(@offset_x = $offset_x:expr;)?
+        |$message:ident, $ans:ident, $backend:ident| $custom:expr
+    ) => {
+        /// Renders the question as it is shown once it is finished with `ans`, without asking it.
+        pub(crate) fn render_answered<B: Backend>(
+            mut $self,
+            message: String,
+            ans: Option<$ans_ty>,
+            answer_align: AnswerAlign,
+            answers: &Answers,
+            b: &mut B,
+        ) -> ui::Result<()> {
+            Self::write_answer(
+                $self.transform.take(),
+                message,
+                ans,
+                $($extra_value,)*
+                answer_align,
+                answers,
+                b,
+            )
+            .map(|_| ())
+        }
+
+        /// Writes the finished prompt with the answer, or that it was skipped if there is no answer.
+        $(#[$meta])*
+        fn write_answer<B: Backend>(
+            transform: $transform,
+            $message: String,
+            $ans: Option<$ans_ty>,
+            $($extra: $extra_ty,)*
+            answer_align: AnswerAlign,
+            answers: &Answers,
+            $backend: &mut B,
+        ) -> ui::Result<Option<Answer>> {
+            $crate::write_final!(
+                transform,
+                $message,
+                $ans $([$tt])?,
+                answer_align,
+                0 $(+ $offset_x)?,
+                answers,
+                $backend,
+                |$ans| $custom
+            )
+        }
+    };
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use ui::backend::Backend;
 
 use super::Input;
@@ -88,6 +90,39 @@ impl<'a> InputBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let input = Question::input("name")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let input = Question::input("name")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let input = Question::input("name")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default value for the input
@@ -113,6 +148,53 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// Read the default value from a file when the question is asked.
+    ///
+    /// The contents of the file, without a trailing newline, are used in the same way as a
+    /// [`default`]. This can be used for a default which is large, or is kept somewhere else. If
+    /// the file cannot be read, the [`default`] is used instead if there is one, unless
+    /// [`default_file_required`] is set.
+    ///
+    /// [`default`]: InputBuilder::default
+    /// [`default_file_required`]: InputBuilder::default_file_required
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .default_file("last_name.txt")
+    ///     .build();
+    /// ```
+    pub fn default_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.input.default_file.path = Some(path.into());
+        self
+    }
+
+    /// Whether asking the question should fail if the [`default_file`] cannot be read.
+    ///
+    /// If set, the error from reading the file is returned when the question is asked.
+    ///
+    /// If `default_file_required` is not set, it will default to `false`.
+    ///
+    /// [`default_file`]: InputBuilder::default_file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .default_file("last_name.txt")
+    ///     .default_file_required(true)
+    ///     .build();
+    /// ```
+    pub fn default_file_required(mut self, required: bool) -> Self {
+        self.input.default_file.required = required;
+        self
+    }
+
     crate::impl_auto_complete_builder! {
     /// # Examples
     ///
@@ -182,6 +264,149 @@ impl<'a> InputBuilder<'a> {
         self
     }
 
+    /// Whether to remove leading and trailing whitespace from the answer.
+    ///
+    /// The trimming is done before `validate` is called, so an input consisting only of
+    /// whitespace is treated as empty. If a [`default`] is set, it will be used in that case.
+    ///
+    /// If `trim` is not set, it will default to `false`.
+    ///
+    /// [`default`]: InputBuilder::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .trim(true)
+    ///     .build();
+    /// ```
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.input.trim = trim;
+        self
+    }
+
+    /// Whether to replace every run of whitespace in the answer with a single space.
+    ///
+    /// Like [`trim`], this is done before `validate` is called. Note that this does not remove
+    /// leading and trailing whitespace, it only shortens it to a single space. Use it along with
+    /// [`trim`] to get fully normalised text.
+    ///
+    /// If `collapse_whitespace` is not set, it will default to `false`.
+    ///
+    /// [`trim`]: InputBuilder::trim
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .trim(true)
+    ///     .collapse_whitespace(true)
+    ///     .build();
+    /// ```
+    pub fn collapse_whitespace(mut self, collapse_whitespace: bool) -> Self {
+        self.input.collapse_whitespace = collapse_whitespace;
+        self
+    }
+
+    /// Whether the answer can span multiple lines.
+    ///
+    /// If set, pressing `Enter` inserts a newline, and the answer is submitted with `Ctrl+D`
+    /// instead. Only the first line of the answer is shown once the question is answered.
+    ///
+    /// If `multiline` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("notes")
+    ///     .message("Any notes? (Ctrl+D to submit)")
+    ///     .multiline(true)
+    ///     .build();
+    /// ```
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.input.multiline = multiline;
+        self
+    }
+
+    /// Whether to mark the answer as `(modified)` once the question is answered, if it is not the
+    /// same as the [`default`].
+    ///
+    /// If there is a default, the input starts with it so that it can be edited. Leaving it as is,
+    /// editing it back to the default, or clearing the input is not a modification. Without a
+    /// default, anything that is typed is. The mark is not shown if there is a [`transform`].
+    ///
+    /// If `mark_modified` is not set, it will default to `false`.
+    ///
+    /// [`default`]: InputBuilder::default
+    /// [`transform`]: InputBuilder::transform
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .default("John Doe")
+    ///     .mark_modified(true)
+    ///     .build();
+    /// ```
+    pub fn mark_modified(mut self, mark_modified: bool) -> Self {
+        self.input.mark_modified = mark_modified;
+        self
+    }
+
+    /// The number of columns to indent the prompt by.
+    ///
+    /// The whole prompt, including the answer once it is submitted, is shifted to the right. This
+    /// can be used to nest questions under a header. Note that if the text entered is long enough
+    /// to wrap, the wrapped lines start at the beginning of the terminal line.
+    ///
+    /// If `indent` is not set, it will default to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("street")
+    ///     .message("Street")
+    ///     .indent(4)
+    ///     .build();
+    /// ```
+    pub fn indent(mut self, indent: u16) -> Self {
+        self.input.indent = indent;
+        self
+    }
+
+    /// A writer to copy the finished prompt to once the question is answered.
+    ///
+    /// The line shown after the question is answered (or skipped) is written to `transcript` as
+    /// plain text, without any colours or other escape codes. This can be used to keep a log of
+    /// the answers given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let mut log = Vec::new();
+    ///
+    /// let input = Question::input("name")
+    ///     .message("What is your name?")
+    ///     .transcript(&mut log)
+    ///     .build();
+    /// ```
+    pub fn transcript<W: std::io::Write + 'a>(mut self, transcript: W) -> Self {
+        self.input.transcript = Some(super::Transcript(Box::new(transcript)));
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -235,6 +460,42 @@ impl<'a> InputBuilder<'a> {
     str; input
     }
 
+    crate::impl_accessory_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("username")
+    ///     .accessory(|username, previous_answers| {
+    ///         format!("Your profile will be at example.com/{}", username)
+    ///     })
+    ///     .build();
+    /// ```
+    input
+    }
+
+    crate::impl_dynamic_hint_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{cell::Cell, rc::Rc};
+    /// use requestty::Question;
+    ///
+    /// let remaining = Rc::new(Cell::new(20));
+    /// let hint_remaining = Rc::clone(&remaining);
+    ///
+    /// let input = Question::input("bio")
+    ///     .validate_on_key(move |bio, previous_answers| {
+    ///         remaining.set(20 - bio.chars().count() as i64);
+    ///         remaining.get() >= 0
+    ///     })
+    ///     .dynamic_hint(move || format!("{} characters left", hint_remaining.get()))
+    ///     .build();
+    /// ```
+    input
+    }
+
     crate::impl_transform_builder! {
     /// # Examples
     ///
@@ -250,6 +511,19 @@ impl<'a> InputBuilder<'a> {
     str; input
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let input = Question::input("name")
+    ///     .display_transform(|name, previous_answers| format!("Hello, {}!", name))
+    ///     .build();
+    /// ```
+    str; input
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
@@ -1,13 +1,21 @@
-use std::io;
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{self, Write},
+};
 
 use ui::{
     backend::Backend,
     events::{EventIterator, KeyCode, KeyEvent},
     style::Stylize,
-    widgets, Prompt, Validation, Widget,
+    widgets::{self, AnswerAlign},
+    Prompt, Validation, Widget,
 };
 
-use super::{AutoComplete, ChoiceList, Filter, Transform, Validate, ValidateOnKey};
+use super::{
+    Accessory, AutoComplete, ChoiceList, DefaultFile, DynamicHint, Filter, Transform, Validate,
+    ValidateOnKey,
+};
 use crate::{Answer, Answers};
 
 pub use builder::InputBuilder;
@@ -20,30 +28,106 @@ mod tests;
 #[derive(Debug)]
 pub(super) struct Input<'a> {
     default: Option<(String, usize)>,
+    default_file: DefaultFile,
     filter: Filter<'a, String>,
     validate: Validate<'a, str>,
     validate_on_key: ValidateOnKey<'a, str>,
     transform: Transform<'a, str>,
+    accessory: Accessory<'a, str>,
+    dynamic_hint: DynamicHint<'a>,
     auto_complete: AutoComplete<'a, String>,
     page_size: usize,
     should_loop: bool,
+    trim: bool,
+    collapse_whitespace: bool,
+    multiline: bool,
+    indent: u16,
+    mark_modified: bool,
+    transcript: Option<Transcript<'a>>,
 }
 
 impl<'a> Default for Input<'a> {
     fn default() -> Self {
         Self {
             default: None,
+            default_file: DefaultFile::default(),
             filter: Filter::None,
             validate: Validate::None,
             validate_on_key: ValidateOnKey::None,
             transform: Transform::None,
+            accessory: Accessory::None,
+            dynamic_hint: DynamicHint::default(),
             auto_complete: AutoComplete::None,
             page_size: 15,
             should_loop: true,
+            trim: false,
+            collapse_whitespace: false,
+            multiline: false,
+            indent: 0,
+            mark_modified: false,
+            transcript: None,
         }
     }
 }
 
+impl Input<'_> {
+    /// Sets the default to a previous answer, which takes priority over the `default_file`.
+    pub(super) fn prefill(&mut self, answer: String) {
+        let len = answer.chars().count();
+        self.default = Some((answer, len));
+        self.default_file = DefaultFile::default();
+    }
+
+    /// Replaces the default with the contents of the `default_file`, if it could be read.
+    fn read_default_file(&mut self) -> io::Result<()> {
+        if let Some(default) = self.default_file.read()? {
+            let len = default.chars().count();
+            self.default = Some((default, len));
+        }
+
+        Ok(())
+    }
+
+    /// Applies the `trim` and `collapse_whitespace` options to the given text.
+    fn normalize<'s>(&self, s: &'s str) -> Cow<'s, str> {
+        let s = if self.trim { s.trim() } else { s };
+
+        if self.collapse_whitespace {
+            let mut collapsed = String::with_capacity(s.len());
+            let mut last_was_whitespace = false;
+
+            for c in s.chars() {
+                if c.is_whitespace() {
+                    if !last_was_whitespace {
+                        collapsed.push(' ');
+                    }
+                    last_was_whitespace = true;
+                } else {
+                    collapsed.push(c);
+                    last_was_whitespace = false;
+                }
+            }
+
+            Cow::Owned(collapsed)
+        } else {
+            Cow::Borrowed(s)
+        }
+    }
+}
+
+/// The writer that the finished prompt is copied to. See [`InputBuilder::transcript`].
+pub(super) struct Transcript<'a>(pub(super) Box<dyn io::Write + 'a>);
+
+impl fmt::Debug for Transcript<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transcript(_)")
+    }
+}
+
+/// The key used to submit a [`multiline`](InputBuilder::multiline) input.
+const MULTILINE_SUBMIT_KEY: KeyEvent =
+    KeyEvent::new(KeyCode::Char('d'), ui::events::KeyModifiers::CONTROL);
+
 type CompletionSelector = widgets::Select<ChoiceList<widgets::Text<String>>>;
 
 struct InputPrompt<'i, 'a> {
@@ -82,6 +166,14 @@ impl InputPrompt<'_, '_> {
         res
     }
 
+    /// Runs `validate_on_key` on the current value, so that it is rendered in red if it is not
+    /// valid. This is done after anything that changes the value.
+    fn validate_on_key(&mut self) {
+        if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
+            self.is_valid = validate(self.input.value(), self.answers);
+        }
+    }
+
     /// Returns the remaining default text if the current input is a substring of it
     fn get_remaining_default(&self) -> Option<&str> {
         if self.select.is_none() {
@@ -112,8 +204,13 @@ impl InputPrompt<'_, '_> {
 
 impl Widget for InputPrompt<'_, '_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
+        self.input_opts.dynamic_hint.refresh(&mut self.prompt);
+
         let mut original_layout = *layout;
         self.prompt.render(layout, b)?;
+        self.input_opts
+            .accessory
+            .render(self.input.value(), self.answers, layout, b)?;
 
         // if the current input does not satisfy the on key validation, then we show its wrong by
         // using the red colour
@@ -143,7 +240,10 @@ impl Widget for InputPrompt<'_, '_> {
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+        self.input_opts.dynamic_hint.refresh(&mut self.prompt);
+
         let mut height = self.prompt.height(layout) - 1;
+        height += self.input_opts.accessory.height(layout);
 
         if self.get_remaining_default().is_some() {
             let mut width = self.input_opts.default.as_ref().unwrap().1 as u16;
@@ -160,7 +260,7 @@ impl Widget for InputPrompt<'_, '_> {
                 height += 1;
             }
         } else {
-            height = self.input.height(layout);
+            height += self.input.height(layout);
         }
 
         if let Some(picker_height) = self.maybe_select_op(|select| select.height(layout)) {
@@ -203,6 +303,7 @@ impl Widget for InputPrompt<'_, '_> {
                             res
                         }
                     });
+                    self.validate_on_key();
                     return true;
                 }
             } else if self.check_complete_default() {
@@ -211,33 +312,58 @@ impl Widget for InputPrompt<'_, '_> {
         }
 
         if self.input.handle_key(key) {
-            if let ValidateOnKey::Sync(ref mut validate) = self.input_opts.validate_on_key {
-                self.is_valid = validate(self.input.value(), self.answers);
-            }
-
+            self.validate_on_key();
             self.select = None;
             return true;
         } else if key.code == KeyCode::Right && self.check_complete_default() {
             return true;
         }
 
-        self.maybe_select_op(|select| select.handle_key(key))
-            .unwrap_or(false)
+        // Moving through the completions changes the value to the hovered one
+        let handled = self
+            .maybe_select_op(|select| select.handle_key(key))
+            .unwrap_or(false);
+        if handled {
+            self.validate_on_key();
+        }
+        handled
+    }
+
+    fn handle_paste(&mut self, text: &str) -> bool {
+        if !self.input.handle_paste(text) {
+            return false;
+        }
+
+        self.validate_on_key();
+        self.select = None;
+        true
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-        self.input
-            .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+        let input_pos = self
+            .input_opts
+            .accessory
+            .input_pos(self.prompt.cursor_pos(layout));
+        self.input.cursor_pos(layout.with_cursor_pos(input_pos))
     }
 }
 
 impl Prompt for InputPrompt<'_, '_> {
     type ValidateErr = widgets::Text<String>;
-    type Output = String;
+    // The answer, and whether it is marked as modified
+    type Output = (String, bool);
 
     fn finish(self) -> Self::Output {
+        // An empty input is answered with the default, so it is not a modification either
+        let modified = self.input_opts.mark_modified
+            && self.input.is_dirty()
+            && !self.input.value().is_empty();
         let mut ans = self.input.finish();
 
+        if self.input_opts.trim || self.input_opts.collapse_whitespace {
+            ans = self.input_opts.normalize(&ans).into_owned();
+        }
+
         if ans.is_empty() {
             if let Some((default, _)) = self.input_opts.default {
                 ans = default;
@@ -248,7 +374,13 @@ impl Prompt for InputPrompt<'_, '_> {
             ans = filter(ans, self.answers);
         }
 
-        ans
+        (ans, modified)
+    }
+
+    /// The text is the input as it was typed, before it is normalised or filtered.
+    fn finish_detailed(self) -> (Self::Output, String) {
+        let raw = self.input.value().to_owned();
+        (self.finish(), raw)
     }
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
@@ -257,24 +389,49 @@ impl Prompt for InputPrompt<'_, '_> {
             return Ok(Validation::Continue);
         }
 
-        if self.input.value().is_empty() && self.input_opts.default.is_some() {
-            return Ok(Validation::Finish);
+        self.is_valid()?;
+        Ok(Validation::Finish)
+    }
+
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
+        let value = self.input_opts.normalize(self.input.value());
+
+        if value.is_empty() && self.input_opts.default.is_some() {
+            return Ok(());
         }
 
         if let Validate::Sync(ref mut validate) = self.input_opts.validate {
-            validate(self.input.value(), self.answers)?;
+            validate(&value, self.answers)?;
         }
 
-        Ok(Validation::Finish)
+        Ok(())
+    }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
     }
 }
 
 impl<'i> Input<'i> {
-    fn into_input_prompt<'a>(self, message: &'a str, answers: &'a Answers) -> InputPrompt<'i, 'a> {
+    fn into_input_prompt<'a>(
+        mut self,
+        message: &'a str,
+        answers: &'a Answers,
+    ) -> InputPrompt<'i, 'a> {
+        let mut prompt = widgets::Prompt::new(message);
+        self.dynamic_hint.refresh(&mut prompt);
+
+        let mut input = widgets::StringInput::default().multiline(self.multiline);
+        if let Some((ref default, _)) = self.default {
+            if self.mark_modified {
+                input = input.with_initial_value(default.clone());
+            }
+        }
+
         InputPrompt {
-            prompt: widgets::Prompt::new(message),
+            prompt,
+            input,
             input_opts: self,
-            input: widgets::StringInput::default(),
             select: None,
             is_valid: true,
             answers,
@@ -284,18 +441,78 @@ impl<'i> Input<'i> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
+        self.read_default_file()?;
+
         let transform = self.transform.take();
+        let multiline = self.multiline;
+        let indent = self.indent;
+        let transcript = self.transcript.take();
+
+        let mut prompt = self.into_input_prompt(&message, answers);
+        prompt.prompt.set_step(opts.step);
+
+        let mut input = ui::Input::new(prompt, b)
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
+            .indent(indent);
+
+        if multiline {
+            input = input.submit_key(MULTILINE_SUBMIT_KEY);
+        }
+
+        let (ans, modified) = match input.run(events)? {
+            Some((ans, modified)) => (Some(ans), modified),
+            None => (None, false),
+        };
+
+        // The text of the finished prompt is collected without any styles for the transcript
+        let mut finished_line = Vec::new();
+        let res = {
+            let b = &mut ui::backend::InspectBackend::new(b, |text: &[u8]| {
+                finished_line.extend_from_slice(text);
+                text.into()
+            });
 
-        let ans = ui::Input::new(self.into_input_prompt(&message, answers), b)
-            .on_esc(on_esc)
-            .run(events)?;
+            Self::write_answer(
+                transform,
+                message,
+                ans,
+                modified,
+                indent,
+                opts.answer_align,
+                answers,
+                b,
+            )
+        };
+
+        if let Some(Transcript(mut transcript)) = transcript {
+            transcript.write_all(&finished_line)?;
+            transcript.flush()?;
+        }
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b
-            .write_styled(&ans.as_str().cyan())?)
+        res
     }
+
+    crate::impl_write_answer!(
+        /// If `modified` is set, the answer is marked as changed from the default. The finished
+        /// prompt is written starting at `indent`.
+        #[allow(clippy::too_many_arguments)]
+        self; Transform<'_, str>, String [ref];
+        modified: bool = false;
+        indent: u16 = 0;
+        @offset_x = indent;
+        // Only the first line is shown so that the finished prompt stays on a single line
+        |message, ans, b| {
+            b.write_styled(&ans.lines().next().unwrap_or("").cyan())?;
+            if modified {
+                b.write_styled(&" (modified)".dark_grey())?;
+            }
+        }
+    );
 }
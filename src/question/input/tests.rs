@@ -173,3 +173,138 @@ fn test_cursor_pos() {
 
     assert_eq!(prompt.cursor_pos(layout), (line_offset + 6, 0));
 }
+
+#[test]
+fn test_finish_detailed() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        trim: true,
+        filter: Filter::Sync(Box::new(|s, _| s.to_uppercase())),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+    prompt.input.set_value("  abc ".into());
+
+    assert_eq!(
+        prompt.finish_detailed(),
+        (("ABC".to_owned(), false), "  abc ".to_owned())
+    );
+}
+
+#[test]
+fn test_validate_on_key() {
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        validate_on_key: ValidateOnKey::Sync(Box::new(|s, _| s.ends_with('a'))),
+        auto_complete: AutoComplete::Sync(Box::new(|s, _| {
+            ['a', 'b']
+                .iter()
+                .map(|&c| {
+                    let mut s = s.clone();
+                    s.push(c);
+                    s
+                })
+                .collect()
+        })),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+
+    assert!(prompt.handle_key(KeyCode::Char('x').into()));
+    assert!(!prompt.is_valid);
+
+    // Completing the value and moving through the completions validates it as well
+    assert!(prompt.handle_key(KeyCode::Tab.into()));
+    assert_eq!(prompt.input.value(), "xa");
+    assert!(prompt.is_valid);
+    assert!(prompt.handle_key(KeyCode::Down.into()));
+    assert_eq!(prompt.input.value(), "xb");
+    assert!(!prompt.is_valid);
+
+    assert!(prompt.handle_paste("a"));
+    assert!(prompt.is_valid);
+}
+
+#[test]
+fn test_accessory() {
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    let mut prompt = Input {
+        accessory: Accessory::Sync(Box::new(|s, _| format!("{} characters", s.len()))),
+        ..Input::default()
+    }
+    .into_input_prompt("message", &answers);
+    prompt.input.set_value("input".into());
+    prompt.input.set_at(5);
+
+    let mut layout = base_layout;
+    let mut backend = TestBackend::new_with_layout(size, layout);
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+
+    // The accessory is on its own line, and the input starts on the line after it
+    let mut expected = TestBackend::new_with_layout(size, base_layout);
+    widgets::Prompt::<_, String>::new("message")
+        .render(&mut base_layout.clone(), &mut expected)
+        .unwrap();
+    expected.move_cursor_to(0, 1).unwrap();
+    expected.set_fg(ui::style::Color::DarkGrey).unwrap();
+    expected.write_all(b"5 characters").unwrap();
+    expected.move_cursor_to(0, 2).unwrap();
+    expected.set_fg(ui::style::Color::Reset).unwrap();
+    expected.write_all(b"input").unwrap();
+
+    backend.assert_eq(&expected);
+    assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(5));
+
+    layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 3);
+    assert_eq!(layout, base_layout.with_offset(0, 2).with_line_offset(5));
+    assert_eq!(prompt.cursor_pos(base_layout), (5, 2));
+}
+
+#[test]
+fn test_default_file() {
+    let answers = Answers::default();
+    let path = std::env::temp_dir().join(format!("requestty-default-{}", std::process::id()));
+    std::fs::write(&path, "from file\n").unwrap();
+
+    let mut input = Input {
+        default: Some(("default".into(), 7)),
+        default_file: DefaultFile {
+            path: Some(path.clone()),
+            required: false,
+        },
+        ..Input::default()
+    };
+    input.read_default_file().unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // The trailing newline is not part of the default
+    let mut prompt = input.into_input_prompt("message", &answers);
+    assert_eq!(prompt.get_remaining_default(), Some("from file"));
+    assert_eq!(prompt.validate(), Ok(Validation::Finish));
+    assert_eq!(prompt.finish().0, "from file");
+
+    // The default is kept if the file is missing...
+    let mut input = Input {
+        default: Some(("default".into(), 7)),
+        default_file: DefaultFile {
+            path: Some(path.clone()),
+            required: false,
+        },
+        ..Input::default()
+    };
+    input.read_default_file().unwrap();
+    assert_eq!(input.default, Some(("default".into(), 7)));
+
+    // ...unless it is required
+    input.default_file.required = true;
+    assert_eq!(
+        input.read_default_file().unwrap_err().kind(),
+        io::ErrorKind::NotFound
+    );
+}
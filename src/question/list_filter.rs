@@ -0,0 +1,105 @@
+use std::io;
+
+use ui::{
+    backend::Backend,
+    layout::Layout,
+    style::Stylize,
+    widgets::{self, List, Text},
+    Widget,
+};
+
+use super::{Choice, ChoiceList};
+
+const NO_MATCHES: &str = "No matching choices";
+
+/// Shows the query typed into a [`Select`] after the message of a list question. The query is
+/// typed with [`Select::with_query_filter`], which hides the choices that do not fuzzy match it.
+///
+/// [`Select`]: widgets::Select
+/// [`Select::with_query_filter`]: widgets::Select::with_query_filter
+pub(super) struct ListFilter {
+    /// Only used to show the query
+    query: widgets::StringInput,
+}
+
+impl ListFilter {
+    pub(super) fn new() -> Self {
+        Self {
+            query: widgets::StringInput::new(),
+        }
+    }
+
+    /// The text each choice is matched against. Separators have no text, so they are always
+    /// hidden while filtering.
+    pub(super) fn texts(choices: &ChoiceList<Text<String>>) -> Vec<String> {
+        choices
+            .choices
+            .iter()
+            .map(|choice| match choice {
+                Choice::Choice(text) => text.text.clone(),
+                _ => String::new(),
+            })
+            .collect()
+    }
+
+    /// Copies the query of `select` to be shown.
+    fn sync<L: List>(&mut self, select: &widgets::Select<L>) {
+        if self.query.value() != select.query() {
+            self.query.set_value(select.query().to_owned());
+            self.query.set_at(usize::MAX);
+        }
+    }
+
+    /// Renders the query, and a message below it if nothing matches.
+    pub(super) fn render<B: Backend, L: List>(
+        &mut self,
+        select: &widgets::Select<L>,
+        layout: &mut Layout,
+        b: &mut B,
+    ) -> io::Result<()> {
+        self.sync(select);
+        self.query.render(layout, b)?;
+
+        if select.is_empty() {
+            if layout.line_offset != 0 {
+                layout.offset_y += 1;
+                b.move_cursor_to(layout.offset_x, layout.offset_y)?;
+            }
+
+            b.write_styled(&NO_MATCHES.dark_grey())?;
+            layout.line_offset = NO_MATCHES.len() as u16;
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn height<L: List>(
+        &mut self,
+        select: &widgets::Select<L>,
+        layout: &mut Layout,
+    ) -> u16 {
+        self.sync(select);
+        let mut height = self.query.height(layout);
+
+        if select.is_empty() {
+            if layout.line_offset != 0 {
+                layout.offset_y += 1;
+                height += 1;
+            }
+
+            layout.line_offset = NO_MATCHES.len() as u16;
+        }
+
+        height
+    }
+
+    /// The cursor position at the end of the query, given `layout` is where the query starts.
+    pub(super) fn cursor_pos<L: List>(
+        &mut self,
+        select: &widgets::Select<L>,
+        layout: Layout,
+    ) -> (u16, u16) {
+        self.sync(select);
+        self.query.cursor_pos(layout)
+    }
+}
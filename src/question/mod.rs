@@ -8,6 +8,7 @@ mod handler;
 #[macro_use]
 mod impl_macros;
 mod input;
+mod list_filter;
 mod multi_select;
 mod number;
 mod order_select;
@@ -27,19 +28,27 @@ pub use input::InputBuilder;
 pub use multi_select::MultiSelectBuilder;
 pub use number::{FloatBuilder, IntBuilder};
 pub use order_select::{builder::OrderSelectBuilder, OrderSelectItem};
-pub use password::PasswordBuilder;
+pub use password::{EchoMode, PasswordBuilder};
 pub use raw_select::RawSelectBuilder;
 pub use select::SelectBuilder;
 
-use ui::{backend::Backend, events::EventIterator};
+use std::{io, ops::RangeInclusive, path::PathBuf};
+
+use ui::{
+    backend::{Backend, Size},
+    events::{EventIterator, KeyCode, KeyEvent, KeyModifiers},
+    layout::Layout,
+    widgets::{self, Widget},
+};
 
 use crate::{Answer, Answers};
 use choice::{get_sep_str, ChoiceList};
 use custom_prompt::CustomPromptInteral;
 use handler::{
-    AutoComplete, Filter, Transform, TransformByVal, Validate, ValidateByVal, ValidateOnKey,
-    ValidateOnKeyByVal,
+    Accessory, AutoComplete, DynamicHint, Filter, Transform, TransformByVal, Validate,
+    ValidateByVal, ValidateOnKey, ValidateOnKeyByVal,
 };
+use list_filter::ListFilter;
 use options::Options;
 
 /// A `Question` that can be asked.
@@ -175,7 +184,7 @@ impl Question<'static> {
     /// Once the user exits their editor, the contents of the temporary file are read in as the
     /// result. The editor to use can be specified by the [`editor`] method. If unspecified, the
     /// editor is determined by the `$VISUAL` or `$EDITOR` environment variables. If neither of
-    /// those are present, `vim` (for unix) or `notepad` (for windows) is used.
+    /// those are present, `vi` (for unix) or `notepad` (for windows) is used.
     ///
     /// <img
     ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/editor.gif"
@@ -259,6 +268,37 @@ impl Question<'static> {
         IntBuilder::new(name.into())
     }
 
+    /// Prompt that takes a [`i64`] within the given range as input.
+    ///
+    /// This is the same as calling [`int`] followed by [`min`] and [`max`] with the bounds of the
+    /// range.
+    ///
+    /// # Panics
+    ///
+    /// If the start of the range is greater than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let int = Question::int_range("age", 1..=130)
+    ///     .message("What is your age?")
+    ///     .build();
+    /// ```
+    ///
+    /// [`int`]: Question::int
+    /// [`min`]: IntBuilder::min
+    /// [`max`]: IntBuilder::max
+    pub fn int_range<N: Into<String>>(name: N, range: RangeInclusive<i64>) -> IntBuilder<'static> {
+        let (min, max) = range.into_inner();
+        assert!(
+            min <= max,
+            "the start of the range must not be greater than the end"
+        );
+        Self::int(name).min(min).max(max)
+    }
+
     /// Prompt that takes a [`f64`] as input.
     ///
     /// The number is parsed using [`from_str`], but cannot be `NaN`.
@@ -293,10 +333,45 @@ impl Question<'static> {
         FloatBuilder::new(name.into())
     }
 
+    /// Prompt that takes a [`f64`] within the given range as input.
+    ///
+    /// This is the same as calling [`float`] followed by [`min`] and [`max`] with the bounds of
+    /// the range.
+    ///
+    /// # Panics
+    ///
+    /// If the start of the range is greater than the end, or either of them is `NaN`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let float = Question::float_range("opacity", 0.0..=1.0)
+    ///     .message("How opaque should the window be?")
+    ///     .build();
+    /// ```
+    ///
+    /// [`float`]: Question::float
+    /// [`min`]: FloatBuilder::min
+    /// [`max`]: FloatBuilder::max
+    pub fn float_range<N: Into<String>>(
+        name: N,
+        range: RangeInclusive<f64>,
+    ) -> FloatBuilder<'static> {
+        let (min, max) = range.into_inner();
+        assert!(
+            min <= max,
+            "the start of the range must not be greater than the end"
+        );
+        Self::float(name).min(min).max(max)
+    }
+
     /// Prompt that allows the user to select from a list of options by key
     ///
     /// The keys are ascii case-insensitive characters. The 'h' option is added by the prompt and
-    /// shouldn't be defined.
+    /// shouldn't be defined. Submitting 'h' lists all the options, and submitting it again hides
+    /// them.
     ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
     /// but [`Choice::Separator`]s can only be single line.
@@ -340,6 +415,13 @@ impl Question<'static> {
     ///   style="max-height: 15rem"
     /// />
     ///
+    /// If the answers already contain an [`Answer::String`] for this question, it is resolved to the
+    /// choice with the same text instead of prompting. This allows select questions to be answered
+    /// by scripts using [`PromptModule::with_answers`]. It is an error if the text does not match
+    /// exactly one choice.
+    ///
+    /// [`PromptModule::with_answers`]: crate::PromptModule::with_answers
+    ///
     /// See the various methods on the [`builder`] for more details on each available option.
     ///
     /// # Examples
@@ -367,6 +449,10 @@ impl Question<'static> {
 
     /// Prompt that allows the user to select from a list of options with indices
     ///
+    /// Every choice is numbered starting from 1, skipping the separators. A choice can be picked
+    /// either by moving through the list, or by typing its number which hovers it. A number which
+    /// is not shown cannot be submitted, and the list can still be moved through from there.
+    ///
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
     /// but [`Choice::Separator`]s can only be single line.
     ///
@@ -407,6 +493,8 @@ impl Question<'static> {
     /// The choices are represented with the [`Choice`] enum. [`Choice::Choice`] can be multi-line,
     /// but [`Choice::Separator`]s can only be single line.
     ///
+    /// Holding `Shift` while moving up or down selects every choice that is moved across.
+    ///
     /// <img
     ///   src="https://raw.githubusercontent.com/lutetium-vanadium/requestty/master/assets/multi-select.gif"
     ///   style="max-height: 20rem"
@@ -512,6 +600,178 @@ impl Question<'static> {
     }
 }
 
+impl Question<'_> {
+    /// Estimates the number of rows the question takes up when it is first rendered on a terminal
+    /// of the given `size`, without asking it.
+    ///
+    /// Questions that will not be asked are estimated to take 0 rows. This is the case if the
+    /// question is already present in `answers` (and `ask_if_answered` is not set), or if `when`
+    /// is `false`. Since `when` and `message` can only be called once if they are functions, a
+    /// `when` function is assumed to return `true`, and a `message` function is replaced by the
+    /// default message.
+    ///
+    /// The estimate is for the initial state of the prompt, and does not account for hints,
+    /// errors or auto-completions. [`custom`](Question::custom) prompts are assumed to take a
+    /// single row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let mut question = Question::select("action")
+    ///     .message("What do you want to do?")
+    ///     .choices(vec!["Order a pizza", "Make a reservation"])
+    ///     .build();
+    ///
+    /// let height = question.estimated_height(&Answers::default(), (80, 24).into());
+    /// assert_eq!(height, 3);
+    /// ```
+    pub fn estimated_height(&mut self, answers: &Answers, size: Size) -> u16 {
+        if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+            return 0;
+        }
+
+        if let options::Getter::Value(false) = self.opts.when {
+            return 0;
+        }
+
+        let mut layout = Layout::new(0, size);
+
+        let default_message;
+        let message = match self.opts.message {
+            Some(options::Getter::Value(ref message)) => message,
+            _ => {
+                default_message = self.opts.name.clone() + ":";
+                &default_message
+            }
+        };
+
+        let mut prompt: widgets::Prompt<&str> =
+            widgets::Prompt::new(message.as_str()).with_optional_step(self.opts.step);
+
+        prompt.height(&mut layout)
+            + match self.kind {
+                QuestionKind::Select(ref mut s) => estimated_list_height(s, &mut layout),
+                QuestionKind::RawSelect(ref mut r) => {
+                    // The list is followed by a line to type the answer
+                    estimated_list_height(r, &mut layout) + 1
+                }
+                QuestionKind::MultiSelect(ref mut m) => estimated_list_height(m, &mut layout),
+                QuestionKind::OrderSelect(ref mut o) => estimated_list_height(o, &mut layout),
+                _ => 0,
+            }
+    }
+
+    /// Renders the question as it is shown once it has been answered with `answer`, without asking
+    /// it.
+    ///
+    /// This writes the same line that is left behind when the question is answered interactively,
+    /// including any [`transform`](InputBuilder::transform), so it can be used to show a review
+    /// of the answers before they are submitted. An [`Answer::Skipped`] is rendered as a skipped
+    /// question. If `message` is a function, it is called with `answers`.
+    ///
+    /// # Errors
+    ///
+    /// An [`InvalidInput`](io::ErrorKind::InvalidInput) error is returned if the `answer` cannot
+    /// be returned by this kind of question, or if the question is a [`custom`](Question::custom)
+    /// prompt, since the way they show their answer is not known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answer, Answers, Question};
+    /// use requestty::prompt::backend::TestBackend;
+    ///
+    /// let question = Question::int("age").message("How old are you?").build();
+    ///
+    /// let mut backend = TestBackend::new((50, 5).into());
+    /// question
+    ///     .render_answered(&Answer::Int(23), &Answers::default(), &mut backend)
+    ///     .unwrap();
+    /// ```
+    pub fn render_answered<B: Backend>(
+        self,
+        answer: &Answer,
+        answers: &Answers,
+        b: &mut B,
+    ) -> ui::Result<()> {
+        let name = self.opts.name;
+        let message = self
+            .opts
+            .message
+            .map(|message| message.get(answers))
+            .unwrap_or_else(|| name.clone() + ":");
+        let answer_align = self.opts.answer_align;
+
+        macro_rules! answered {
+            ($kind:expr, $variant:ident, |$ans:ident| $to_ans:expr) => {
+                match answer {
+                    Answer::$variant($ans) => {
+                        return $kind.render_answered(
+                            message,
+                            Some($to_ans),
+                            answer_align,
+                            answers,
+                            b,
+                        )
+                    }
+                    Answer::Skipped => {
+                        return $kind.render_answered(message, None, answer_align, answers, b)
+                    }
+                    _ => {}
+                }
+            };
+        }
+
+        match self.kind {
+            QuestionKind::Input(i) => answered!(i, String, |s| s.clone()),
+            QuestionKind::Int(i) => answered!(i, Int, |n| *n),
+            QuestionKind::Float(f) => answered!(f, Float, |n| *n),
+            QuestionKind::Confirm(c) => answered!(c, Bool, |v| *v),
+            QuestionKind::Select(l) => answered!(l, ListItem, |item| item.clone()),
+            QuestionKind::RawSelect(r) => answered!(r, ListItem, |item| item.clone()),
+            QuestionKind::Expand(e) => answered!(e, ExpandItem, |item| item.clone()),
+            QuestionKind::MultiSelect(c) => answered!(c, ListItems, |items| items.clone()),
+            QuestionKind::OrderSelect(c) => answered!(c, ListItems, |items| items
+                .iter()
+                .map(|item| OrderSelectItem {
+                    initial_index: item.index,
+                    text: widgets::Text::new(item.text.clone()),
+                })
+                .collect()),
+            QuestionKind::Password(p) => answered!(p, String, |s| s.clone()),
+            QuestionKind::Editor(e) => answered!(e, String, |s| s.clone()),
+            QuestionKind::Custom(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "custom prompts cannot be rendered as answered",
+                )
+                .into())
+            }
+        }
+
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "the question {:?} cannot be answered with {:?}",
+                name,
+                answer.variant_name()
+            ),
+        )
+        .into())
+    }
+}
+
+/// The height of a list shown below a prompt, excluding the line the prompt is on.
+fn estimated_list_height<L: widgets::List>(list: &mut L, layout: &mut Layout) -> u16 {
+    if !(0..list.len()).any(|i| list.is_selectable(i)) {
+        return 0;
+    }
+
+    widgets::Select::new(list).height(layout) - 1
+}
+
 #[derive(Debug)]
 enum QuestionKind<'a> {
     Input(input::Input<'a>),
@@ -528,6 +788,53 @@ enum QuestionKind<'a> {
     Custom(Box<dyn CustomPromptInteral + 'a>),
 }
 
+/// The options that are passed on to every built-in kind of question when it is asked.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AskOpts {
+    pub(crate) on_esc: ui::OnEsc,
+    pub(crate) skip_key: Option<KeyEvent>,
+    pub(crate) step: Option<(usize, usize)>,
+    pub(crate) answer_align: ui::widgets::AnswerAlign,
+    pub(crate) set_title: bool,
+}
+
+/// A file to read the default of a question from when it is asked. See
+/// [`InputBuilder::default_file`].
+#[derive(Debug, Default)]
+pub(crate) struct DefaultFile {
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) required: bool,
+}
+
+impl DefaultFile {
+    /// Reads the contents of the file without a trailing newline.
+    ///
+    /// It is `None` if there is no file, or if it could not be read and is not required.
+    pub(crate) fn read(&self) -> io::Result<Option<String>> {
+        let path = match self.path {
+            Some(ref path) => path,
+            None => return Ok(None),
+        };
+
+        match std::fs::read_to_string(path) {
+            Ok(mut contents) => {
+                if contents.ends_with('\n') {
+                    contents.pop();
+                    if contents.ends_with('\r') {
+                        contents.pop();
+                    }
+                }
+                Ok(Some(contents))
+            }
+            Err(_) if !self.required => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The key used to skip [`optional`](InputBuilder::optional) questions.
+const SKIP_KEY: KeyEvent = KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+
 impl Question<'_> {
     pub(crate) fn ask<B: Backend, I: EventIterator>(
         self,
@@ -537,7 +844,15 @@ impl Question<'_> {
     ) -> ui::Result<Option<(String, Answer)>> {
         // Already asked
         if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
-            return Ok(None);
+            // A select question may also be answered beforehand with the text of a choice, for
+            // example when the answers come from a script. It is resolved to the matching choice.
+            return match (self.kind, answers.get(&self.opts.name)) {
+                (QuestionKind::Select(s), Some(Answer::String(label))) => {
+                    let item = s.resolve_label(label)?;
+                    Ok(Some((self.opts.name, Answer::ListItem(item))))
+                }
+                _ => Ok(None),
+            };
         }
 
         // Shouldn't be asked
@@ -551,25 +866,107 @@ impl Question<'_> {
             .message
             .map(|message| message.get(answers))
             .unwrap_or_else(|| name.clone() + ":");
-        let on_esc = self.opts.on_esc.get(answers);
+        let optional = self.opts.optional;
+        let opts = AskOpts {
+            on_esc: self.opts.on_esc.get(answers),
+            skip_key: if optional { Some(SKIP_KEY) } else { None },
+            step: self.opts.step,
+            answer_align: self.opts.answer_align,
+            set_title: self.opts.set_title,
+        };
 
         let res = match self.kind {
-            QuestionKind::Input(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Int(i) => i.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Float(f) => f.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Confirm(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Select(l) => l.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::RawSelect(r) => r.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Expand(e) => e.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::MultiSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::OrderSelect(c) => c.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Password(p) => p.ask(message, on_esc, answers, b, events)?,
-            QuestionKind::Editor(e) => e.ask(message, on_esc, answers, b, events)?,
+            QuestionKind::Input(i) => i.ask(message, opts, answers, b, events)?,
+            QuestionKind::Int(i) => i.ask(message, opts, answers, b, events)?,
+            QuestionKind::Float(f) => f.ask(message, opts, answers, b, events)?,
+            QuestionKind::Confirm(c) => c.ask(message, opts, answers, b, events)?,
+            QuestionKind::Select(l) => l.ask(message, opts, answers, b, events)?,
+            QuestionKind::RawSelect(r) => r.ask(message, opts, answers, b, events)?,
+            QuestionKind::Expand(e) => e.ask(message, opts, answers, b, events)?,
+            QuestionKind::MultiSelect(c) => c.ask(message, opts, answers, b, events)?,
+            QuestionKind::OrderSelect(c) => c.ask(message, opts, answers, b, events)?,
+            QuestionKind::Password(p) => p.ask(message, opts, answers, b, events)?,
+            QuestionKind::Editor(e) => e.ask(message, opts, answers, b, events)?,
             QuestionKind::Custom(mut o) => o.ask(message, answers, b, events)?,
         };
 
+        let res = if optional {
+            Some(res.unwrap_or(Answer::Skipped))
+        } else {
+            res
+        };
+
         Ok(res.map(|res| (name, res)))
     }
+
+    /// The name the answer to the question is stored under.
+    pub(crate) fn name(&self) -> &str {
+        &self.opts.name
+    }
+
+    /// Whether the question always has an answer once it is reached, so that a later question
+    /// with the same name is never asked unless it sets `ask_if_answered`.
+    pub(crate) fn is_always_answered(&self) -> bool {
+        matches!(self.opts.when, options::Getter::Value(true)) && !self.opts.optional
+    }
+
+    /// Whether the question is asked even if there is already an answer with its name.
+    pub(crate) fn asks_if_answered(&self) -> bool {
+        self.opts.ask_if_answered
+    }
+
+    /// Whether `when` allows the question to be asked with `answers`, regardless of whether it is
+    /// already answered.
+    pub(crate) fn is_enabled(&mut self, answers: &Answers) -> bool {
+        *self.opts.when.resolve(answers)
+    }
+
+    /// The message of the question as it is shown with `answers`.
+    pub(crate) fn message(&mut self, answers: &Answers) -> String {
+        match self.opts.message {
+            Some(ref mut message) => message.resolve(answers).clone(),
+            None => self.opts.name.clone() + ":",
+        }
+    }
+
+    /// Sets the default of the question to a previous `answer`, so that it is pre-filled if the
+    /// question is asked again. Answers of the wrong type and passwords are ignored.
+    pub(crate) fn prefill(&mut self, answer: &Answer) {
+        match (&mut self.kind, answer) {
+            (QuestionKind::Input(i), Answer::String(s)) => i.prefill(s.clone()),
+            (QuestionKind::Int(i), Answer::Int(n)) => i.prefill(*n),
+            (QuestionKind::Float(f), Answer::Float(n)) => f.prefill(*n),
+            (QuestionKind::Confirm(c), Answer::Bool(b)) => c.prefill(*b),
+            (QuestionKind::Select(l), Answer::ListItem(item)) => l.prefill(item),
+            (QuestionKind::RawSelect(r), Answer::ListItem(item)) => r.prefill(item),
+            (QuestionKind::Expand(e), Answer::ExpandItem(item)) => e.prefill(item),
+            (QuestionKind::MultiSelect(c), Answer::ListItems(items)) => c.prefill(items),
+            (QuestionKind::OrderSelect(c), Answer::ListItems(items)) => c.prefill(items),
+            (QuestionKind::Editor(e), Answer::String(s)) => e.prefill(s.clone()),
+            _ => {}
+        }
+    }
+
+    /// Sets the step shown in the prompt, unless one was already set by the user.
+    pub(crate) fn set_default_step(&mut self, current: usize, total: usize) {
+        self.opts.step.get_or_insert((current, total));
+    }
+
+    /// Sets the window title to the message of the question while it is asked.
+    pub(crate) fn set_title_to_message(&mut self, set_title: bool) {
+        self.opts.set_title = set_title;
+    }
+
+    /// What [`ask`](Question::ask) would do with the question, without asking it.
+    pub(crate) fn plan(self, answers: &Answers) -> crate::Plan {
+        if !self.opts.ask_if_answered && answers.contains_key(&self.opts.name) {
+            crate::Plan::AutoAnswered(self.opts.name)
+        } else if !self.opts.when.get(answers) {
+            crate::Plan::Skipped(self.opts.name)
+        } else {
+            crate::Plan::Asked(self.opts.name)
+        }
+    }
 }
 
 /// The type which needs to be returned by the [`auto_complete`] function.
@@ -3,7 +3,7 @@ use ui::{backend::Backend, widgets::Text};
 use super::MultiSelect;
 use crate::{
     question::{Choice, Options},
-    ListItem,
+    KeyBindings, ListItem,
 };
 
 /// The builder for a [`multi_select`] prompt.
@@ -97,6 +97,39 @@ impl<'a> MultiSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Answers, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// The maximum height that can be taken by the list
@@ -143,6 +176,113 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// The typed query is shown after the message, and only the choices which fuzzy match it are
+    /// shown. Choices stay selected while they are hidden. Since letters are typed into the query,
+    /// `<a>` and `<i>` no longer toggle or invert the selection, but `<space>` still selects the
+    /// hovered choice. If nothing matches, the question cannot be submitted until the query is
+    /// changed.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.multi_select.filterable = filterable;
+        self
+    }
+
+    /// The keys used to move through the choices.
+    ///
+    /// These only apply to this question, every other question keeps the default keys. If
+    /// `bindings` is not set, it will default to [`KeyBindings::new`], which are the arrow keys
+    /// along with `h`, `j`, `k` and `l` and the other keys captured by [`Movement`].
+    ///
+    /// [`KeyBindings::new`]: crate::KeyBindings::new
+    /// [`Movement`]: crate::prompt::events::Movement
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::events::{KeyCode, KeyEvent, KeyModifiers, Movement},
+    ///     KeyBindings, Question,
+    /// };
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .bindings(
+    ///         KeyBindings::arrows()
+    ///             .bind(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL), Movement::Down)
+    ///             .bind(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL), Movement::Up),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn bindings(mut self, bindings: KeyBindings) -> Self {
+        self.multi_select.bindings = bindings;
+        self
+    }
+
+    /// Whether to show how many choices are selected in each section.
+    ///
+    /// A section is made of the choices after a [`Separator`], up to the next separator. When
+    /// enabled, the separator is shown with the number of selected choices and the total number of
+    /// choices in its section, for example `Fruits (2/5)`. [`DefaultSeparator`]s and separators
+    /// without any choices after them are shown as is.
+    ///
+    /// If `section_counts` is not set, it will default to `false`.
+    ///
+    /// [`Separator`]: crate::question::Choice::Separator
+    /// [`DefaultSeparator`]: crate::question::Choice::DefaultSeparator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("food")
+    ///     .section_counts(true)
+    ///     .separator("Fruits")
+    ///     .choices(vec!["Apple", "Banana"])
+    ///     .separator("Vegetables")
+    ///     .choices(vec!["Carrot", "Potato"])
+    ///     .build();
+    /// ```
+    pub fn section_counts(mut self, section_counts: bool) -> Self {
+        self.multi_select.section_counts = section_counts;
+        self
+    }
+
+    /// The text placed between the selected choices when they are shown once the question is
+    /// finished.
+    ///
+    /// The selected choices are shown on a single line, for example `✔ Toppings · Cheese, Olives`,
+    /// and are cut short with `…` if they do not fit. If nothing is selected, `(none)` is shown.
+    ///
+    /// If `answer_separator` is not set, it will default to `", "`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("toppings")
+    ///     .choices(vec!["Cheese", "Olives", "Onions"])
+    ///     .answer_separator(" + ")
+    ///     .build();
+    /// ```
+    pub fn answer_separator<S: Into<String>>(mut self, answer_separator: S) -> Self {
+        self.multi_select.answer_separator = Some(answer_separator.into());
+        self
+    }
+
     /// Inserts a [`Choice`] with given text and its default checked state as `false`.
     ///
     /// If you want to set the default checked state, use [`choice_with_default`].
@@ -191,6 +331,42 @@ impl<'a> MultiSelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] which cannot be selected, along with the `reason` why.
+    ///
+    /// The choice is shown in dark grey and skipped when moving through the list, like a
+    /// separator. It is followed by the `reason` it is disabled in brackets, if they fit on the
+    /// same line. An empty `reason` is not shown.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .choice("Cheddar")
+    ///     .disabled_choice("Brie", "out of stock")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I, R>(mut self, text: I, reason: R) -> Self
+    where
+        I: Into<String>,
+        R: Into<String>,
+    {
+        let multi_select = &mut self.multi_select;
+        multi_select
+            .disabled
+            .resize(multi_select.choices.len(), None);
+        multi_select.disabled.push(Some(reason.into()));
+        multi_select
+            .choices
+            .choices
+            .push(Choice::Choice(Text::new(text.into())));
+        multi_select.selected.push(false);
+        self
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`multi_select`] for more information.
@@ -397,6 +573,21 @@ impl<'a> MultiSelectBuilder<'a> {
     [ListItem]; multi_select
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let multi_select = Question::multi_select("cheese")
+    ///     .display_transform(|cheeses, previous_answers| {
+    ///         format!("{} cheeses", cheeses.len())
+    ///     })
+    ///     .build();
+    /// ```
+    [ListItem]; multi_select
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
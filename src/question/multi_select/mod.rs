@@ -2,13 +2,13 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyCode, KeyEvent},
-    style::Color,
-    widgets::{self, Text},
+    events::{EventIterator, KeyBindings, KeyCode, KeyEvent, KeyModifiers, Movement},
+    style::{Color, Stylize},
+    widgets::{self, AnswerAlign, Text, WrapMode},
     Prompt, Validation, Widget,
 };
 
-use super::{Choice, Filter, Transform, Validate};
+use super::{Choice, Filter, ListFilter, Transform, Validate};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::MultiSelectBuilder;
@@ -22,11 +22,75 @@ mod tests;
 pub(super) struct MultiSelect<'a> {
     choices: super::ChoiceList<Text<String>>,
     selected: Vec<bool>,
+    // The reason each choice is disabled, or `None` if it is not. Choices after the end of this are
+    // not disabled.
+    disabled: Vec<Option<String>>,
+    section_counts: bool,
+    filterable: bool,
+    bindings: KeyBindings,
+    answer_separator: Option<String>,
     filter: Filter<'a, Vec<bool>>,
     validate: Validate<'a, [bool]>,
     transform: Transform<'a, [ListItem]>,
 }
 
+impl MultiSelect<'_> {
+    /// Selects exactly the choices of a previous answer.
+    pub(super) fn prefill(&mut self, answer: &[ListItem]) {
+        self.selected
+            .iter_mut()
+            .for_each(|selected| *selected = false);
+
+        for item in answer {
+            if item.index < self.selected.len() {
+                self.set_selected(item.index, true);
+            }
+        }
+    }
+
+    /// Whether the choice at `index` was added with [`MultiSelectBuilder::disabled_choice`].
+    fn is_disabled(&self, index: usize) -> bool {
+        super::choice::is_disabled(&self.disabled, index)
+    }
+
+    /// Selects or unselects the choice at `index`. Separators and disabled choices cannot be
+    /// selected, so they are left as they are.
+    fn set_selected(&mut self, index: usize, selected: bool) {
+        if widgets::List::is_selectable(self, index) {
+            self.selected[index] = selected;
+        }
+    }
+
+    /// The selected state of the choices, skipping separators and disabled choices which cannot
+    /// be selected.
+    fn selectable_mut(&mut self) -> impl Iterator<Item = &mut bool> + '_ {
+        let disabled = &self.disabled;
+
+        self.selected
+            .iter_mut()
+            .zip(self.choices.choices.iter())
+            .enumerate()
+            .filter(move |(index, (_, choice))| {
+                !choice.is_separator() && !super::choice::is_disabled(disabled, *index)
+            })
+            .map(|(_, (selected, _))| selected)
+    }
+
+    /// The number of selected choices and the total number of choices in the section which
+    /// starts at the separator at `index`.
+    ///
+    /// A section extends until the next separator or the end of the list.
+    fn section_count(&self, index: usize) -> (usize, usize) {
+        self.choices.choices[index + 1..]
+            .iter()
+            .zip(self.selected[index + 1..].iter())
+            .take_while(|(choice, _)| choice.is_choice())
+            .fold((0, 0), |(checked, total), (_, &selected)| {
+                (checked + selected as usize, total + 1)
+            })
+    }
+}
+
 fn set_seperators_false(selected: &mut [bool], choices: &[Choice<Text<String>>]) {
     for (i, choice) in choices.iter().enumerate() {
         selected[i] &= !choice.is_separator();
@@ -36,6 +100,10 @@ fn set_seperators_false(selected: &mut [bool], choices: &[Choice<Text<String>>])
 struct MultiSelectPrompt<'a, 'c> {
     prompt: widgets::Prompt<&'a str>,
     select: widgets::Select<MultiSelect<'c>>,
+    filter: Option<ListFilter>,
+    // The choice a range selected by moving with shift started at, and what was selected before
+    // the range
+    range: Option<(usize, Vec<bool>)>,
     answers: &'a Answers,
 }
 
@@ -62,6 +130,16 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
     type Output = Vec<ListItem>;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        // The selection cannot be submitted while the filter hides every choice
+        if self.select.is_empty() {
+            return Ok(Validation::Continue);
+        }
+
+        self.is_valid()?;
+        Ok(Validation::Finish)
+    }
+
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
         if let Validate::Sync(ref mut validate) = self.select.list.validate {
             set_seperators_false(
                 &mut self.select.list.selected,
@@ -69,7 +147,7 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
             );
             validate(&self.select.list.selected, self.answers)?;
         }
-        Ok(Validation::Finish)
+        Ok(())
     }
 
     fn finish(self) -> Self::Output {
@@ -88,35 +166,84 @@ impl Prompt for MultiSelectPrompt<'_, '_> {
 
         create_list_items(selected, choices)
     }
+
+    fn tick(&mut self) -> bool {
+        self.select.tick()
+    }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl Widget for MultiSelectPrompt<'_, '_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         self.prompt.render(layout, b)?;
+        if let Some(ref mut filter) = self.filter {
+            filter.render(&self.select, layout, b)?;
+        }
         self.select.render(layout, b)
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
+        let mut height = self.prompt.height(layout);
+        if let Some(ref mut filter) = self.filter {
+            height += filter.height(&self.select, layout) - 1;
+        }
+        height + self.select.height(layout) - 1
     }
 
     fn handle_key(&mut self, key: KeyEvent) -> bool {
+        let extends_range = key.modifiers.contains(KeyModifiers::SHIFT)
+            && !self.select.is_empty()
+            && matches!(
+                self.select.bindings().movement(key),
+                Some(Movement::Up) | Some(Movement::Down)
+            );
+
+        if !extends_range {
+            self.range = None;
+        }
+
         match key.code {
+            KeyCode::Char(' ') if self.select.is_empty() => return false,
             KeyCode::Char(' ') => {
                 let index = self.select.get_at();
-                self.select.list.selected[index] = !self.select.list.selected[index];
+                let selected = !self.select.list.selected[index];
+                self.select.list.set_selected(index, selected);
             }
+            // The letters are typed into the filter instead
+            KeyCode::Char(_) if self.filter.is_some() => return self.select.handle_key(key),
             KeyCode::Char('i') => {
-                self.select.list.selected.iter_mut().for_each(|s| *s = !*s);
+                self.select.list.selectable_mut().for_each(|s| *s = !*s);
             }
             KeyCode::Char('a') => {
-                let select_state = self.select.list.selected.iter().any(|s| !s);
+                let select_state = self.select.list.selectable_mut().any(|s| !*s);
                 self.select
                     .list
-                    .selected
-                    .iter_mut()
+                    .selectable_mut()
                     .for_each(|s| *s = select_state);
             }
+            _ if extends_range => {
+                // Moving with shift selects every choice between the one the range started at and
+                // the hovered one, on top of what was selected before. So moving back towards the
+                // start shrinks the range again.
+                if self.range.is_none() {
+                    let start = self.select.get_at();
+                    self.range = Some((start, self.select.list.selected.clone()));
+                }
+
+                self.select.handle_key(key);
+
+                let at = self.select.get_at();
+                let (start, ref before) = *self.range.as_ref().unwrap();
+                let list = &mut self.select.list;
+
+                list.selected.clone_from(before);
+                for index in start.min(at)..=start.max(at) {
+                    list.set_selected(index, true);
+                }
+            }
             _ => return self.select.handle_key(key),
         }
 
@@ -124,7 +251,17 @@ impl Widget for MultiSelectPrompt<'_, '_> {
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-        self.select.cursor_pos(layout)
+        match self.filter {
+            Some(ref mut filter) => {
+                let layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+                filter.cursor_pos(&self.select, layout)
+            }
+            None => self.select.cursor_pos(layout),
+        }
+    }
+
+    fn wants_cursor(&self) -> bool {
+        self.filter.is_some() || self.select.wants_cursor()
     }
 }
 
@@ -136,41 +273,56 @@ impl widgets::List for MultiSelect<'_> {
         mut layout: ui::layout::Layout,
         b: &mut B,
     ) -> io::Result<()> {
-        let symbol_set = ui::symbols::current();
-        if hovered {
-            b.set_fg(Color::Cyan)?;
-            write!(b, "{} ", symbol_set.pointer)?;
-        } else {
+        if self.choices[index].is_separator() {
             b.write_all(b"  ")?;
-        }
-
-        if self.is_selectable(index) {
-            if self.selected[index] {
-                b.set_fg(Color::LightGreen)?;
-            } else {
-                b.set_fg(Color::DarkGrey)?;
-            }
-
-            write!(b, "{} ", symbol_set.completed)?;
-
-            if hovered {
-                b.set_fg(Color::Cyan)?;
-            } else {
-                b.set_fg(Color::Reset)?;
-            }
-        } else {
             b.set_fg(Color::DarkGrey)?;
+        } else {
+            // Disabled choices are lined up with the other choices, without a marker
+            let checked = if self.is_disabled(index) {
+                None
+            } else {
+                Some(self.selected[index])
+            };
+            widgets::write_check_marker(hovered, checked, b)?;
         }
 
         layout.offset_x += 4;
 
-        self.choices[index].render(&mut layout, b)?;
+        let section_count = if self.section_counts && self.choices[index].is_separator() {
+            self.section_count(index)
+        } else {
+            (0, 0)
+        };
+
+        let with_reason = if self.is_disabled(index) {
+            super::choice::with_disabled_reason(
+                &self.choices[index],
+                self.disabled_reason(index),
+                layout.line_width(),
+            )
+        } else {
+            None
+        };
+
+        match (&mut self.choices[index], section_count, with_reason) {
+            (_, _, Some(with_reason)) => b.write_all(with_reason.as_bytes())?,
+            (Choice::Separator(header), (checked, total), _) if total > 0 => {
+                format!("{} ({}/{})", header, checked, total)
+                    .as_str()
+                    .render(&mut layout, b)?;
+            }
+            (choice, _, _) => choice.render(&mut layout, b)?,
+        }
 
         b.set_fg(Color::Reset)
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        !self.choices[index].is_separator()
+        !self.choices[index].is_separator() && !self.is_disabled(index)
+    }
+
+    fn disabled_reason(&self, index: usize) -> Option<&str> {
+        super::choice::disabled_reason(&self.disabled, index)
     }
 
     fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
@@ -193,14 +345,37 @@ impl widgets::List for MultiSelect<'_> {
 
 impl<'c> MultiSelect<'c> {
     fn into_multi_select_prompt<'a>(
-        self,
+        mut self,
         message: &'a str,
         answers: &'a Answers,
     ) -> MultiSelectPrompt<'a, 'c> {
+        let bindings = std::mem::take(&mut self.bindings);
+        let (hint, texts) = if self.filterable {
+            (
+                "Press <space> to select, type to filter",
+                Some(ListFilter::texts(&self.choices)),
+            )
+        } else {
+            (
+                "Press <space> to select, <a> to toggle all, <i> to invert selection",
+                None,
+            )
+        };
+
+        let mut select = widgets::Select::new(self).with_bindings(bindings);
+        let filter = match texts {
+            Some(texts) => {
+                select = select.with_query_filter(texts);
+                Some(ListFilter::new())
+            }
+            None => None,
+        };
+
         MultiSelectPrompt {
-            prompt: widgets::Prompt::new(message)
-                .with_hint("Press <space> to select, <a> to toggle all, <i> to invert selection"),
-            select: widgets::Select::new(self),
+            prompt: widgets::Prompt::new(message).with_hint(hint),
+            select,
+            filter,
+            range: None,
             answers,
         }
     }
@@ -208,46 +383,99 @@ impl<'c> MultiSelect<'c> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
+        let separator = self.answer_separator.take();
+
+        let mut prompt = self.into_multi_select_prompt(&message, answers);
+        prompt.prompt.set_step(opts.step);
 
-        let ans = ui::Input::new(self.into_multi_select_prompt(&message, answers), b)
-            .hide_cursor()
-            .on_esc(on_esc)
+        // The cursor is needed to type the filter
+        let hide_cursor = prompt.filter.is_none();
+        let mut input = ui::Input::new(prompt, b);
+        if hide_cursor {
+            input = input.hide_cursor();
+        }
+
+        let ans = input
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
-            b.set_fg(Color::Cyan)?;
-            print_comma_separated(
-                ans.iter().map(|item| {
-                    item.text
-                        .lines()
-                        .next()
-                        .expect("There must be at least one line in a `str`")
-                }),
-                b,
-            )?;
-            b.set_fg(Color::Reset)?;
-        })
+        Self::write_answer(
+            transform,
+            message,
+            ans,
+            separator,
+            opts.answer_align,
+            answers,
+            b,
+        )
     }
+
+    crate::impl_write_answer!(
+        /// The first lines of the selected choices are joined with the `separator`, and cut short
+        /// if they do not fit on the line.
+        self; Transform<'_, [ListItem]>, Vec<ListItem> [ref];
+        separator: Option<String> = self.answer_separator.take();
+        |message, ans, b| {
+            if ans.is_empty() {
+                b.write_styled(&"(none)".dark_grey())?;
+            } else {
+                // The last column is left empty, so that the cursor does not wrap to the next line
+                let max_width = b
+                    .size()?
+                    .width
+                    .saturating_sub(widgets::Prompt::finished_message_width(&message) + 1);
+                let joined = join_first_lines(ans, separator.as_deref().unwrap_or(", "));
+
+                b.set_fg(Color::Cyan)?;
+                write_cut_short(&joined, max_width, b)?;
+                b.set_fg(Color::Reset)?;
+            }
+        }
+    );
 }
 
-fn print_comma_separated<'a, B: Backend>(
-    iter: impl Iterator<Item = &'a str>,
-    b: &mut B,
-) -> io::Result<()> {
-    let mut iter = iter.peekable();
+/// Joins the first line of the text of each item with the `separator`.
+fn join_first_lines(items: &[ListItem], separator: &str) -> String {
+    let mut joined = String::new();
 
-    while let Some(item) = iter.next() {
-        b.write_all(item.as_bytes())?;
-        if iter.peek().is_some() {
-            b.write_all(b", ")?;
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            joined.push_str(separator);
         }
+        joined.push_str(
+            item.text
+                .lines()
+                .next()
+                .expect("There must be at least one line in a `str`"),
+        );
+    }
+
+    joined
+}
+
+/// Writes `text` if it fits in `max_width` columns, otherwise it is cut short and '…' is added to
+/// the end.
+fn write_cut_short<B: Backend>(text: &str, max_width: u16, b: &mut B) -> io::Result<()> {
+    if max_width == 0 {
+        return Ok(());
     }
 
-    Ok(())
+    if widgets::wrapped_height(text, max_width, WrapMode::Char) <= 1 {
+        return b.write_all(text.as_bytes());
+    }
+
+    if let Some(line) =
+        widgets::wrap_lines(text, max_width.saturating_sub(1), WrapMode::Char).next()
+    {
+        b.write_all(line.as_bytes())?;
+    }
+    b.write_all("…".as_bytes())
 }
@@ -176,3 +176,207 @@ test_multi_select!(with_default {
         KeyCode::Char('a').into(),
     ]
 });
+
+#[test]
+fn test_toggle_all_and_invert() {
+    let answers = Answers::default();
+    let mut multi_select =
+        unwrap_multi_select(MultiSelectBuilder::new("name".into()).choices(vec![
+            Choice::Choice("Choice 0".to_owned()),
+            Choice::DefaultSeparator,
+            Choice::Choice("Choice 2".to_owned()),
+        ]))
+        .into_multi_select_prompt("message", &answers);
+
+    let selected =
+        |multi_select: &MultiSelectPrompt<'_, '_>| multi_select.select.list.selected.clone();
+
+    // Selecting every choice by hand means toggle all unselects everything, even though the
+    // separator is not selected
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(selected(&multi_select), [true, false, true]);
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(selected(&multi_select), [false, false, false]);
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(selected(&multi_select), [true, false, true]);
+
+    // Inverting leaves the separator unselected
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(selected(&multi_select), [false, false, true]);
+}
+
+#[test]
+fn test_shift_range_selection() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choice("Choice 0")
+            .choice("Choice 1")
+            .default_separator()
+            .choice("Choice 3")
+            .disabled_choice("Choice 4", "unavailable")
+            .choice("Choice 5")
+            .choice("Choice 6"),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    let selected =
+        |multi_select: &MultiSelectPrompt<'_, '_>| multi_select.select.list.selected.clone();
+    let shift = |code| KeyEvent::new(code, KeyModifiers::SHIFT);
+
+    multi_select.height(&mut Layout::new(0, (50, 20).into()));
+
+    // Choices selected before the range stay selected
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(
+        selected(&multi_select),
+        [true, false, false, false, false, false, false]
+    );
+
+    // The range extends over the separator without selecting it
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert_eq!(multi_select.select.get_at(), 3);
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, true, false, false, false]
+    );
+
+    // The disabled choice is skipped, and is not selected either
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert_eq!(multi_select.select.get_at(), 5);
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, true, false, true, false]
+    );
+
+    // Moving back towards the start shrinks the range
+    assert!(multi_select.handle_key(shift(KeyCode::Up)));
+    assert_eq!(multi_select.select.get_at(), 3);
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, true, false, false, false]
+    );
+
+    // Moving past the start extends the range the other way
+    assert!(multi_select.handle_key(shift(KeyCode::Up)));
+    assert!(multi_select.handle_key(shift(KeyCode::Up)));
+    assert_eq!(multi_select.select.get_at(), 0);
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, false, false, false, false]
+    );
+
+    // Moving without shift ends the range, and does not select anything
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, false, false, false, false]
+    );
+
+    // A new range starts from the hovered choice
+    assert!(multi_select.handle_key(shift(KeyCode::Down)));
+    assert_eq!(
+        selected(&multi_select),
+        [true, true, false, true, false, true, false]
+    );
+}
+
+#[test]
+fn test_disabled_choice() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .choice("Choice 0")
+            .disabled_choice("Choice 1", "unavailable")
+            .choice("Choice 2"),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    multi_select
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    assert!(backend.to_string().contains("Choice 1 (unavailable)"));
+
+    // Toggling all and inverting leave the disabled choice unselected
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(multi_select.select.list.selected, [true, false, true]);
+    assert!(multi_select.handle_key(KeyCode::Char('i').into()));
+    assert_eq!(multi_select.select.list.selected, [false, false, false]);
+}
+
+#[test]
+fn test_shift_range_selection_with_bindings() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .bindings(ui::events::KeyBindings::arrows())
+            .choices(vec!["Choice 0".to_owned(), "Choice 1".to_owned()]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    multi_select.height(&mut Layout::new(0, (50, 20).into()));
+
+    // The arrows are bound without shift, but still select a range with it
+    assert!(multi_select.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT)));
+    assert_eq!(multi_select.select.get_at(), 1);
+    assert_eq!(multi_select.select.list.selected, [true, true]);
+}
+
+#[test]
+fn test_section_counts() {
+    let answers = Answers::default();
+    let mut multi_select = unwrap_multi_select(
+        MultiSelectBuilder::new("name".into())
+            .section_counts(true)
+            .separator("Fruits")
+            .choices_with_default(vec![("Apple", true), ("Banana", false)])
+            .default_separator()
+            .separator("Empty")
+            .separator("Vegetables")
+            .choices(vec!["Carrot"]),
+    )
+    .into_multi_select_prompt("message", &answers);
+
+    let counts = |multi_select: &MultiSelectPrompt<'_, '_>| {
+        [0, 3, 4, 5]
+            .iter()
+            .map(|&i| multi_select.select.list.section_count(i))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(counts(&multi_select), [(1, 2), (0, 0), (0, 0), (0, 1)]);
+
+    // Unselect "Apple" and select "Banana"
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(counts(&multi_select), [(1, 2), (0, 0), (0, 0), (0, 1)]);
+
+    // Select "Carrot"
+    assert!(multi_select.handle_key(KeyCode::Down.into()));
+    assert!(multi_select.handle_key(KeyCode::Char(' ').into()));
+    assert_eq!(counts(&multi_select), [(1, 2), (0, 0), (0, 0), (1, 1)]);
+
+    assert!(multi_select.handle_key(KeyCode::Char('a').into()));
+    assert_eq!(counts(&multi_select), [(2, 2), (0, 0), (0, 0), (1, 1)]);
+
+    let size = (50, 20).into();
+    let mut layout = Layout::new(0, size);
+    let mut backend = TestBackend::new(size);
+    multi_select.render(&mut layout, &mut backend).unwrap();
+
+    let contents = backend.to_string();
+    assert!(contents.contains("Fruits (2/2)"));
+    assert!(contents.contains("Vegetables (1/1)"));
+    assert!(contents.contains("Empty"));
+    assert!(!contents.contains("Empty ("));
+}
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use ui::backend::Backend;
 
 use super::{Float, Int};
@@ -7,6 +9,8 @@ macro_rules! builder {
     ($(#[$meta:meta])* struct $builder_name:ident : $type:ident -> $inner_ty:ty, $litral:expr;
      declare = $declare:expr;
      default = $default:expr;
+     min = $min:expr;
+     max = $max:expr;
      filter = $filter:expr;
      validate = $validate:expr;
      validate_on_key = $validate_on_key:expr;
@@ -73,6 +77,39 @@ macro_rules! builder {
             ///     .on_esc(OnEsc::Terminate)
             ///     .build();
             /// ```
+
+            optional
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::{Question, Answers};
+            ///
+            #[doc = $declare]
+            ///     .optional(true)
+            ///     .build();
+            /// ```
+
+            step
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::{Question, Answers};
+            ///
+            #[doc = $declare]
+            ///     .step(2, 5)
+            ///     .build();
+            /// ```
+
+            answer_align
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::{AnswerAlign, Question};
+            ///
+            #[doc = $declare]
+            ///     .answer_align(AnswerAlign::Right)
+            ///     .build();
+            /// ```
             }
 
             /// Set a default value
@@ -97,6 +134,115 @@ macro_rules! builder {
                 self
             }
 
+            /// Read the default value from a file when the question is asked.
+            ///
+            /// The contents of the file are parsed in the same way as the answer, ignoring leading
+            /// and trailing whitespace, and used as a [`default`]. If the file cannot be read or
+            /// does not contain a number, the [`default`] is used instead if there is one, unless
+            /// [`default_file_required`] is set.
+            ///
+            /// [`default`]: Self::default
+            /// [`default_file_required`]: Self::default_file_required
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .default_file("last_value.txt")
+            ///     .build();
+            /// ```
+            pub fn default_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+                self.inner.default_file.path = Some(path.into());
+                self
+            }
+
+            /// Whether asking the question should fail if the [`default_file`] cannot be read, or
+            /// does not contain a number.
+            ///
+            /// If set, the error is returned when the question is asked.
+            ///
+            /// If `default_file_required` is not set, it will default to `false`.
+            ///
+            /// [`default_file`]: Self::default_file
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .default_file("last_value.txt")
+            ///     .default_file_required(true)
+            ///     .build();
+            /// ```
+            pub fn default_file_required(mut self, required: bool) -> Self {
+                self.inner.default_file.required = required;
+                self
+            }
+
+            /// Set the minimum value of the number
+            ///
+            /// Numbers less than `min` are rejected during validation with an error message, and
+            /// incrementing or decrementing the number using the arrow keys will not go below it.
+            /// This check is done before `validate` is called.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $min]
+            ///     .build();
+            /// ```
+            pub fn min(mut self, min: $inner_ty) -> Self {
+                self.inner.min = Some(min);
+                self
+            }
+
+            /// Set the maximum value of the number
+            ///
+            /// Numbers greater than `max` are rejected during validation with an error message,
+            /// and incrementing or decrementing the number using the arrow keys will not go above
+            /// it. This check is done before `validate` is called.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            #[doc = $max]
+            ///     .build();
+            /// ```
+            pub fn max(mut self, max: $inner_ty) -> Self {
+                self.inner.max = Some(max);
+                self
+            }
+
+            /// Show a unit after the number, for example `MB` to show `30 MB`.
+            ///
+            /// The unit is dimmed, and shown both after the number being typed and after the
+            /// answer once the question is finished. It is not typed, and does not change how the
+            /// number is parsed or the answer. While typing, it is left out if nothing has been
+            /// typed yet, or if it does not fit on the rest of the line.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .unit("MB")
+            ///     .build();
+            /// ```
+            pub fn unit<S: Into<String>>(mut self, unit: S) -> Self {
+                self.inner.unit = Some(unit.into());
+                self
+            }
+
             crate::impl_filter_builder! {
             /// # Examples
             ///
@@ -153,6 +299,45 @@ macro_rules! builder {
             by val $inner_ty; inner
             }
 
+            crate::impl_accessory_builder! {
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .accessory(|text, previous_answers| match text.parse::<f64>() {
+            ///         Ok(n) => format!("Twice that is {}", n * 2.0),
+            ///         Err(_) => String::new(),
+            ///     })
+            ///     .build();
+            /// ```
+            inner
+            }
+
+            crate::impl_dynamic_hint_builder! {
+            /// # Examples
+            ///
+            /// ```
+            /// use std::{cell::Cell, rc::Rc};
+            /// use requestty::Question;
+            ///
+            /// let valid = Rc::new(Cell::new(true));
+            /// let hint_valid = Rc::clone(&valid);
+            ///
+            #[doc = $declare]
+            ///     .validate_on_key(move |n, previous_answers| {
+            ///         valid.set(n >= 0 as _);
+            ///         valid.get()
+            ///     })
+            ///     .dynamic_hint(move || {
+            ///         if hint_valid.get() { String::new() } else { "must be positive".to_owned() }
+            ///     })
+            ///     .build();
+            /// ```
+            inner
+            }
+
             crate::impl_transform_builder! {
             /// # Examples
             ///
@@ -168,6 +353,19 @@ macro_rules! builder {
             by val $inner_ty; inner
             }
 
+            crate::impl_display_transform_builder! {
+            /// # Examples
+            ///
+            /// ```
+            /// use requestty::Question;
+            ///
+            #[doc = $declare]
+            ///     .display_transform(|n, previous_answers| format!("{:e}", n))
+            ///     .build();
+            /// ```
+            by val $inner_ty; inner
+            }
+
             /// Consumes the builder returning a [`Question`]
             ///
             /// [`Question`]: crate::question::Question
@@ -221,6 +419,8 @@ builder! {
 struct IntBuilder: Int -> i64, 10;
 declare  = r#"let int = Question::int("int")"#;
 default  = "    .default(10)";
+min      = "    .min(0)";
+max      = "    .max(100)";
 filter   = "    .filter(|n, previous_answers| n + 10)";
 validate = "        if n.is_positive() {";
 validate_on_key = "     .validate_on_key(|n, previous_answers| n.is_positive())";
@@ -260,7 +460,66 @@ builder! {
 struct FloatBuilder: Float -> f64, 10.0;
 declare  = r#"let float = Question::float("float")"#;
 default  = "    .default(10.0)";
+min      = "    .min(0.0)";
+max      = "    .max(1.0)";
 filter   = "    .filter(|n, previous_answers| (n * 10000.0).round() / 10000.0)";
 validate = "        if n.is_sign_positive() {";
 validate_on_key = "     .validate_on_key(|n, previous_answers| n.is_sign_positive())";
 }
+
+impl IntBuilder<'_> {
+    /// Show a gauge of where the number typed so far lies between `min` and `max`, such as
+    /// `[■■■□□□□□□□] 30/100`.
+    ///
+    /// The gauge is shown after the input and updated as the number is typed. It is left out
+    /// while the input cannot be parsed, or if it does not fit on the rest of the line. Numbers
+    /// outside the range are shown as an empty or full gauge. This does not limit the number
+    /// which can be entered, use [`min`](Self::min) and [`max`](Self::max) for that.
+    ///
+    /// # Panics
+    ///
+    /// If `min` is not less than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let int = Question::int("volume")
+    ///     .min(0)
+    ///     .max(100)
+    ///     .with_gauge(0, 100)
+    ///     .build();
+    /// ```
+    pub fn with_gauge(mut self, min: i64, max: i64) -> Self {
+        assert!(
+            min < max,
+            "the minimum of the gauge must be less than the maximum"
+        );
+        self.inner.gauge = Some((min, max));
+        self
+    }
+}
+
+impl FloatBuilder<'_> {
+    /// The maximum number of significant digits that can be typed.
+    ///
+    /// Keys which would add a digit beyond the limit are ignored while typing. Leading zeros are
+    /// not significant, and the digits of the exponent (after an `e`) are not counted. Unlike
+    /// rounding with a [`filter`](Self::filter), this limits what the user can enter. By default,
+    /// there is no limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let float = Question::float("float")
+    ///     .significant_digits(3)
+    ///     .build();
+    /// ```
+    pub fn significant_digits(mut self, significant_digits: usize) -> Self {
+        self.inner.significant_digits = Some(significant_digits);
+        self
+    }
+}
@@ -1,15 +1,20 @@
-use std::{fmt::Write, io};
+use std::{
+    fmt::{Display, Write},
+    io,
+    str::FromStr,
+};
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyCode, KeyEvent},
+    events::{EventIterator, KeyCode, KeyEvent, KeyModifiers},
     style::Color,
-    widgets, Prompt, Validation, Widget,
+    widgets::{self, AnswerAlign},
+    Prompt, Validation, Widget,
 };
 
 use super::{
-    Filter, TransformByVal as Transform, ValidateByVal as Validate,
-    ValidateOnKeyByVal as ValidateOnKey,
+    Accessory, DefaultFile, DynamicHint, Filter, TransformByVal as Transform,
+    ValidateByVal as Validate, ValidateOnKeyByVal as ValidateOnKey,
 };
 use crate::{Answer, Answers};
 
@@ -25,19 +30,33 @@ mod tests;
 #[derive(Debug, Default)]
 pub(super) struct Float<'a> {
     default: Option<(f64, String)>,
+    default_file: DefaultFile,
+    min: Option<f64>,
+    max: Option<f64>,
+    significant_digits: Option<usize>,
+    unit: Option<String>,
     filter: Filter<'a, f64>,
     validate: Validate<'a, f64>,
     validate_on_key: ValidateOnKey<'a, f64>,
     transform: Transform<'a, f64>,
+    accessory: Accessory<'a, str>,
+    dynamic_hint: DynamicHint<'a>,
 }
 
 #[derive(Debug, Default)]
 pub(super) struct Int<'a> {
     default: Option<(i64, String)>,
+    default_file: DefaultFile,
+    min: Option<i64>,
+    max: Option<i64>,
+    gauge: Option<(i64, i64)>,
+    unit: Option<String>,
     filter: Filter<'a, i64>,
     validate: Validate<'a, i64>,
     validate_on_key: ValidateOnKey<'a, i64>,
     transform: Transform<'a, i64>,
+    accessory: Accessory<'a, str>,
+    dynamic_hint: DynamicHint<'a>,
 }
 
 impl Int<'_> {
@@ -58,8 +77,45 @@ impl Int<'_> {
             None
         }
     }
+
+    /// Whether the character `c` can be inserted at `at` in the `value` being typed.
+    fn accepts_insert(&self, _value: &str, _at: usize, _c: char) -> bool {
+        true
+    }
+
+    /// The gauge shown after the input for `n`, if one was asked for.
+    fn gauge(&self, n: i64) -> Option<String> {
+        let (min, max) = self.gauge?;
+
+        // i128 so that the full range of i64 cannot overflow
+        let filled = (n.clamp(min, max) as i128 - min as i128) * GAUGE_WIDTH as i128
+            / (max as i128 - min as i128);
+        let filled = filled as usize;
+        let symbol_set = ui::symbols::current();
+
+        Some(format!(
+            " [{}{}] {}/{}",
+            symbol_set.gauge_filled.to_string().repeat(filled),
+            symbol_set
+                .gauge_empty
+                .to_string()
+                .repeat(GAUGE_WIDTH - filled),
+            n,
+            max
+        ))
+    }
+}
+
+/// Writes the `unit` dimmed after a number, separated by a space.
+fn write_unit<B: Backend>(unit: &str, b: &mut B) -> io::Result<()> {
+    b.set_fg(Color::DarkGrey)?;
+    write!(b, " {}", unit)?;
+    b.set_fg(Color::Reset)
 }
 
+/// The number of cells in the bar of the gauge of an int.
+const GAUGE_WIDTH: usize = 10;
+
 impl Float<'_> {
     fn write<B: Backend>(f: f64, b: &mut B) -> io::Result<()> {
         b.set_fg(Color::Cyan)?;
@@ -82,6 +138,67 @@ impl Float<'_> {
             None
         }
     }
+
+    /// Whether the character `c` can be inserted at `at` in the `value` being typed.
+    ///
+    /// It is rejected if it would take the number over the maximum significant digits.
+    fn accepts_insert(&self, value: &str, at: usize, c: char) -> bool {
+        let max = match self.significant_digits {
+            Some(max) => max,
+            None => return true,
+        };
+
+        let mut new_value = value.to_owned();
+        let byte_i = value.char_indices().nth(at).map_or(value.len(), |(i, _)| i);
+        new_value.insert(byte_i, c);
+
+        let new_digits = significant_digits(&new_value);
+        new_digits <= max || new_digits <= significant_digits(value)
+    }
+
+    fn gauge(&self, _: f64) -> Option<String> {
+        None
+    }
+}
+
+/// The number of significant digits in a float as it is typed. Only the digits before the exponent
+/// are counted, and leading zeros are not significant.
+fn significant_digits(value: &str) -> usize {
+    value
+        .split(&['e', 'E'][..])
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .skip_while(|&c| c == '0')
+        .count()
+}
+
+/// Replaces the default with the number in the `default_file`, if it could be read and parsed.
+fn read_default_file<N: FromStr + Display>(
+    default_file: &DefaultFile,
+    default: &mut Option<(N, String)>,
+) -> io::Result<()>
+where
+    N::Err: Display,
+{
+    let contents = match default_file.read()? {
+        Some(contents) => contents,
+        None => return Ok(()),
+    };
+
+    match contents.trim().parse::<N>() {
+        Ok(n) => {
+            let n_str = n.to_string();
+            *default = Some((n, n_str));
+        }
+        Err(e) if default_file.required => {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, e.to_string()))
+        }
+        Err(_) => {}
+    }
+
+    Ok(())
 }
 
 macro_rules! impl_number_prompt {
@@ -126,8 +243,94 @@ macro_rules! impl_number_prompt {
                 }
             }
 
+            /// Clamps the number to the `min` and `max` bounds if they are set
+            fn clamp(&self, mut n: $inner_ty) -> $inner_ty {
+                if let Some(min) = self.number.min {
+                    if n < min {
+                        n = min;
+                    }
+                }
+                if let Some(max) = self.number.max {
+                    if n > max {
+                        n = max;
+                    }
+                }
+                n
+            }
+
+            fn check_bounds(&self, n: $inner_ty) -> Result<(), String> {
+                match (self.number.min, self.number.max) {
+                    (Some(min), _) if n < min => {
+                        Err(format!("The number must be at least {}", min))
+                    }
+                    (_, Some(max)) if n > max => Err(format!("The number must be at most {}", max)),
+                    _ => Ok(()),
+                }
+            }
+
+            /// Clears the input, going back to how the prompt was when it was first shown
+            fn reset(&mut self) {
+                self.input.reset();
+                self.is_valid = true;
+            }
+
+            /// The gauge for the number typed so far, if it fits on the rest of the line.
+            fn gauge(&self, layout: &ui::layout::Layout) -> Option<String> {
+                let gauge = self.number.gauge(self.parse().ok()?)?;
+
+                if (textwrap::core::display_width(&gauge) as u16) < layout.line_width() {
+                    Some(gauge)
+                } else {
+                    None
+                }
+            }
+
+            /// The width of the unit after the number, if there is a number before it and it fits
+            /// on the rest of the line.
+            fn unit_width(&self, layout: &ui::layout::Layout) -> Option<u16> {
+                let unit = self.number.unit.as_ref()?;
+                if self.input.value().is_empty() && self.get_remaining_default().is_none() {
+                    return None;
+                }
+
+                let width = 1 + textwrap::core::display_width(unit) as u16;
+                if width < layout.line_width() {
+                    Some(width)
+                } else {
+                    None
+                }
+            }
+
+            /// The height of everything but the unit and the gauge.
+            fn input_height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
+                let mut height = self.prompt.height(layout) - 1;
+                height += self.number.accessory.height(layout);
+
+                if self.get_remaining_default().is_some() {
+                    let mut width = self.number.default.as_ref().unwrap().1.len() as u16;
+
+                    if width > layout.line_width() {
+                        width -= layout.line_width();
+
+                        layout.line_offset = width % layout.width;
+                        layout.offset_y += 1 + width / layout.width;
+
+                        height += 2 + width / layout.width;
+                    } else {
+                        layout.line_offset += width;
+                        height += 1;
+                    }
+                } else {
+                    height += self.input.height(layout);
+                }
+
+                height
+            }
+
             fn validate_on_key(&mut self, n: $inner_ty) {
-                if let ValidateOnKey::Sync(ref mut validate) = self.number.validate_on_key {
+                if self.check_bounds(n).is_err() {
+                    self.is_valid = false;
+                } else if let ValidateOnKey::Sync(ref mut validate) = self.number.validate_on_key {
                     self.is_valid = validate(n, self.answers);
                 } else {
                     self.is_valid = true;
@@ -141,8 +344,13 @@ macro_rules! impl_number_prompt {
                 layout: &mut ui::layout::Layout,
                 b: &mut B,
             ) -> io::Result<()> {
+                self.number.dynamic_hint.refresh(&mut self.prompt);
+
                 let mut original_layout = *layout;
                 self.prompt.render(layout, b)?;
+                self.number
+                    .accessory
+                    .render(self.input.value(), self.answers, layout, b)?;
 
                 // if the current input does not satisfy the on key validation, then we show its wrong by
                 // using the red colour
@@ -161,38 +369,61 @@ macro_rules! impl_number_prompt {
                     // We need to update the layout to reflect the rest of the hint that is
                     // rendered. Instead of doing the math to compute where the cursor ends after
                     // rendering, we use the height function which already calculates it.
-                    self.height(&mut original_layout);
+                    self.input_height(&mut original_layout);
                     *layout = original_layout;
                 }
 
+                if let Some(width) = self.unit_width(layout) {
+                    if let Some(ref unit) = self.number.unit {
+                        write_unit(unit, b)?;
+                    }
+                    layout.line_offset += width;
+                }
+
+                if let Some(gauge) = self.gauge(layout) {
+                    b.set_fg(ui::style::Color::DarkGrey)?;
+                    write!(b, "{}", gauge)?;
+                    b.set_fg(ui::style::Color::Reset)?;
+                    layout.line_offset += textwrap::core::display_width(&gauge) as u16;
+                }
+
                 Ok(())
             }
 
             fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-                let mut height = self.prompt.height(layout) - 1;
+                self.number.dynamic_hint.refresh(&mut self.prompt);
 
-                if self.get_remaining_default().is_some() {
-                    let mut width = self.number.default.as_ref().unwrap().1.len() as u16;
-
-                    if width > layout.line_width() {
-                        width -= layout.line_width();
+                let height = self.input_height(layout);
 
-                        layout.line_offset = width % layout.width;
-                        layout.offset_y += 1 + width / layout.width;
+                if let Some(width) = self.unit_width(layout) {
+                    layout.line_offset += width;
+                }
 
-                        height += 2 + width / layout.width;
-                    } else {
-                        layout.line_offset += width;
-                        height += 1;
-                    }
-                } else {
-                    height = self.input.height(layout);
+                if let Some(gauge) = self.gauge(layout) {
+                    layout.line_offset += textwrap::core::display_width(&gauge) as u16;
                 }
 
                 height
             }
 
             fn handle_key(&mut self, key: KeyEvent) -> bool {
+                if key.code == KeyCode::Char('u') && key.modifiers.contains(KeyModifiers::CONTROL) {
+                    self.reset();
+                    return true;
+                }
+
+                if let KeyCode::Char(c) = key.code {
+                    if !key
+                        .modifiers
+                        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+                        && !self
+                            .number
+                            .accepts_insert(self.input.value(), self.input.get_at(), c)
+                    {
+                        return false;
+                    }
+                }
+
                 if self.input.handle_key(key) {
                     match self.parse() {
                         Ok(n) => self.validate_on_key(n),
@@ -211,6 +442,7 @@ macro_rules! impl_number_prompt {
                     (KeyCode::Down, Ok(n)) => $type::delta(n, -1),
                     _ => return false,
                 };
+                let n = self.clamp(n);
 
                 self.input.replace_with(|mut s| {
                     s.clear();
@@ -224,8 +456,11 @@ macro_rules! impl_number_prompt {
             }
 
             fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-                self.input
-                    .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
+                let input_pos = self
+                    .number
+                    .accessory
+                    .input_pos(self.prompt.cursor_pos(layout));
+                self.input.cursor_pos(layout.with_cursor_pos(input_pos))
             }
         }
 
@@ -234,16 +469,22 @@ macro_rules! impl_number_prompt {
             type Output = $inner_ty;
 
             fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+                self.is_valid()?;
+                Ok(Validation::Finish)
+            }
+
+            fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
                 if self.input.value().is_empty() && self.number.default.is_some() {
-                    return Ok(Validation::Finish);
+                    return Ok(());
                 }
                 let n = self.parse()?;
+                self.check_bounds(n)?;
 
                 if let Validate::Sync(ref mut validate) = self.number.validate {
                     validate(n, self.answers)?;
                 }
 
-                Ok(Validation::Finish)
+                Ok(())
             }
 
             fn finish(self) -> Self::Output {
@@ -259,6 +500,16 @@ macro_rules! impl_number_prompt {
                     _ => n,
                 }
             }
+
+            /// The text is the number as it was typed, before it is parsed or filtered.
+            fn finish_detailed(self) -> (Self::Output, String) {
+                let raw = self.input.value().to_owned();
+                (self.finish(), raw)
+            }
+
+            fn message(&self) -> Option<&str> {
+                Some(self.prompt.message())
+            }
         }
     };
 }
@@ -267,15 +518,25 @@ impl_number_prompt!(IntPrompt, Int, i64);
 impl_number_prompt!(FloatPrompt, Float, f64);
 
 macro_rules! impl_ask {
-    ($t:ident, $prompt_name:ident) => {
+    ($t:ident, $prompt_name:ident, $inner_ty:ty) => {
         impl<'n> $t<'n> {
+            /// Sets the default to a previous answer, which takes priority over the
+            /// `default_file`.
+            pub(super) fn prefill(&mut self, answer: $inner_ty) {
+                self.default = Some((answer, answer.to_string()));
+                self.default_file = DefaultFile::default();
+            }
+
             fn into_prompt<'a>(
-                self,
+                mut self,
                 message: &'a str,
                 answers: &'a Answers,
             ) -> $prompt_name<'n, 'a> {
+                let mut prompt = widgets::Prompt::new(message);
+                self.dynamic_hint.refresh(&mut prompt);
+
                 $prompt_name {
-                    prompt: widgets::Prompt::new(message),
+                    prompt,
                     input: widgets::StringInput::with_filter_map(Self::filter_map),
                     is_valid: true,
                     number: self,
@@ -286,24 +547,48 @@ macro_rules! impl_ask {
             pub(crate) fn ask<B: Backend, E: EventIterator>(
                 mut self,
                 message: String,
-                on_esc: ui::OnEsc,
+                opts: crate::question::AskOpts,
                 answers: &Answers,
                 b: &mut B,
                 events: &mut E,
             ) -> ui::Result<Option<Answer>> {
+                read_default_file(&self.default_file, &mut self.default)?;
+
                 let transform = self.transform.take();
+                let unit = self.unit.clone();
 
-                let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-                    .on_esc(on_esc)
+                let mut prompt = self.into_prompt(&message, answers);
+                prompt.prompt.set_step(opts.step);
+
+                let ans = ui::Input::new(prompt, b)
+                    .on_esc(opts.on_esc)
+                    .skip_key(opts.skip_key)
+                    .set_title_to_message(opts.set_title)
                     .run(events)?;
 
-                crate::write_final!(transform, message, ans, answers, b, |ans| Self::write(
-                    ans, b
-                )?)
+                Self::write_answer(
+                    transform,
+                    message,
+                    ans,
+                    unit.as_deref(),
+                    opts.answer_align,
+                    answers,
+                    b,
+                )
             }
+
+            crate::impl_write_answer!(self; Transform<'_, $inner_ty>, $inner_ty;
+                unit: Option<&str> = self.unit.as_deref();
+                |message, ans, b| {
+                    Self::write(ans, b)?;
+                    if let Some(unit) = unit {
+                        write_unit(unit, b)?;
+                    }
+                }
+            );
         }
     };
 }
 
-impl_ask!(Int, IntPrompt);
-impl_ask!(Float, FloatPrompt);
+impl_ask!(Int, IntPrompt, i64);
+impl_ask!(Float, FloatPrompt, f64);
@@ -92,9 +92,192 @@ macro_rules! test_numbers {
                     assert_eq!(prompt.cursor_pos(layout), (17, 1));
                 }
             }
+
+            #[test]
+            fn test_reset() {
+                let answers = Answers::default();
+                let mut prompt = $prompt_name {
+                    default: Some(($default, $default.to_string())),
+                    ..Default::default()
+                }
+                .into_prompt("message", &answers);
+
+                for c in "12-".chars() {
+                    prompt.handle_key(KeyCode::Char(c).into());
+                }
+                prompt.handle_key(KeyCode::Left.into());
+                assert!(!prompt.is_valid);
+
+                assert!(prompt.handle_key(KeyEvent::new(
+                    KeyCode::Char('u'),
+                    KeyModifiers::CONTROL
+                )));
+                assert_eq!(prompt.input.value(), "");
+                assert_eq!(prompt.input.get_at(), 0);
+                assert!(prompt.is_valid);
+                assert!(prompt.get_remaining_default().is_some());
+            }
         }
     };
 }
 
 test_numbers!(mod int { Int, 333 });
 test_numbers!(mod float { Float, 3.3 });
+
+#[test]
+fn test_gauge() {
+    use ui::{backend::TestBackend, layout::Layout};
+
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    let mut prompt = Int {
+        gauge: Some((0, 100)),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+    prompt.input.set_value("30".into());
+    prompt.input.set_at(2);
+
+    let mut layout = base_layout;
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    assert!(backend.to_string().contains(" [■■■□□□□□□□] 30/100"));
+    // The gauge is after the input, but the cursor stays in the input
+    assert_eq!(layout, base_layout.with_line_offset(19 + 20));
+    let mut layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 1);
+    assert_eq!(layout, base_layout.with_line_offset(19 + 20));
+    assert_eq!(prompt.cursor_pos(base_layout), (19, 0));
+
+    // Values outside the range fill all or none of it
+    prompt.input.set_value("250".into());
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    assert!(backend.to_string().contains("[■■■■■■■■■■] 250/100"));
+
+    // Nothing is shown while the input cannot be parsed
+    prompt.input.set_value("-".into());
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    assert!(!backend.to_string().contains('□'));
+    assert_eq!(layout, base_layout.with_line_offset(18));
+}
+
+#[test]
+fn test_unit() {
+    use std::io::Write;
+
+    use ui::{backend::TestBackend, layout::Layout};
+
+    let size = (50, 20).into();
+    let base_layout = Layout::new(5, size);
+    let answers = Answers::default();
+
+    // The unit takes 5 columns with the space before it, even though it is only 3 characters
+    let mut prompt = Int {
+        unit: Some("平方米".into()),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+    let mut plain = Int::default().into_prompt("message", &answers);
+
+    // Nothing is shown until a number is typed
+    let mut backend = TestBackend::new_with_layout(size, base_layout);
+    let mut layout = base_layout;
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(layout, base_layout.with_line_offset(17));
+
+    let mut expected = TestBackend::new_with_layout(size, base_layout);
+    assert!(plain
+        .render(&mut base_layout.clone(), &mut expected)
+        .is_ok());
+    backend.assert_eq(&expected);
+
+    prompt.input.set_value("30".into());
+    prompt.input.set_at(2);
+    plain.input.set_value("30".into());
+    plain.input.set_at(2);
+
+    let mut layout = base_layout;
+    backend.reset_with_layout(layout);
+    assert!(prompt.render(&mut layout, &mut backend).is_ok());
+    assert_eq!(layout, base_layout.with_line_offset(19 + 7));
+
+    expected.reset_with_layout(base_layout);
+    assert!(plain
+        .render(&mut base_layout.clone(), &mut expected)
+        .is_ok());
+    write_unit("平方米", &mut expected).unwrap();
+    backend.assert_eq(&expected);
+
+    let mut layout = base_layout;
+    assert_eq!(prompt.height(&mut layout), 1);
+    assert_eq!(layout, base_layout.with_line_offset(19 + 7));
+    assert_eq!(prompt.cursor_pos(base_layout), (19, 0));
+
+    // The unit is written after the answer as well
+    let mut backend = TestBackend::new(size);
+    Int::write_answer(
+        Transform::None,
+        "message".into(),
+        Some(30),
+        Some("MB"),
+        AnswerAlign::Left,
+        &answers,
+        &mut backend,
+    )
+    .unwrap();
+
+    let mut expected = TestBackend::new(size);
+    widgets::Prompt::write_finished_message(&"message", false, &mut expected).unwrap();
+    Int::write(30, &mut expected).unwrap();
+    write_unit("MB", &mut expected).unwrap();
+    expected.write_all(b"\n").unwrap();
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_significant_digits() {
+    fn typed(input: &str) -> String {
+        let answers = Answers::default();
+        let mut prompt = Float {
+            significant_digits: Some(3),
+            ..Default::default()
+        }
+        .into_prompt("message", &answers);
+
+        for c in input.chars() {
+            prompt.handle_key(KeyCode::Char(c).into());
+        }
+
+        prompt.input.finish()
+    }
+
+    assert_eq!(typed("1.2345"), "1.23");
+    assert_eq!(typed("-12345"), "-123");
+    // Leading zeros are not significant
+    assert_eq!(typed("0.0012345"), "0.00123");
+    assert_eq!(typed("100.5"), "100.");
+    // The exponent is not counted
+    assert_eq!(typed("1.2345e123"), "1.23e123");
+    assert_eq!(typed("12.34E-56"), "12.3E-56");
+
+    // Digits can still be typed before the exponent if they don't go over the limit
+    let answers = Answers::default();
+    let mut prompt = Float {
+        significant_digits: Some(3),
+        ..Default::default()
+    }
+    .into_prompt("message", &answers);
+    prompt.input.set_value("1e5".into());
+    prompt.input.set_at(1);
+    assert!(prompt.handle_key(KeyCode::Char('2').into()));
+    assert!(prompt.handle_key(KeyCode::Char('3').into()));
+    assert!(!prompt.handle_key(KeyCode::Char('4').into()));
+    assert_eq!(prompt.input.value(), "123e5");
+}
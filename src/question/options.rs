@@ -1,6 +1,6 @@
 use std::fmt;
 
-use ui::OnEsc;
+use ui::{widgets::AnswerAlign, OnEsc};
 
 use crate::Answers;
 
@@ -11,6 +11,10 @@ pub(crate) struct Options<'a> {
     pub(crate) when: Getter<'a, bool>,
     pub(crate) ask_if_answered: bool,
     pub(crate) on_esc: Getter<'a, OnEsc>,
+    pub(crate) optional: bool,
+    pub(crate) step: Option<(usize, usize)>,
+    pub(crate) answer_align: AnswerAlign,
+    pub(crate) set_title: bool,
 }
 
 impl<'a> Options<'a> {
@@ -21,6 +25,10 @@ impl<'a> Options<'a> {
             when: true.into(),
             ask_if_answered: false,
             on_esc: OnEsc::Ignore.into(),
+            optional: false,
+            step: None,
+            answer_align: AnswerAlign::Left,
+            set_title: false,
         }
     }
 }
@@ -33,7 +41,10 @@ macro_rules! impl_options_builder {
     (message $(#[$message_meta:meta])*
      when $(#[$when_meta:meta])*
      ask_if_answered $(#[$ask_if_answered_meta:meta])*
-     $(on_esc $(#[$on_esc_meta:meta])*)?) => {
+     $(on_esc $(#[$on_esc_meta:meta])*
+     optional $(#[$optional_meta:meta])*
+     step $(#[$step_meta:meta])*
+     answer_align $(#[$answer_align_meta:meta])*)?) => {
         /// The message to display when the prompt is rendered in the terminal.
         ///
         /// It can be either a [`String`] or a [`FnOnce`] that returns a [`String`]. If it is a
@@ -114,6 +125,60 @@ macro_rules! impl_options_builder {
             self.opts.on_esc = on_esc.into();
             self
         }
+
+        /// Whether the question can be skipped by the user.
+        ///
+        /// If it is `true`, the user can press `Ctrl+S` to skip the question. Skipped questions
+        /// have the answer [`Answer::Skipped`], which is treated as absent by [`Answers::get`].
+        /// Skipping the question with `Esc` (see [`on_esc`]) will also give [`Answer::Skipped`].
+        ///
+        /// If it is not given, it defaults to `false`.
+        ///
+        /// [`Answer::Skipped`]: crate::Answer::Skipped
+        /// [`Answers::get`]: crate::Answers::get
+        /// [`on_esc`]: Self::on_esc
+        ///
+        ///
+        $(#[$optional_meta])*
+        pub fn optional(mut self, optional: bool) -> Self {
+            self.opts.optional = optional;
+            self
+        }
+
+        /// Show the progress of the question in a list of questions as `[current/total]`.
+        ///
+        /// It is rendered dimmed before the message. When the question is asked as part of a list
+        /// of questions, this is filled in automatically unless [`show_steps`] is turned off.
+        /// Setting it here overrides that.
+        ///
+        /// If it is not given, no step is shown.
+        ///
+        /// [`show_steps`]: crate::PromptModule::show_steps
+        ///
+        ///
+        $(#[$step_meta])*
+        pub fn step(mut self, current: usize, total: usize) -> Self {
+            self.opts.step = Some((current, total));
+            self
+        }
+
+        /// Where to put the answer on the line printed once the question is answered.
+        ///
+        /// With [`AnswerAlign::Right`], the answer is pushed to the right edge of the terminal,
+        /// which lines up the answers of a list of questions like a form. Answers which do not fit
+        /// on the rest of the line are left aligned instead.
+        ///
+        /// If it is not given, it defaults to [`AnswerAlign::Left`].
+        ///
+        /// [`AnswerAlign::Right`]: crate::AnswerAlign::Right
+        /// [`AnswerAlign::Left`]: crate::AnswerAlign::Left
+        ///
+        ///
+        $(#[$answer_align_meta])*
+        pub fn answer_align(mut self, answer_align: ui::widgets::AnswerAlign) -> Self {
+            self.opts.answer_align = answer_align;
+            self
+        }
         )?
     };
 }
@@ -145,6 +210,20 @@ impl<T> Getter<'_, T> {
             Getter::Value(v) => v,
         }
     }
+
+    /// Calls the function with `answers` if it has not been called yet, so that the value can be
+    /// read again without consuming the getter.
+    pub(crate) fn resolve(&mut self, answers: &Answers) -> &T {
+        if let Getter::Function(_) = self {
+            let f = std::mem::replace(self, Getter::Function(Box::new(|_| unreachable!())));
+            *self = Getter::Value(f.get(answers));
+        }
+
+        match self {
+            Getter::Value(v) => v,
+            Getter::Function(_) => unreachable!(),
+        }
+    }
 }
 
 macro_rules! impl_getter_from_val {
@@ -101,6 +101,45 @@ impl<'a> OrderSelectBuilder<'a> {
         ///     //...
         ///     .build();
         /// ```
+
+        optional
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::{Answers, Question};
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .optional(true)
+        ///     //...
+        ///     .build();
+        /// ```
+
+        step
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::{Answers, Question};
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .step(2, 5)
+        ///     //...
+        ///     .build();
+        /// ```
+
+        answer_align
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::{AnswerAlign, Question};
+        ///
+        /// let order_select = Question::order_select("home_tasks")
+        ///     //...
+        ///     .answer_align(AnswerAlign::Right)
+        ///     //...
+        ///     .build();
+        /// ```
     }
 
     /// The maximum height that can be taken by the list
@@ -249,6 +288,27 @@ impl<'a> OrderSelectBuilder<'a> {
         [OrderSelectItem]; order_select
     }
 
+    crate::impl_display_transform_builder! {
+        /// # Examples
+        ///
+        /// ```
+        /// use requestty::Question;
+        ///
+        /// let order_select = Question::order_select("items")
+        ///     //...
+        ///     .display_transform(|items, previous_answers| {
+        ///         items
+        ///             .iter()
+        ///             .map(|item| item.text())
+        ///             .collect::<Vec<_>>()
+        ///             .join(" > ")
+        ///     })
+        ///     //...
+        ///     .build();
+        /// ```
+        [OrderSelectItem]; order_select
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
@@ -4,7 +4,7 @@ use ui::{
     backend::Backend,
     events::{EventIterator, KeyEvent},
     style::Color,
-    widgets::{self, Text},
+    widgets::{self, AnswerAlign, Text},
     Prompt, Widget,
 };
 
@@ -12,7 +12,7 @@ use super::{
     choice::SelectList,
     handler::{Filter, Transform, Validate},
 };
-use crate::{Answer, Answers};
+use crate::{Answer, Answers, ListItem};
 
 pub(super) mod builder;
 
@@ -111,7 +111,23 @@ impl widgets::List for OrderSelect<'_> {
     }
 }
 
+impl widgets::ReorderList for OrderSelect<'_> {
+    fn swap(&mut self, a: usize, b: usize) {
+        self.choices.swap(a, b)
+    }
+}
+
 impl<'c> OrderSelect<'c> {
+    /// Orders the choices as in a previous answer. Choices which are not in the answer are moved to
+    /// the start.
+    pub(super) fn prefill(&mut self, answer: &[ListItem]) {
+        self.choices.choices.sort_by_key(|choice| {
+            answer
+                .iter()
+                .position(|item| item.index == choice.initial_index)
+        });
+    }
+
     fn into_order_select_prompt<'a>(
         self,
         message: &'a str,
@@ -128,29 +144,33 @@ impl<'c> OrderSelect<'c> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_order_select_prompt(&message, answers), b)
+        let mut prompt = self.into_order_select_prompt(&message, answers);
+        prompt.prompt.set_step(opts.step);
+
+        let ans = ui::Input::new(prompt, b)
             .hide_cursor()
-            .on_esc(on_esc)
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| {
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
+    }
+
+    crate::impl_write_answer!(self; Transform<'_, [OrderSelectItem]>, Vec<OrderSelectItem> [ref];
+        |message, ans, b| {
             b.set_fg(Color::Cyan)?;
-            print_comma_separated(
-                ans.iter().map(|item| {
-                    item.text()
-                }),
-                b,
-            )?;
+            print_comma_separated(ans.iter().map(|item| item.text()), b)?;
             b.set_fg(Color::Reset)?;
-        })
-    }
+        }
+    );
 }
 
 fn print_comma_separated<'a, B: Backend>(
@@ -186,7 +206,7 @@ impl Prompt for OrderSelectPrompt<'_, '_> {
     fn finish(self) -> Self::Output {
         let OrderSelect {
             choices, filter, ..
-        } = self.select.into_inner();
+        } = self.select.apply_order();
 
         let mut c = choices.choices;
 
@@ -203,6 +223,10 @@ impl Prompt for OrderSelectPrompt<'_, '_> {
         }
         Ok(ui::Validation::Finish)
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl Widget for OrderSelectPrompt<'_, '_> {
@@ -223,6 +247,10 @@ impl Widget for OrderSelectPrompt<'_, '_> {
         self.select.cursor_pos(layout)
     }
 
+    fn wants_cursor(&self) -> bool {
+        self.select.wants_cursor()
+    }
+
     fn handle_key(&mut self, key: ui::events::KeyEvent) -> bool {
         let prev_at = self.select.get_at();
 
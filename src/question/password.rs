@@ -4,12 +4,26 @@ use ui::{
     backend::Backend,
     events::{EventIterator, KeyEvent},
     style::Stylize,
-    widgets, Validation, Widget,
+    widgets::{self, AnswerAlign},
+    Validation, Widget,
 };
 
 use super::{Filter, Options, Transform, Validate, ValidateOnKey};
 use crate::{Answer, Answers};
 
+/// How the characters of a [`password`] are shown as they are typed.
+///
+/// See [`PasswordBuilder::echo_mode`].
+///
+/// [`password`]: crate::question::Question::password
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EchoMode {
+    /// Nothing is shown.
+    Hidden,
+    /// Each character is replaced by the given mask.
+    Masked(char),
+}
+
 #[derive(Debug, Default)]
 pub(super) struct Password<'a> {
     mask: Option<char>,
@@ -32,11 +46,16 @@ impl ui::Prompt for PasswordPrompt<'_, '_> {
     type Output = String;
 
     fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        self.is_valid()?;
+        Ok(Validation::Finish)
+    }
+
+    fn is_valid(&mut self) -> Result<(), Self::ValidateErr> {
         if let Validate::Sync(ref mut validate) = self.password.validate {
             validate(self.input.value(), self.answers)?;
         }
 
-        Ok(Validation::Finish)
+        Ok(())
     }
 
     fn finish(self) -> Self::Output {
@@ -48,6 +67,10 @@ impl ui::Prompt for PasswordPrompt<'_, '_> {
 
         ans
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl Widget for PasswordPrompt<'_, '_> {
@@ -84,6 +107,19 @@ impl Widget for PasswordPrompt<'_, '_> {
         handled
     }
 
+    fn handle_paste(&mut self, text: &str) -> bool {
+        let handled = self.input.handle_paste(text);
+
+        match self.password.validate_on_key {
+            ValidateOnKey::Sync(ref mut validate) if handled => {
+                self.is_valid = validate(self.input.value(), self.answers);
+            }
+            _ => {}
+        }
+
+        handled
+    }
+
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
         self.input
             .cursor_pos(layout.with_cursor_pos(self.prompt.cursor_pos(layout)))
@@ -110,26 +146,27 @@ impl<'p> Password<'p> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_prompt(&message, answers), b)
-            .on_esc(on_esc)
+        let mut prompt = self.into_prompt(&message, answers);
+        prompt.prompt.set_step(opts.step);
+
+        let ans = ui::Input::new(prompt, b)
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(
-            transform,
-            message,
-            ans [ref],
-            answers,
-            b,
-            |_ans| b.write_styled(&"[hidden]".dark_grey())?
-        )
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
     }
+
+    crate::impl_write_answer!(self; Transform<'_, str>, String [ref]; |message, _ans, b| b
+        .write_styled(&"[hidden]".dark_grey())?);
 }
 
 /// The builder for an [`password`] prompt.
@@ -223,6 +260,39 @@ impl<'a> PasswordBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let password = Question::password("password")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a mask to print instead of the characters
@@ -244,6 +314,32 @@ impl<'a> PasswordBuilder<'a> {
         self
     }
 
+    /// Set how the characters are shown as they are typed.
+    ///
+    /// [`EchoMode::Masked`] is the same as setting a [`mask`]. The answer is never shown once the
+    /// question is answered, regardless of the echo mode.
+    ///
+    /// If `echo_mode` is not set, it will default to [`EchoMode::Hidden`].
+    ///
+    /// [`mask`]: PasswordBuilder::mask
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{question::EchoMode, Question};
+    ///
+    /// let password = Question::password("password")
+    ///     .echo_mode(EchoMode::Masked('•'))
+    ///     .build();
+    /// ```
+    pub fn echo_mode(mut self, echo_mode: EchoMode) -> Self {
+        self.password.mask = match echo_mode {
+            EchoMode::Hidden => None,
+            EchoMode::Masked(mask) => Some(mask),
+        };
+        self
+    }
+
     crate::impl_filter_builder! {
     /// # Examples
     ///
@@ -316,6 +412,19 @@ impl<'a> PasswordBuilder<'a> {
     str; password
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let password = Question::password("password")
+    ///     .display_transform(|password, previous_answers| "*".repeat(password.chars().count()))
+    ///     .build();
+    /// ```
+    str; password
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
@@ -334,7 +443,7 @@ impl<'a> From<PasswordBuilder<'a>> for super::Question<'a> {
 }
 #[cfg(test)]
 mod tests {
-    use ui::{backend::TestBackend, layout::Layout};
+    use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
 
     use super::*;
 
@@ -435,4 +544,32 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_echo_mode() {
+        fn render(echo_mode: EchoMode) -> String {
+            let answers = Answers::default();
+            let builder = PasswordBuilder::new("name".into())
+                .mask('*')
+                .echo_mode(echo_mode);
+            let mut prompt = builder.password.into_prompt("message", &answers);
+            prompt.handle_key(KeyCode::Char('a').into());
+            prompt.handle_key(KeyCode::Char('b').into());
+
+            let size = (50, 20).into();
+            let mut backend = TestBackend::new(size);
+            prompt
+                .render(&mut Layout::new(0, size), &mut backend)
+                .unwrap();
+            backend.to_string()
+        }
+
+        let hidden = render(EchoMode::Hidden);
+        assert!(!hidden.contains("ab"));
+        assert!(!hidden.contains('*'));
+
+        let masked = render(EchoMode::Masked('•'));
+        assert!(!masked.contains("ab"));
+        assert!(masked.contains("••"));
+    }
 }
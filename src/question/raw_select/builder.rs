@@ -101,6 +101,39 @@ impl<'a> RawSelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
@@ -312,6 +345,19 @@ impl<'a> RawSelectBuilder<'a> {
     ListItem; raw_select
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let raw_select = Question::raw_select("theme")
+    ///     .display_transform(|choice, previous_answers| format!("Theme #{}", choice.index + 1))
+    ///     .build();
+    /// ```
+    ListItem; raw_select
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
@@ -4,7 +4,7 @@ use ui::{
     backend::Backend,
     events::{EventIterator, KeyEvent},
     style::{Color, Stylize},
-    widgets::{self, List, Text},
+    widgets::{self, AnswerAlign, List, Text},
     Prompt, Validation, Widget,
 };
 
@@ -65,6 +65,10 @@ impl Prompt for RawSelectPrompt<'_> {
         let index = self.select.get_at();
         self.finish_index(index)
     }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 const ANSWER_PROMPT: &[u8] = b"  Answer: ";
@@ -190,6 +194,11 @@ impl widgets::List for RawSelect<'_> {
 }
 
 impl<'a> RawSelect<'a> {
+    /// Sets the default to the choice of a previous answer.
+    pub(super) fn prefill(&mut self, answer: &ListItem) {
+        self.choices.set_default(answer.index);
+    }
+
     fn into_prompt(self, message: &'a str) -> RawSelectPrompt<'a> {
         let mut select = widgets::Select::new(self);
 
@@ -213,23 +222,31 @@ impl<'a> RawSelect<'a> {
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
 
-        let ans = ui::Input::new(self.into_prompt(&message), b)
-            .on_esc(on_esc)
+        let mut prompt = self.into_prompt(&message);
+        prompt.prompt.set_step(opts.step);
+
+        let ans = ui::Input::new(prompt, b)
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
+    }
+
+    crate::impl_write_answer!(self; Transform<'_, ListItem>, ListItem [ref]; |message, ans, b| b
+        .write_styled(
             &ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
                 .cyan()
-        )?)
-    }
+        )?);
 }
@@ -126,3 +126,20 @@ fn test_cursor_pos() {
         assert_eq!(select.cursor_pos(layout), (line_offset, 12));
     }
 }
+
+#[test]
+fn test_invalid_number() {
+    let mut raw_select = raw_select("message");
+    raw_select.height(&mut Layout::new(0, (50, 20).into()));
+
+    assert!(raw_select.handle_key(KeyCode::Char('9').into()));
+    assert!(raw_select.handle_key(KeyCode::Char('9').into()));
+    assert_eq!(raw_select.input.value(), "99");
+    assert_eq!(raw_select.validate(), Err("Please enter a valid choice"));
+
+    // Moving from an invalid number goes back to the choices, and shows the hovered number
+    assert!(raw_select.handle_key(KeyCode::Down.into()));
+    assert_eq!(raw_select.select.get_at(), 0);
+    assert_eq!(raw_select.input.value(), "1");
+    assert_eq!(raw_select.validate(), Ok(Validation::Finish));
+}
@@ -3,7 +3,7 @@ use ui::{backend::Backend, widgets::Text};
 use super::Select;
 use crate::{
     question::{Choice, Options},
-    ListItem,
+    KeyBindings, ListItem,
 };
 
 /// The builder for a [`select`] prompt.
@@ -41,6 +41,7 @@ use crate::{
 pub struct SelectBuilder<'a> {
     opts: Options<'a>,
     select: Select<'a>,
+    default_name: Option<String>,
 }
 
 impl<'a> SelectBuilder<'a> {
@@ -48,6 +49,7 @@ impl<'a> SelectBuilder<'a> {
         SelectBuilder {
             opts: Options::new(name),
             select: Default::default(),
+            default_name: None,
         }
     }
 
@@ -98,20 +100,55 @@ impl<'a> SelectBuilder<'a> {
     ///     .on_esc(OnEsc::Terminate)
     ///     .build();
     /// ```
+
+    optional
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::select("theme")
+    ///     .optional(true)
+    ///     .build();
+    /// ```
+
+    step
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{Question, Answers};
+    ///
+    /// let select = Question::select("theme")
+    ///     .step(2, 5)
+    ///     .build();
+    /// ```
+
+    answer_align
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{AnswerAlign, Question};
+    ///
+    /// let select = Question::select("theme")
+    ///     .answer_align(AnswerAlign::Right)
+    ///     .build();
+    /// ```
     }
 
     /// Set a default index for the select
     ///
-    /// The given index will be hovered in the beginning.
+    /// The given index will be hovered in the beginning. If the list does not fit on one page, the
+    /// first page is moved down so that the default is visible.
     ///
     /// If `default` is unspecified, the first [`Choice`] will be hovered.
     ///
     /// # Panics
     ///
-    /// If the default given is not a [`Choice`], it will cause a panic on [`build`]
+    /// If the default given is not a [`Choice`] or is [disabled], it will cause a panic on [`build`]
     ///
     /// [`Choice`]: crate::question::Choice
     /// [`build`]: Self::build
+    /// [disabled]: Self::disabled_choice
     ///
     /// # Examples
     ///
@@ -135,6 +172,33 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Set the default to the first [`Choice`] with the given text
+    ///
+    /// This is useful to hover a previously chosen option, when only its text was saved. The text
+    /// is looked up when the question is [built](Self::build), so it can be called before the
+    /// choices are added. This takes precedence over [`default`](Self::default).
+    ///
+    /// If no choice has the text, or it is [disabled](Self::disabled_choice), the [`default`]
+    /// set by index is hovered instead, or the first [`Choice`] if there is none.
+    ///
+    /// [`Choice`]: crate::question::Choice
+    /// [`default`]: Self::default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .default_by_name("Dark")
+    ///     .choices(vec!["Light", "Dark"])
+    ///     .build();
+    /// ```
+    pub fn default_by_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.default_name = Some(name.into());
+        self
+    }
+
     /// The maximum height that can be taken by the list
     ///
     /// If the total height exceeds the page size, the list will be scrollable.
@@ -179,6 +243,123 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Whether the choices can be filtered by typing.
+    ///
+    /// The typed query is shown after the message, and only the choices which fuzzy match it are
+    /// shown. Moving up and down goes through the matching choices. If nothing matches, the
+    /// question cannot be submitted until the query is changed.
+    ///
+    /// If `filterable` is not set, it will default to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .filterable(true)
+    ///     .build();
+    /// ```
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.select.filterable = filterable;
+        self
+    }
+
+    /// Whether typing the start of a choice moves to it.
+    ///
+    /// Typed letters are added to a prefix, and the next choice which starts with it is hovered,
+    /// ignoring case. The start of each matching choice is underlined. The prefix is started again
+    /// if nothing is typed for a second. Unlike [`filterable`], every choice is still shown. If
+    /// both are set, the choices are filtered instead.
+    ///
+    /// As letters are typed into the prefix, they cannot also be movement keys, so this is best
+    /// used with [`KeyBindings::arrows`] as the [`bindings`].
+    ///
+    /// If `type_ahead` is not set, it will default to `false`.
+    ///
+    /// [`filterable`]: Self::filterable
+    /// [`bindings`]: Self::bindings
+    /// [`KeyBindings::arrows`]: crate::KeyBindings::arrows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{KeyBindings, Question};
+    ///
+    /// let select = Question::select("theme")
+    ///     .type_ahead(true)
+    ///     .bindings(KeyBindings::arrows())
+    ///     .build();
+    /// ```
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.select.type_ahead = type_ahead;
+        self
+    }
+
+    /// The keys used to move through the choices.
+    ///
+    /// These only apply to this question, every other question keeps the default keys. If
+    /// `bindings` is not set, it will default to [`KeyBindings::new`], which are the arrow keys
+    /// along with `h`, `j`, `k` and `l` and the other keys captured by [`Movement`].
+    ///
+    /// [`KeyBindings::new`]: crate::KeyBindings::new
+    /// [`Movement`]: crate::prompt::events::Movement
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::{
+    ///     prompt::events::{KeyCode, KeyEvent, KeyModifiers, Movement},
+    ///     KeyBindings, Question,
+    /// };
+    ///
+    /// let select = Question::select("theme")
+    ///     .bindings(
+    ///         KeyBindings::arrows()
+    ///             .bind(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL), Movement::Down)
+    ///             .bind(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL), Movement::Up),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn bindings(mut self, bindings: KeyBindings) -> Self {
+        self.select.bindings = bindings;
+        self
+    }
+
+    /// The icons shown before each choice, such as `✓` or `✗`.
+    ///
+    /// The `n`th prefix is shown before the `n`th item, counting separators. The prefixes are
+    /// padded to a column as wide as the widest of them followed by a space, so that the text of
+    /// every choice lines up, including the lines of choices which wrap. Items without a prefix
+    /// are left blank in that column.
+    ///
+    /// If `prefixes` is not set, no column is shown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("file")
+    ///     .choices(vec!["src/lib.rs", "Cargo.toml", "README.md"])
+    ///     .prefixes(vec!["M", "A", "??"])
+    ///     .build();
+    /// ```
+    pub fn prefixes<I, S>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let prefixes: Vec<String> = prefixes.into_iter().map(Into::into).collect();
+        self.select.prefix_width = prefixes
+            .iter()
+            .map(|prefix| textwrap::core::display_width(prefix) as u16 + 1)
+            .max()
+            .unwrap_or(0);
+        self.select.prefixes = prefixes;
+        self
+    }
+
     /// Inserts a [`Choice`] with the given text.
     ///
     /// See [`select`] for more information.
@@ -203,6 +384,36 @@ impl<'a> SelectBuilder<'a> {
         self
     }
 
+    /// Inserts a [`Choice`] with the given text which cannot be selected.
+    ///
+    /// The choice is shown in dark grey and skipped when moving through the list, like a
+    /// separator. It is followed by the `reason` it is disabled in brackets, if they fit on the
+    /// same line. An empty `reason` is not shown.
+    ///
+    /// [`Choice`]: crate::question::Choice::Choice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("plan")
+    ///     .choice("Basic")
+    ///     .disabled_choice("Priority support", "requires pro plan")
+    ///     .build();
+    /// ```
+    pub fn disabled_choice<I, R>(mut self, text: I, reason: R) -> Self
+    where
+        I: Into<String>,
+        R: Into<String>,
+    {
+        let choices = &mut self.select.choices.choices;
+        self.select.disabled.resize(choices.len(), None);
+        self.select.disabled.push(Some(reason.into()));
+        choices.push(Choice::Choice(Text::new(text.into())));
+        self
+    }
+
     /// Inserts a [`Separator`] with the given text
     ///
     /// See [`select`] for more information.
@@ -299,14 +510,48 @@ impl<'a> SelectBuilder<'a> {
     ListItem; select
     }
 
+    crate::impl_display_transform_builder! {
+    /// # Examples
+    ///
+    /// ```
+    /// use requestty::Question;
+    ///
+    /// let select = Question::select("theme")
+    ///     .display_transform(|choice, previous_answers| format!("Theme #{}", choice.index + 1))
+    ///     .build();
+    /// ```
+    ListItem; select
+    }
+
     /// Consumes the builder returning a [`Question`]
     ///
     /// [`Question`]: crate::question::Question
-    pub fn build(self) -> crate::question::Question<'a> {
+    pub fn build(mut self) -> crate::question::Question<'a> {
+        if let Some(name) = self.default_name.take() {
+            let default = self
+                .select
+                .choices
+                .choices
+                .iter()
+                .enumerate()
+                .position(|(i, choice)| {
+                    matches!(choice, Choice::Choice(text) if text.text == name)
+                        && !self.select.is_disabled(i)
+                });
+
+            // Otherwise the default set by index is kept
+            if let Some(default) = default {
+                self.select.choices.set_default(default);
+            }
+        }
+
         if let Some(default) = self.select.choices.default() {
             if self.select.choices[default].is_separator() {
                 panic!("Invalid default '{}' is not a `Choice`", default);
             }
+            if self.select.is_disabled(default) {
+                panic!("Invalid default '{}' is disabled", default);
+            }
         }
 
         crate::question::Question::new(
@@ -2,13 +2,13 @@ use std::io;
 
 use ui::{
     backend::Backend,
-    events::{EventIterator, KeyEvent},
+    events::{EventIterator, KeyBindings, KeyEvent},
     style::Stylize,
-    widgets::{self, Text},
-    Prompt, Widget,
+    widgets::{self, AnswerAlign, List, Text},
+    Prompt, Validation, Widget,
 };
 
-use super::Transform;
+use super::{Choice, ListFilter, Transform};
 use crate::{Answer, Answers, ListItem};
 
 pub use builder::SelectBuilder;
@@ -21,12 +21,23 @@ mod tests;
 #[derive(Debug, Default)]
 pub(super) struct Select<'a> {
     choices: super::ChoiceList<Text<String>>,
+    // The reason each choice is disabled, or `None` if it is not. Choices after the end of this are
+    // not disabled.
+    disabled: Vec<Option<String>>,
+    // The icon shown before each choice, and the width of the column they are shown in. Choices
+    // after the end of this have an empty icon.
+    prefixes: Vec<String>,
+    prefix_width: u16,
+    filterable: bool,
+    type_ahead: bool,
+    bindings: KeyBindings,
     transform: Transform<'a, ListItem>,
 }
 
 struct SelectPrompt<'a> {
     prompt: widgets::Prompt<&'a str>,
     select: widgets::Select<Select<'a>>,
+    filter: Option<ListFilter>,
 }
 
 impl SelectPrompt<'_> {
@@ -49,26 +60,61 @@ impl Prompt for SelectPrompt<'_> {
     type ValidateErr = &'static str;
     type Output = ListItem;
 
+    fn validate(&mut self) -> Result<Validation, Self::ValidateErr> {
+        // There is nothing to pick until the filter matches something which is not disabled
+        if self.select.is_empty() || !self.select.list.is_selectable(self.select.get_at()) {
+            Ok(Validation::Continue)
+        } else {
+            Ok(Validation::Finish)
+        }
+    }
+
     fn finish(self) -> Self::Output {
         let index = self.select.get_at();
         self.finish_index(index)
     }
+
+    fn tick(&mut self) -> bool {
+        self.select.tick()
+    }
+
+    fn message(&self) -> Option<&str> {
+        Some(self.prompt.message())
+    }
 }
 
 impl Widget for SelectPrompt<'_> {
     fn render<B: Backend>(&mut self, layout: &mut ui::layout::Layout, b: &mut B) -> io::Result<()> {
         self.prompt.render(layout, b)?;
+        if let Some(ref mut filter) = self.filter {
+            filter.render(&self.select, layout, b)?;
+        }
         self.select.render(layout, b)
     }
 
     fn height(&mut self, layout: &mut ui::layout::Layout) -> u16 {
-        self.prompt.height(layout) + self.select.height(layout) - 1
+        let mut height = self.prompt.height(layout);
+        if let Some(ref mut filter) = self.filter {
+            height += filter.height(&self.select, layout) - 1;
+        }
+        height + self.select.height(layout) - 1
     }
 
     fn cursor_pos(&mut self, layout: ui::layout::Layout) -> (u16, u16) {
-        self.select.cursor_pos(layout)
+        match self.filter {
+            Some(ref mut filter) => {
+                let layout = layout.with_cursor_pos(self.prompt.cursor_pos(layout));
+                filter.cursor_pos(&self.select, layout)
+            }
+            None => self.select.cursor_pos(layout),
+        }
     }
 
+    fn wants_cursor(&self) -> bool {
+        self.filter.is_some() || self.select.wants_cursor()
+    }
+
+    /// The query is typed into the list if it can be filtered.
     fn handle_key(&mut self, key: KeyEvent) -> bool {
         self.select.handle_key(key)
     }
@@ -82,14 +128,68 @@ impl widgets::List for Select<'_> {
         layout: ui::layout::Layout,
         backend: &mut B,
     ) -> io::Result<()> {
-        self.choices.render_item(index, hovered, layout, backend)
+        let selectable = self.is_selectable(index);
+        let prefixes = &self.prefixes;
+        let prefix_width = self.prefix_width;
+        let disabled = &self.disabled;
+
+        self.choices.render_item_with(
+            index,
+            hovered,
+            selectable,
+            layout,
+            backend,
+            |choice, layout, b| {
+                render_prefix(prefixes.get(index), prefix_width, layout, b)?;
+
+                if !super::choice::is_disabled(disabled, index) {
+                    return choice.render(layout, b);
+                }
+
+                let reason = super::choice::disabled_reason(disabled, index);
+                match super::choice::with_disabled_reason(choice, reason, layout.line_width()) {
+                    Some(with_reason) => b.write_all(with_reason.as_bytes()),
+                    None => choice.render(layout, b),
+                }
+            },
+        )
+    }
+
+    fn render_matched_item<B: Backend>(
+        &mut self,
+        index: usize,
+        hovered: bool,
+        matched: usize,
+        layout: ui::layout::Layout,
+        backend: &mut B,
+    ) -> io::Result<()> {
+        if self.is_disabled(index) {
+            return self.render_item(index, hovered, layout, backend);
+        }
+
+        let prefixes = &self.prefixes;
+        let prefix_width = self.prefix_width;
+
+        self.choices.render_matched_item_with(
+            index,
+            hovered,
+            matched,
+            layout,
+            backend,
+            |layout, b| render_prefix(prefixes.get(index), prefix_width, layout, b),
+        )
     }
 
     fn is_selectable(&self, index: usize) -> bool {
-        self.choices.is_selectable(index)
+        self.choices.is_selectable(index) && !self.is_disabled(index)
     }
 
-    fn height_at(&mut self, index: usize, layout: ui::layout::Layout) -> u16 {
+    fn disabled_reason(&self, index: usize) -> Option<&str> {
+        super::choice::disabled_reason(&self.disabled, index)
+    }
+
+    fn height_at(&mut self, index: usize, mut layout: ui::layout::Layout) -> u16 {
+        layout.offset_x = (layout.offset_x + self.prefix_width).min(layout.width);
         self.choices.height_at(index, layout)
     }
 
@@ -107,38 +207,132 @@ impl widgets::List for Select<'_> {
 }
 
 impl<'a> Select<'a> {
-    fn into_prompt(self, message: &'a str) -> SelectPrompt<'a> {
-        let mut select = widgets::Select::new(self);
+    /// Sets the default to the choice of a previous answer.
+    pub(super) fn prefill(&mut self, answer: &ListItem) {
+        self.choices.set_default(answer.index);
+    }
+
+    /// Whether the choice at `index` was added with [`SelectBuilder::disabled_choice`].
+    fn is_disabled(&self, index: usize) -> bool {
+        super::choice::is_disabled(&self.disabled, index)
+    }
+
+    fn into_prompt(mut self, message: &'a str) -> SelectPrompt<'a> {
+        let bindings = std::mem::take(&mut self.bindings);
+        let filterable = self.filterable;
+        let texts = if filterable || self.type_ahead {
+            Some(ListFilter::texts(&self.choices))
+        } else {
+            None
+        };
+
+        let mut select = widgets::Select::new(self).with_bindings(bindings);
         if let Some(default) = select.list.choices.default() {
             select.set_at(default);
         }
 
+        let filter = match texts {
+            Some(texts) if filterable => {
+                select = select.with_query_filter(texts);
+                Some(ListFilter::new())
+            }
+            Some(texts) => {
+                select = select.with_type_ahead(texts);
+                None
+            }
+            None => None,
+        };
+
         SelectPrompt {
             prompt: widgets::Prompt::new(message),
             select,
+            filter,
+        }
+    }
+
+    /// Finds the choice whose text is exactly `label`.
+    ///
+    /// It is an error if no choice or more than one choice matches. Disabled choices never match.
+    pub(crate) fn resolve_label(self, label: &str) -> io::Result<ListItem> {
+        let disabled = self.disabled;
+        let mut matches = self.choices.choices.into_iter().enumerate().filter_map(
+            |(index, choice)| match choice {
+                Choice::Choice(text)
+                    if text.text == label && !super::choice::is_disabled(&disabled, index) =>
+                {
+                    Some((index, text.text))
+                }
+                _ => None,
+            },
+        );
+
+        match (matches.next(), matches.next()) {
+            (Some((index, text)), None) => Ok(ListItem { index, text }),
+            (Some(_), Some(_)) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} matches more than one choice", label),
+            )),
+            (None, _) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{:?} does not match any choice", label),
+            )),
         }
     }
 
     pub(crate) fn ask<B: Backend, E: EventIterator>(
         mut self,
         message: String,
-        on_esc: ui::OnEsc,
+        opts: crate::question::AskOpts,
         answers: &Answers,
         b: &mut B,
         events: &mut E,
     ) -> ui::Result<Option<Answer>> {
         let transform = self.transform.take();
-        let ans = ui::Input::new(self.into_prompt(&message), b)
-            .hide_cursor()
-            .on_esc(on_esc)
+        let mut prompt = self.into_prompt(&message);
+        prompt.prompt.set_step(opts.step);
+
+        // The cursor is needed to type the filter
+        let hide_cursor = prompt.filter.is_none();
+        let mut input = ui::Input::new(prompt, b);
+        if hide_cursor {
+            input = input.hide_cursor();
+        }
+
+        let ans = input
+            .on_esc(opts.on_esc)
+            .skip_key(opts.skip_key)
+            .set_title_to_message(opts.set_title)
             .run(events)?;
 
-        crate::write_final!(transform, message, ans [ref], answers, b, |ans| b.write_styled(
+        Self::write_answer(transform, message, ans, opts.answer_align, answers, b)
+    }
+
+    crate::impl_write_answer!(self; Transform<'_, ListItem>, ListItem [ref]; |message, ans, b| b
+        .write_styled(
             &ans.text
                 .lines()
                 .next()
                 .expect("There must be at least one line in a `str`")
                 .cyan()
-        )?)
+        )?);
+}
+
+/// Writes the `prefix` of a choice padded to the `prefix_width` of the prefix column, and moves
+/// `layout` past the column.
+fn render_prefix<B: Backend>(
+    prefix: Option<&String>,
+    prefix_width: u16,
+    layout: &mut ui::layout::Layout,
+    backend: &mut B,
+) -> io::Result<()> {
+    if prefix_width == 0 {
+        return Ok(());
     }
+
+    let prefix = prefix.map_or("", String::as_str);
+    let padding = prefix_width as usize - textwrap::core::display_width(prefix);
+    write!(backend, "{}{:2$}", prefix, "", padding)?;
+
+    layout.offset_x = (layout.offset_x + prefix_width).min(layout.width);
+    Ok(())
 }
@@ -1,6 +1,6 @@
 use rand::prelude::*;
 use rand_chacha::ChaCha12Rng;
-use ui::{backend::TestBackend, events::KeyCode, layout::Layout};
+use ui::{backend::TestBackend, events::KeyCode, layout::Layout, style::Color};
 
 use crate::question::{Choice, Question, QuestionKind};
 
@@ -128,3 +128,160 @@ test_select!(pagination {
         );
     height = 16;
 });
+
+#[test]
+fn test_prefixes() {
+    use std::io::Write;
+
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(vec!["Staged", "Untracked in tree"])
+            .prefixes(vec!["✓", "??"]),
+    );
+
+    let size = (20, 10).into();
+    let layout = Layout::new(0, size);
+
+    // The text only has the 15 columns after the pointer and the prefix column
+    assert_eq!(select.height_at(0, layout), 1);
+    assert_eq!(select.height_at(1, layout), 2);
+
+    let mut backend = TestBackend::new(size);
+    select.render_item(0, true, layout, &mut backend).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    write!(expected, "{} ✓  Staged", ui::symbols::current().pointer).unwrap();
+    expected.move_cursor_to(5, 1).unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    backend.assert_eq(&expected);
+
+    let mut backend = TestBackend::new(size);
+    select.render_item(1, false, layout, &mut backend).unwrap();
+
+    // The wrapped line starts under the text, not under the prefix
+    let rendered = backend.to_string();
+    assert!(rendered.contains("│  ?? Untracked in   │"));
+    assert!(rendered.contains("│     tree           │"));
+}
+
+#[test]
+fn test_wide_prefixes() {
+    use std::io::Write;
+
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(vec!["Done", "Todo"])
+            .prefixes(vec!["完", "-"]),
+    );
+
+    let size = (20, 10).into();
+    let layout = Layout::new(0, size);
+
+    // The prefix column is as wide as the widest prefix is displayed
+    let mut backend = TestBackend::new(size);
+    select.render_item(1, true, layout, &mut backend).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    write!(expected, "{} -  Todo", ui::symbols::current().pointer).unwrap();
+    expected.move_cursor_to(5, 1).unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    backend.assert_eq(&expected);
+
+    let mut backend = TestBackend::new(size);
+    select.render_item(0, true, layout, &mut backend).unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    write!(expected, "{} 完 Done", ui::symbols::current().pointer).unwrap();
+    expected.move_cursor_to(5, 1).unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_disabled_choice() {
+    use std::io::Write;
+
+    let mut select = unwrap_select(
+        SelectBuilder::new("plan".into())
+            .choice("Basic")
+            .disabled_choice("Pro", "requires pro plan")
+            .disabled_choice("Legacy", "")
+            .disabled_choice("团队", "需要专业版"),
+    );
+
+    let render = |select: &mut Select<'_>, index: usize, width: u16| {
+        let size = (width, 10).into();
+        let mut backend = TestBackend::new(size);
+        select
+            .render_item(index, false, Layout::new(0, size), &mut backend)
+            .unwrap();
+        backend
+    };
+
+    let expected = |width: u16, text: &str, wraps: bool| {
+        let mut expected = TestBackend::new((width, 10).into());
+        expected.write_all(b"  ").unwrap();
+        expected.set_fg(Color::DarkGrey).unwrap();
+        expected.write_all(text.as_bytes()).unwrap();
+        if wraps {
+            expected.move_cursor_to(2, 1).unwrap();
+        }
+        expected.set_fg(Color::Reset).unwrap();
+        expected
+    };
+
+    // The reason is shown in brackets after the text
+    render(&mut select, 1, 30).assert_eq(&expected(30, "Pro (requires pro plan)", false));
+
+    // An empty reason is not shown
+    render(&mut select, 2, 30).assert_eq(&expected(30, "Legacy", true));
+
+    // The reason is only shown if it fits, which depends on how wide it is displayed. It takes 17
+    // columns here, even though it is only 10 characters
+    render(&mut select, 3, 20).assert_eq(&expected(20, "团队 (需要专业版)", false));
+    render(&mut select, 3, 16).assert_eq(&expected(16, "团队", true));
+}
+
+#[test]
+fn test_render_matched_item() {
+    use std::io::Write;
+
+    use ui::style::Attributes;
+
+    let mut select = unwrap_select(
+        SelectBuilder::new("name".into())
+            .choices(vec!["Banana", "Blueberry and a very long name"])
+            .prefixes(vec!["✓"]),
+    );
+
+    let size = (20, 10).into();
+    let layout = Layout::new(0, size);
+
+    let mut backend = TestBackend::new(size);
+    select
+        .render_matched_item(0, true, 2, layout, &mut backend)
+        .unwrap();
+
+    let mut expected = TestBackend::new(size);
+    expected.set_fg(Color::Cyan).unwrap();
+    write!(expected, "{} ✓ ", ui::symbols::current().pointer).unwrap();
+    expected.set_attributes(Attributes::UNDERLINED).unwrap();
+    expected.write_all(b"Ba").unwrap();
+    expected.set_attributes(Attributes::empty()).unwrap();
+    expected.write_all(b"nana").unwrap();
+    expected.set_fg(Color::Reset).unwrap();
+    backend.assert_eq(&expected);
+
+    // Text which does not fit on the line is not underlined
+    let mut backend = TestBackend::new(size);
+    select
+        .render_matched_item(1, false, 2, layout, &mut backend)
+        .unwrap();
+
+    let mut expected = TestBackend::new(size);
+    select.render_item(1, false, layout, &mut expected).unwrap();
+    backend.assert_eq(&expected);
+}
@@ -0,0 +1,46 @@
+use requestty::Question;
+use ui::{
+    backend::TestBackend,
+    events::{KeyCode, TestEvents},
+    layout::Layout,
+    widgets::Prompt,
+    Widget,
+};
+
+const BOLD: &str = "\x1b[1m";
+
+fn render_prompt() -> String {
+    let size = (50, 20).into();
+    let mut backend = TestBackend::new(size);
+    Prompt::<_>::new("message")
+        .render(&mut Layout::new(0, size), &mut backend)
+        .unwrap();
+    backend.to_string()
+}
+
+// This is a separate test binary since the setting is global, and would otherwise change how the
+// prompts of other tests are rendered
+#[test]
+fn test_bold_message() {
+    assert!(render_prompt().contains(BOLD));
+
+    requestty::bold_message(false);
+    assert!(!ui::widgets::is_message_bold());
+    assert!(!render_prompt().contains(BOLD));
+
+    let mut backend = TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Char('y').into(), KeyCode::Enter.into()]);
+    requestty::prompt_one_with(
+        Question::confirm("name").message("message"),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    let finished = backend.to_string();
+    assert!(finished.contains("message"));
+    assert!(!finished.contains(BOLD));
+
+    requestty::bold_message(true);
+    assert!(render_prompt().contains(BOLD));
+}
@@ -174,3 +174,28 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_range() {
+    let prompt = Question::float_range("name", 0.0..=1.0).message("message");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('-').into(),
+        KeyCode::Char('2').into(),
+        // Rejected, since -2 < 0
+        KeyCode::Enter.into(),
+        KeyCode::Up.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Float(1.0));
+}
+
+#[test]
+#[should_panic(expected = "the start of the range must not be greater than the end")]
+fn test_range_reversed() {
+    let _ = Question::float_range("name", 1.0..=0.0);
+}
@@ -1,7 +1,7 @@
 use requestty::{question::Completions, Answer, Question};
 use ui::{
-    events::{KeyCode, TestEvents},
-    style::Color,
+    events::{KeyCode, KeyEvent, KeyModifiers, TestEvents},
+    style::{Color, Stylize},
 };
 
 mod helpers;
@@ -211,3 +211,232 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_trim() {
+    let prompt = Question::input("name")
+        .message("message")
+        .trim(true)
+        .collapse_whitespace(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(
+        "  a \t b  "
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("a b".into()));
+}
+
+#[test]
+fn test_trim_required() {
+    let prompt = Question::input("name")
+        .message("message")
+        .trim(true)
+        .validate(|s, _| {
+            if s.is_empty() {
+                Err("The name is required".into())
+            } else {
+                Ok(())
+            }
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char(' ').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('s').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("s".into()));
+
+    let prompt = Question::input("name")
+        .message("message")
+        .trim(true)
+        .default("default")
+        .validate(|_, _| Err("validate should be skipped".into()));
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Char(' ').into(), KeyCode::Enter.into()]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("default".into()));
+}
+
+#[test]
+fn test_multiline() {
+    let prompt = Question::input("notes").message("message").multiline(true);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(
+        "first line\nsecond line"
+            .chars()
+            .map(|c| match c {
+                '\n' => KeyCode::Enter.into(),
+                c => KeyCode::Char(c).into(),
+            })
+            .chain(Some(KeyEvent::new(
+                KeyCode::Char('d'),
+                KeyModifiers::CONTROL,
+            ))),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("first line\nsecond line".into()));
+}
+
+#[test]
+fn test_display_transform() {
+    let displayed = std::cell::RefCell::new(None);
+
+    let prompt = Question::input("slug")
+        .message("message")
+        .filter(|s, _| s.to_lowercase().replace(' ', "-"))
+        .display_transform(|slug, _| {
+            *displayed.borrow_mut() = Some(slug.to_owned());
+            "My Title".into()
+        });
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(
+        "My Title"
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("my-title".into()));
+    assert_eq!(displayed.into_inner().as_deref(), Some("my-title"));
+}
+
+#[test]
+fn test_indent() {
+    use std::io::Write;
+    use ui::backend::Backend;
+
+    let prompt = Question::input("name").message("message").indent(4);
+
+    let size = (50, 20).into();
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(
+        "abc"
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("abc".into()));
+
+    // The answered prompt is written at the indent as well
+    let mut expected = ui::backend::TestBackend::new(size);
+    expected.move_cursor_to(4, 0).unwrap();
+    ui::widgets::Prompt::write_finished_message(&"message", false, &mut expected).unwrap();
+    expected.write_styled(&"abc".cyan()).unwrap();
+    expected.write_all(b"\n").unwrap();
+
+    backend.assert_eq(&expected);
+
+    // Right aligned answers still end before the edge of the terminal
+    let prompt = Question::input("name")
+        .message("message")
+        .indent(4)
+        .answer_align(requestty::AnswerAlign::Right);
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(
+        "abc"
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+    requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+
+    // "✔ message · " takes 12 columns
+    let mut expected = ui::backend::TestBackend::new(size);
+    expected.move_cursor_to(4, 0).unwrap();
+    ui::widgets::Prompt::write_finished_message(&"message", false, &mut expected).unwrap();
+    write!(expected, "{:30}", "").unwrap();
+    expected.write_styled(&"abc".cyan()).unwrap();
+    expected.write_all(b"\n").unwrap();
+
+    backend.assert_eq(&expected);
+}
+
+#[test]
+fn test_transcript() {
+    let mut transcript = Vec::new();
+
+    let prompt = Question::input("name")
+        .message("message")
+        .transcript(&mut transcript);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(
+        "abc"
+            .chars()
+            .map(|c| KeyCode::Char(c).into())
+            .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::String("abc".into()));
+
+    assert_eq!(String::from_utf8(transcript).unwrap(), "✔ message · abc\n");
+}
+
+#[test]
+fn test_mark_modified() {
+    fn finished_line(keys: Vec<KeyEvent>) -> String {
+        let mut transcript = Vec::new();
+
+        let prompt = Question::input("name")
+            .message("message")
+            .default("John")
+            .mark_modified(true)
+            .transcript(&mut transcript);
+
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        let mut events = TestEvents::new(keys.into_iter().chain(Some(KeyCode::Enter.into())));
+        requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+
+        String::from_utf8(transcript).unwrap()
+    }
+
+    fn typed(text: &str) -> Vec<KeyEvent> {
+        text.chars().map(|c| KeyCode::Char(c).into()).collect()
+    }
+
+    // The input starts with the default
+    assert_eq!(finished_line(Vec::new()), "✔ message · John\n");
+
+    // Empty with a default
+    let clear = KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL);
+    assert_eq!(finished_line(vec![clear]), "✔ message · John\n");
+
+    // Edited
+    let mut keys = vec![clear];
+    keys.extend(typed("Jane"));
+    assert_eq!(finished_line(keys), "✔ message · Jane (modified)\n");
+    assert_eq!(
+        finished_line(typed("ny")),
+        "✔ message · Johnny (modified)\n"
+    );
+
+    // Reverted to the default
+    let mut keys = typed("ny");
+    keys.push(KeyCode::Backspace.into());
+    keys.push(KeyCode::Backspace.into());
+    assert_eq!(finished_line(keys), "✔ message · John\n");
+    let mut keys = vec![clear];
+    keys.extend(typed("John"));
+    assert_eq!(finished_line(keys), "✔ message · John\n");
+}
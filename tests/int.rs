@@ -170,3 +170,78 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_range() {
+    let prompt = Question::int_range("name", 0..=10).message("message");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(
+        vec![
+            KeyCode::Char('1').into(),
+            KeyCode::Char('5').into(),
+            // Rejected, since 15 > 10
+            KeyCode::Enter.into(),
+            KeyCode::Backspace.into(),
+        ]
+        .into_iter()
+        .chain((0..20).map(|_| KeyCode::Up.into()))
+        .chain(Some(KeyCode::Enter.into())),
+    );
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(10));
+
+    let prompt = Question::int_range("name", 0..=10).message("message");
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('2').into(),
+        KeyCode::PageDown.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(0));
+}
+
+#[test]
+#[should_panic(expected = "the start of the range must not be greater than the end")]
+fn test_range_reversed() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = Question::int_range("name", 10..=0);
+}
+
+#[test]
+fn test_render_answered() {
+    let size = (50, 20).into();
+    let question = || {
+        Question::int("name")
+            .message("message")
+            .transform(|n, _, b| write!(b, "{} years", n))
+    };
+
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('3').into(),
+        KeyCode::Char('2').into(),
+        KeyCode::Enter.into(),
+    ]);
+    let ans = requestty::prompt_one_with(question(), &mut backend, &mut events).unwrap();
+    assert_eq!(ans, Answer::Int(32));
+
+    // The review looks exactly like the finished prompt
+    let mut review = ui::backend::TestBackend::new(size);
+    question()
+        .build()
+        .render_answered(&ans, &Default::default(), &mut review)
+        .unwrap();
+    review.assert_eq(&backend);
+
+    let mut review = ui::backend::TestBackend::new(size);
+    let err = question()
+        .build()
+        .render_answered(&Answer::Bool(true), &Default::default(), &mut review)
+        .unwrap_err();
+    assert!(matches!(err, requestty::ErrorKind::IoError(_)));
+}
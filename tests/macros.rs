@@ -43,6 +43,7 @@ fn test_duplicate() {
     t.compile_fail("extension");
     t.compile_fail("editor");
     t.compile_fail("prompt");
+    t.compile_fail("question_name");
 }
 
 #[test]
@@ -263,3 +264,15 @@ fn test_raw_select() {
     t.compile_fail("editor");
     t.compile_fail("prompt");
 }
+
+#[test]
+#[ignore = "proc-macro test"]
+fn test_derive() {
+    let t = Runner::new("derive");
+
+    t.pass("valid");
+    t.compile_fail("unsupported_type");
+    t.compile_fail("not_option");
+    t.compile_fail("invalid_option");
+    t.compile_fail("tuple_struct");
+}
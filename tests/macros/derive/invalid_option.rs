@@ -0,0 +1,7 @@
+#[derive(requestty::PromptMissing)]
+struct Config {
+    #[prompt(validate: |_, _| Ok(()))]
+    subscribe: Option<bool>,
+}
+
+fn main() {}
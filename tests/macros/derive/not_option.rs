@@ -0,0 +1,6 @@
+#[derive(requestty::PromptMissing)]
+struct Config {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[derive(requestty::PromptMissing)]
+struct Config(Option<String>);
+
+fn main() {}
@@ -0,0 +1,44 @@
+use requestty::{Answer, Answers, PromptMissing};
+
+#[derive(Default, PromptMissing)]
+struct Config {
+    #[prompt(message: "What is your name?", default: "John Doe")]
+    name: Option<String>,
+    #[prompt(message: "How old are you?", validate: |age, _| if age > 0 {
+        Ok(())
+    } else {
+        Err("Please enter a positive age".to_owned())
+    })]
+    age: Option<i64>,
+    #[prompt(name: "ratio", default: 0.5)]
+    float: Option<f64>,
+    subscribe: Option<bool>,
+}
+
+fn main() {
+    let mut config = Config {
+        age: Some(42),
+        ..Config::default()
+    };
+
+    let names: Vec<_> = requestty::plan(config.missing_questions(), &Answers::default())
+        .iter()
+        .map(|plan| plan.name().to_owned())
+        .collect();
+    assert_eq!(names, ["name", "ratio", "subscribe"]);
+
+    let answers: Answers = vec![
+        ("name".to_owned(), Answer::String("Jane Doe".to_owned())),
+        ("age".to_owned(), Answer::Int(7)),
+        ("ratio".to_owned(), Answer::Float(1.5)),
+    ]
+    .into_iter()
+    .collect();
+    config.fill_from_answers(&answers);
+
+    assert_eq!(config.name.as_deref(), Some("Jane Doe"));
+    assert_eq!(config.age, Some(42));
+    assert_eq!(config.float, Some(1.5));
+    assert_eq!(config.subscribe, None);
+    assert_eq!(config.missing_questions().len(), 1);
+}
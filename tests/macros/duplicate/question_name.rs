@@ -0,0 +1,10 @@
+fn main() {
+    let q = requestty::questions![
+        Input {
+            name: "name",
+        },
+        Confirm {
+            name: "name",
+        },
+    ];
+}
@@ -3,9 +3,11 @@ fn main() {
         name: "name",
         default: "hello world",
         on_esc: requestty::OnEsc::Terminate,
+        optional: true,
         should_loop: true,
         page_size: 10,
         transform: |_, _, _| Ok(()),
+        display_transform: |t, _| t.to_owned(),
         validate: |_, _| Ok(()),
         validate_on_key: |_, _| true,
         filter: |t, _| t,
@@ -94,6 +94,37 @@ fn test_filter() {
     assert_eq!(ans, [0, 4, 6, 7, 8]);
 }
 
+#[test]
+fn test_filterable() {
+    let multi_select = requestty::Question::multi_select("name")
+        .message("multi select")
+        .filterable(true)
+        .choices(vec!["Apple", "Banana", "Blueberry", "Cherry"]);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        // Typed into the filter instead of toggling all the choices
+        KeyEvent::from(KeyCode::Char('b')),
+        KeyCode::Char('a').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Backspace.into(),
+        KeyCode::Char('c').into(),
+        KeyCode::Char(' ').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans: Vec<_> = requestty::prompt_one_with(multi_select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_items()
+        .unwrap()
+        .into_iter()
+        .map(|item| item.index)
+        .collect();
+
+    assert_eq!(ans, [1, 3]);
+}
+
 #[test]
 fn test_transform() {
     let multi_select = requestty::Question::multi_select("name")
@@ -167,3 +198,67 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_answer_separator() {
+    use std::io::Write;
+
+    use requestty::{Answer, ListItem, Question};
+    use ui::{backend::Backend, style::Stylize};
+
+    let size = (40, 5).into();
+    let render = |separator: Option<&str>, selected: &[(usize, &str)]| {
+        let mut question = Question::multi_select("Toppings")
+            .message("Toppings")
+            .choices(vec!["Cheese", "Olives", "Onions", "Mushrooms", "Pineapple"]);
+        if let Some(separator) = separator {
+            question = question.answer_separator(separator);
+        }
+
+        let mut backend = ui::backend::TestBackend::new(size);
+        question
+            .build()
+            .render_answered(
+                &Answer::ListItems(selected.iter().copied().map(ListItem::from).collect()),
+                &Default::default(),
+                &mut backend,
+            )
+            .unwrap();
+        backend
+    };
+
+    let expected = |answer: &dyn Fn(&mut ui::backend::TestBackend)| {
+        let mut expected = ui::backend::TestBackend::new(size);
+        ui::widgets::Prompt::write_finished_message(&"Toppings", false, &mut expected).unwrap();
+        answer(&mut expected);
+        expected.write_all(b"\n").unwrap();
+        expected
+    };
+
+    render(None, &[(0, "Cheese"), (1, "Olives")]).assert_eq(&expected(&|b| {
+        b.write_styled(&"Cheese, Olives".cyan()).unwrap();
+    }));
+
+    render(Some(" + "), &[(0, "Cheese"), (1, "Olives")]).assert_eq(&expected(&|b| {
+        b.write_styled(&"Cheese + Olives".cyan()).unwrap();
+    }));
+
+    render(None, &[]).assert_eq(&expected(&|b| {
+        b.write_styled(&"(none)".dark_grey()).unwrap();
+    }));
+
+    // `✔ Toppings · ` takes 13 columns, and the last column is left empty, leaving 26 columns
+    render(
+        None,
+        &[
+            (0, "Cheese"),
+            (1, "Olives"),
+            (3, "Mushrooms"),
+            (4, "Pineapple"),
+        ],
+    )
+    .assert_eq(&expected(&|b| {
+        b.write_styled(&"Cheese, Olives, Mushrooms…".cyan())
+            .unwrap();
+    }));
+}
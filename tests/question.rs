@@ -44,7 +44,9 @@ fn test_ask_if_answered() {
     let mut prompted_1 = false;
     let mut prompted_2 = false;
 
-    prompt_all(vec![
+    // `prompt` rejects the second question as it can never be asked, but a `PromptModule` asks the
+    // questions as they are
+    requestty::PromptModule::new(vec![
         custom_prompt("name", "message", &mut prompted_0)
             .message("message")
             .build(),
@@ -55,7 +57,12 @@ fn test_ask_if_answered() {
             .message("message")
             .ask_if_answered(true)
             .build(),
-    ]);
+    ])
+    .prompt_all_with(
+        &mut ui::backend::TestBackend::new((1, 1).into()),
+        &mut ui::events::TestEvents::empty(),
+    )
+    .unwrap();
 
     assert!(prompted_0);
     assert!(!prompted_1);
@@ -97,3 +104,423 @@ fn test_message() {
     assert!(prompted_0);
     assert!(prompted_1);
 }
+
+#[test]
+fn test_optional() {
+    use ui::events::{KeyCode, KeyEvent, KeyModifiers, TestEvents};
+
+    let questions = vec![
+        Question::input("a").optional(true).build(),
+        Question::input("b")
+            .when(|answers: &Answers| answers.get("a").is_none())
+            .build(),
+    ];
+
+    let answers = requestty::prompt_with(
+        questions,
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut TestEvents::new(vec![
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            KeyCode::Char('b').into(),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(answers.get("a"), None);
+    assert!(answers.contains_key("a"));
+    assert_eq!(answers["a"], Answer::Skipped);
+    assert_eq!(answers.get("b"), Some(&Answer::String("b".into())));
+
+    // The skip key does nothing if the question is not optional
+    let answers = requestty::prompt_with(
+        Some(Question::input("a").build()),
+        &mut ui::backend::TestBackend::new((50, 20).into()),
+        &mut TestEvents::new(vec![
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL),
+            KeyCode::Enter.into(),
+        ]),
+    )
+    .unwrap();
+
+    assert_eq!(answers.get("a"), Some(&Answer::String("".into())));
+}
+
+#[test]
+fn test_plan() {
+    use requestty::Plan;
+
+    let mut prompted = false;
+
+    let questions = vec![
+        custom_prompt("name-0", "message", &mut prompted).build(),
+        Question::input("name-1")
+            .when(|ans: &requestty::Answers| ans.contains_key("name-0"))
+            .build(),
+        Question::input("name-2")
+            .when(|ans: &requestty::Answers| !ans.contains_key("name-0"))
+            .build(),
+        Question::confirm("name-3")
+            .when(false)
+            .ask_if_answered(true)
+            .build(),
+        Question::confirm("name-4").ask_if_answered(true).build(),
+    ];
+
+    let answers = vec![
+        ("name-0".to_owned(), Answer::Int(0)),
+        ("name-4".to_owned(), Answer::Bool(true)),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(
+        requestty::plan(questions, &answers),
+        vec![
+            Plan::AutoAnswered("name-0".to_owned()),
+            Plan::Asked("name-1".to_owned()),
+            Plan::Skipped("name-2".to_owned()),
+            Plan::Skipped("name-3".to_owned()),
+            Plan::Asked("name-4".to_owned()),
+        ]
+    );
+
+    assert!(!prompted);
+}
+
+#[test]
+fn test_step() {
+    use ui::events::{KeyCode, TestEvents};
+
+    fn output(show_steps: Option<bool>) -> String {
+        let questions = vec![
+            Question::input("a").build(),
+            Question::input("b").build(),
+            Question::input("c").step(7, 9).build(),
+        ];
+
+        let mut output = Vec::new();
+        let mut backend = ui::backend::InspectBackend::new(
+            ui::backend::TestBackend::new((50, 20).into()),
+            |text: &[u8]| {
+                output.extend_from_slice(text);
+                text.into()
+            },
+        );
+
+        let mut module = requestty::PromptModule::new(questions);
+        if let Some(show_steps) = show_steps {
+            module = module.show_steps(show_steps);
+        }
+        module
+            .prompt_all_with(
+                &mut backend,
+                &mut TestEvents::new(vec![KeyCode::Enter.into(); 3]),
+            )
+            .unwrap();
+        drop(backend);
+
+        String::from_utf8(output).unwrap()
+    }
+
+    // The steps are shown by default
+    let output_default = output(None);
+    assert!(output_default.contains("? [1/3] a:"));
+    assert!(output_default.contains("? [2/3] b:"));
+    assert!(output_default.contains("? [7/9] c:"));
+
+    let output_hidden = output(Some(false));
+    assert!(output_hidden.contains("? a:"));
+    assert!(output_hidden.contains("? b:"));
+    assert!(output_hidden.contains("? [7/9] c:"));
+}
+
+#[test]
+fn test_set_title_to_message() {
+    use ui::{
+        backend::{TestBackend, TitleOp},
+        events::{KeyCode, TestEvents},
+    };
+
+    let questions = || {
+        vec![
+            Question::input("a").message("First").build(),
+            Question::confirm("b").default(true).build(),
+            Question::select("c")
+                .message("Third")
+                .choices(vec!["x"])
+                .build(),
+        ]
+    };
+
+    let mut backend = TestBackend::new((50, 20).into());
+    requestty::PromptModule::new(questions())
+        .set_title_to_message(true)
+        .prompt_all_with(
+            &mut backend,
+            &mut TestEvents::new(vec![KeyCode::Enter.into(); 3]),
+        )
+        .unwrap();
+
+    // Each question sets the title to its message, and restores it once it is answered
+    assert_eq!(
+        backend.title_ops(),
+        &[
+            TitleOp::SetTitle("First".into()),
+            TitleOp::RestoreTitle,
+            TitleOp::SetTitle("b:".into()),
+            TitleOp::RestoreTitle,
+            TitleOp::SetTitle("Third".into()),
+            TitleOp::RestoreTitle,
+        ]
+    );
+
+    let mut backend = TestBackend::new((50, 20).into());
+    requestty::PromptModule::new(questions())
+        .prompt_all_with(
+            &mut backend,
+            &mut TestEvents::new(vec![KeyCode::Enter.into(); 3]),
+        )
+        .unwrap();
+
+    assert!(backend.title_ops().is_empty());
+}
+
+#[test]
+fn test_answer_align() {
+    use std::io::Write;
+
+    use requestty::{Answer, AnswerAlign};
+    use ui::{backend::Backend, style::Stylize};
+
+    let size = (30, 5).into();
+    let render = |answer: &str| {
+        let mut backend = ui::backend::TestBackend::new(size);
+        Question::input("name")
+            .message("Name:")
+            .answer_align(AnswerAlign::Right)
+            .build()
+            .render_answered(
+                &Answer::String(answer.into()),
+                &Default::default(),
+                &mut backend,
+            )
+            .unwrap();
+        backend
+    };
+
+    // "✔ Name: · " takes 10 columns, and the last column is left empty
+    let mut expected = ui::backend::TestBackend::new(size);
+    ui::widgets::Prompt::write_finished_message(&"Name:", false, &mut expected).unwrap();
+    write!(expected, "{:14}", "").unwrap();
+    expected.write_styled(&"Alice".cyan()).unwrap();
+    expected.write_all(b"\n").unwrap();
+    render("Alice").assert_eq(&expected);
+
+    // Answers which would overflow are left aligned
+    let long = "a very long answer to fit";
+    let mut expected = ui::backend::TestBackend::new(size);
+    ui::widgets::Prompt::write_finished_message(&"Name:", false, &mut expected).unwrap();
+    expected.write_styled(&long.cyan()).unwrap();
+    expected.write_all(b"\n").unwrap();
+    render(long).assert_eq(&expected);
+}
+
+#[test]
+fn test_prompt_validated() {
+    use std::{cell::Cell, rc::Rc};
+
+    use ui::events::{KeyCode, TestEvents};
+
+    let validated = Rc::new(Cell::new(0));
+    let validated_c = Rc::clone(&validated);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('5').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('3').into(),
+        KeyCode::Enter.into(),
+        // Only the end is asked again
+        KeyCode::Char('9').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let answers = requestty::prompt_validated_with(
+        || vec![Question::int("start").build(), Question::int("end").build()],
+        move |answers: &Answers| {
+            validated_c.set(validated_c.get() + 1);
+
+            if answers["end"].as_int() > answers["start"].as_int() {
+                Ok(())
+            } else {
+                Err("The end must be after the start".into())
+            }
+        },
+        Some("end"),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    assert_eq!(validated.get(), 2);
+    assert!(backend
+        .to_string()
+        .contains("The end must be after the start"));
+    assert_eq!(answers["start"], Answer::Int(5));
+    assert_eq!(answers["end"], Answer::Int(9));
+}
+
+#[test]
+fn test_prompt_with_review() {
+    use ui::events::{KeyCode, KeyEvent, TestEvents};
+
+    // Declines to submit, and changes the answer to "age"
+    let change_age = || -> Vec<KeyEvent> {
+        vec![
+            KeyCode::Char('n').into(),
+            KeyCode::Enter.into(),
+            KeyCode::Down.into(),
+            KeyCode::Enter.into(),
+        ]
+    };
+
+    let mut keys: Vec<KeyEvent> = vec![
+        KeyCode::Char('a').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Char('3').into(),
+        KeyCode::Enter.into(),
+    ];
+    // "adult" is asked now that it is enabled
+    keys.extend(change_age());
+    keys.extend(vec![
+        KeyEvent::from(KeyCode::Backspace),
+        KeyCode::Char('2').into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Enter.into(),
+        KeyCode::Enter.into(),
+    ]);
+    // The previous answer is the default, and "adult" is already answered
+    keys.extend(change_age());
+    keys.push(KeyCode::Enter.into());
+    // "adult" loses its answer once it is disabled
+    keys.extend(change_age());
+    keys.extend(vec![
+        KeyEvent::from(KeyCode::Char('3')),
+        KeyCode::Enter.into(),
+    ]);
+    // Submit the changed answers
+    keys.push(KeyCode::Enter.into());
+
+    let mut backend = ui::backend::TestBackend::new((50, 30).into());
+    let mut events = TestEvents::new(keys);
+
+    let answers = requestty::prompt_with_review_with(
+        || {
+            vec![
+                Question::input("name").build(),
+                Question::int("age").message("How old are you?").build(),
+                Question::confirm("never").when(false).build(),
+                Question::confirm("adult")
+                    .message("Are you an adult?")
+                    .default(true)
+                    .when(|answers: &requestty::Answers| {
+                        matches!(answers["age"], Answer::Int(age) if age >= 18)
+                    })
+                    .build(),
+            ]
+        },
+        requestty::ReviewMessages {
+            submit: "Done?",
+            ..requestty::ReviewMessages::default()
+        },
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+
+    let output = backend.to_string();
+    assert!(output.contains("Which answer do you want to change?"));
+    // The answers are chosen by the messages of their questions, not their names
+    assert!(!output.contains("age"));
+    assert!(!output.contains("Submit?"));
+    assert_eq!(answers["name"], Answer::String("a".into()));
+    assert_eq!(answers["age"], Answer::Int(3));
+    assert!(!answers.contains_key("never"));
+    assert!(!answers.contains_key("adult"));
+}
+
+#[test]
+fn test_duplicate_names() {
+    use ui::events::{KeyCode, TestEvents};
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into(); 3]);
+
+    let answers = requestty::prompt_with(
+        vec![
+            Question::input("name").build(),
+            // Asked even though "name" is answered, so it is not a duplicate
+            Question::input("name").ask_if_answered(true).build(),
+            // Not answered if it is not asked, so the question after it can be asked
+            Question::input("when").when(false).build(),
+            Question::input("when").build(),
+        ],
+        &mut backend,
+        &mut events,
+    )
+    .unwrap();
+    assert_eq!(answers.len(), 2);
+
+    fn duplicate_name(err: requestty::ErrorKind) -> requestty::DuplicateNameError {
+        match err {
+            requestty::ErrorKind::IoError(ref e)
+                if e.kind() == std::io::ErrorKind::InvalidInput =>
+            {
+                e.get_ref()
+                    .and_then(|e| e.downcast_ref::<requestty::DuplicateNameError>())
+                    .expect("expected a duplicate name error")
+                    .clone()
+            }
+            _ => panic!("expected a duplicate name error, got {:?}", err),
+        }
+    }
+
+    // The number of questions is known, so they are checked before anything is asked
+    let size = (50, 20).into();
+    let mut backend = ui::backend::TestBackend::new(size);
+    let err = requestty::prompt_with(
+        vec![
+            Question::input("a").build(),
+            Question::input("b").build(),
+            Question::input("a").build(),
+        ],
+        &mut backend,
+        &mut TestEvents::empty(),
+    )
+    .unwrap_err();
+
+    assert_eq!(duplicate_name(err).name(), "a");
+    backend.assert_eq(&ui::backend::TestBackend::new(size));
+
+    // Otherwise, the names are checked as the questions are taken, so the earlier questions are
+    // asked and the later ones are never taken. The filter keeps the number of questions unknown.
+    let mut events = TestEvents::new(vec![KeyCode::Enter.into(); 2]);
+    let err = requestty::prompt_with(
+        (0..10).filter(|_| true).map(|i: usize| {
+            assert!(i < 3, "question taken after the duplicate");
+            Question::input(i.min(1).to_string()).build()
+        }),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap_err();
+
+    let err = duplicate_name(err);
+    assert_eq!(err.name(), "1");
+    assert_eq!(
+        err.to_string(),
+        "the question `1` can never be asked, since an earlier question with the same name is \
+         always answered"
+    );
+}
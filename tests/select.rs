@@ -87,3 +87,302 @@ fn test_on_esc() {
 
     assert!(res.is_empty());
 }
+
+#[test]
+fn test_answer_with_label() {
+    let questions = || {
+        vec![requestty::Question::select("name")
+            .choices(vec![
+                "Choice 0".into(),
+                Choice::DefaultSeparator,
+                "Choice 2".into(),
+            ])
+            .build()]
+    };
+    let answers = |label: &str| {
+        std::iter::once(("name".to_owned(), requestty::Answer::String(label.into()))).collect()
+    };
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::empty();
+
+    let ans = requestty::PromptModule::new(questions())
+        .with_answers(answers("Choice 2"))
+        .prompt_all_with(&mut backend, &mut events)
+        .unwrap();
+
+    assert_eq!(
+        ans["name"],
+        requestty::Answer::ListItem(requestty::ListItem {
+            index: 2,
+            text: "Choice 2".into(),
+        })
+    );
+
+    let res = requestty::PromptModule::new(questions())
+        .with_answers(answers("Choice 1"))
+        .prompt_all_with(&mut backend, &mut events);
+
+    assert!(matches!(res, Err(requestty::ErrorKind::IoError(_))));
+}
+
+#[test]
+fn test_filterable() {
+    let select = requestty::Question::select("name")
+        .message("select")
+        .filterable(true)
+        .choices(vec![
+            "Apple".into(),
+            Choice::DefaultSeparator,
+            "Banana".into(),
+            "Blueberry".into(),
+            "Cherry".into(),
+        ]);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        // Nothing matches, so the question cannot be submitted
+        KeyEvent::from(KeyCode::Char('x')),
+        KeyCode::Enter.into(),
+        KeyCode::Backspace.into(),
+        // Matches Apple, Blueberry and Cherry
+        KeyCode::Char('E').into(),
+        KeyCode::Down.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(
+        ans,
+        requestty::ListItem {
+            index: 3,
+            text: "Blueberry".into(),
+        }
+    );
+}
+
+#[test]
+fn test_filterable_esc() {
+    let select = || {
+        requestty::Question::select("name")
+            .message("select")
+            .filterable(true)
+            .on_esc(requestty::OnEsc::Terminate)
+            .choices(vec!["Apple", "Banana", "Cherry"])
+    };
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        // Esc clears the query instead of terminating, and Banana is shown again
+        KeyEvent::from(KeyCode::Char('c')),
+        KeyCode::Esc.into(),
+        KeyCode::Up.into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select(), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.text, "Banana");
+
+    // With an empty query, Esc terminates as usual
+    let mut events = TestEvents::new(Some(KeyCode::Esc.into()));
+    let res = requestty::prompt_one_with(select(), &mut backend, &mut events);
+
+    assert!(matches!(res, Err(requestty::ErrorKind::Aborted)));
+}
+
+#[test]
+fn test_estimated_height() {
+    let size = (50, 20).into();
+    let mut question = requestty::Question::select("name")
+        .message("select")
+        .choices(choices(5))
+        .build();
+
+    assert_eq!(
+        question.estimated_height(&requestty::Answers::default(), size),
+        6
+    );
+
+    let answers = std::iter::once(("name".to_owned(), requestty::Answer::Int(0))).collect();
+    assert_eq!(question.estimated_height(&answers, size), 0);
+
+    let mut question = requestty::Question::select("name")
+        .message("select")
+        .choices(choices(30))
+        .build();
+
+    // The list is limited to the page size
+    assert_eq!(
+        question.estimated_height(&requestty::Answers::default(), size),
+        16
+    );
+}
+
+#[test]
+fn test_bindings() {
+    use ui::events::{KeyBindings, KeyModifiers, Movement};
+
+    let ctrl_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::CONTROL);
+
+    let ask = |keys: Vec<KeyEvent>| {
+        let select = requestty::Question::select("name")
+            .message("select")
+            .choices(vec!["a", "b", "c"])
+            .bindings(
+                KeyBindings::arrows()
+                    .bind(ctrl_n, Movement::Down)
+                    .bind(KeyCode::Char('e').into(), Movement::End),
+            );
+
+        let mut backend = ui::backend::TestBackend::new((50, 20).into());
+        let mut events = TestEvents::new(keys);
+
+        requestty::prompt_one_with(select, &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap()
+            .index
+    };
+
+    // `j` is not bound by `arrows`, so it does not move
+    assert_eq!(
+        ask(vec![
+            ctrl_n,
+            KeyCode::Char('j').into(),
+            KeyCode::Enter.into()
+        ]),
+        1
+    );
+    assert_eq!(
+        ask(vec![KeyCode::Char('e').into(), KeyCode::Enter.into()]),
+        2
+    );
+}
+
+#[test]
+fn test_default_by_name() {
+    let select = |name: &str| {
+        requestty::Question::select("name")
+            .message("select")
+            .default(3)
+            .default_by_name(name)
+            .page_size(10)
+            .choices((0..30).map(|i| format!("Choice {}", i)))
+    };
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+    let ans = requestty::prompt_one_with(select("Choice 25"), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+    assert_eq!(ans.index, 25);
+
+    // Without a matching choice, the default index is hovered
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+    let ans = requestty::prompt_one_with(select("Choice 30"), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+    assert_eq!(ans.index, 3);
+
+    // And without either, the first choice is hovered
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+    let ans = requestty::prompt_one_with(
+        requestty::Question::select("name")
+            .message("select")
+            .default_by_name("Choice 30")
+            .choices((0..30).map(|i| format!("Choice {}", i))),
+        &mut backend,
+        &mut events,
+    )
+    .unwrap()
+    .try_into_list_item()
+    .unwrap();
+    assert_eq!(ans.index, 0);
+}
+
+#[test]
+fn test_disabled_choice() {
+    use std::io::Write;
+    use ui::{backend::Backend, style::Stylize};
+
+    let select = || {
+        requestty::Question::select("plan")
+            .message("plan")
+            .choice("Basic")
+            .disabled_choice("Pro", "requires pro plan")
+            .disabled_choice("Legacy", "")
+            .choice("Team")
+    };
+
+    let size = (50, 20).into();
+    let mut backend = ui::backend::TestBackend::new(size);
+    let mut events = TestEvents::new(vec![KeyCode::Down.into(), KeyCode::Enter.into()]);
+    let ans = requestty::prompt_one_with(select(), &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    // The disabled choices are skipped
+    assert_eq!(ans.index, 3);
+
+    let mut expected = ui::backend::TestBackend::new(size);
+    ui::widgets::Prompt::write_finished_message(&"plan", false, &mut expected).unwrap();
+    expected.write_styled(&"Team".cyan()).unwrap();
+    expected.write_all(b"\n").unwrap();
+    backend.assert_eq(&expected);
+
+    // A disabled choice cannot be the default
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(Some(KeyCode::Enter.into()));
+    let ans =
+        requestty::prompt_one_with(select().default_by_name("Pro"), &mut backend, &mut events)
+            .unwrap()
+            .try_into_list_item()
+            .unwrap();
+    assert_eq!(ans.index, 0);
+}
+
+#[test]
+#[should_panic(expected = "Invalid default '1' is disabled")]
+fn test_disabled_default() {
+    requestty::Question::select("plan")
+        .choice("Basic")
+        .disabled_choice("Pro", "requires pro plan")
+        .default(1)
+        .build();
+}
+
+#[test]
+fn test_type_ahead() {
+    let select = requestty::Question::select("fruit")
+        .message("fruit")
+        .type_ahead(true)
+        .bindings(ui::events::KeyBindings::arrows())
+        .choices(vec!["Apple", "Banana", "Blueberry", "Cherry"]);
+
+    let mut backend = ui::backend::TestBackend::new((50, 20).into());
+    let mut events = TestEvents::new(vec![
+        KeyEvent::from(KeyCode::Char('b')),
+        KeyCode::Char('l').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    let ans = requestty::prompt_one_with(select, &mut backend, &mut events)
+        .unwrap()
+        .try_into_list_item()
+        .unwrap();
+
+    assert_eq!(ans.text, "Blueberry");
+}
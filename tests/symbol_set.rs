@@ -30,3 +30,31 @@ fn test_validate() {
 
     requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
 }
+
+#[test]
+fn test_gauge() {
+    requestty::symbols::set(requestty::symbols::ASCII);
+
+    let prompt = Question::int("name").message("message").with_gauge(0, 100);
+
+    let mut output = Vec::new();
+    let mut backend = ui::backend::InspectBackend::new(
+        ui::backend::TestBackend::new((50, 20).into()),
+        |text: &[u8]| {
+            output.extend_from_slice(text);
+            text.into()
+        },
+    );
+    let mut events = TestEvents::new(vec![
+        KeyCode::Char('3').into(),
+        KeyCode::Char('0').into(),
+        KeyCode::Enter.into(),
+    ]);
+
+    requestty::prompt_one_with(prompt, &mut backend, &mut events).unwrap();
+    drop(backend);
+
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains(" [###-------] 30/100"));
+}